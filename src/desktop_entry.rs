@@ -0,0 +1,93 @@
+use std::path::PathBuf;
+
+use log::warn;
+
+use crate::env;
+
+const DESKTOP_FILE_NAME: &str = "hrs-launcher.desktop";
+const ICON_FILE_NAME: &str = "hrs-launcher.png";
+
+/// Installs (or refreshes) a `.desktop` entry so the launcher shows up in
+/// the application menu, pointing `Exec=` at wherever the binary currently
+/// lives. Linux-only; a no-op everywhere else.
+pub fn ensure_desktop_entry() {
+    if !cfg!(target_os = "linux") {
+        return;
+    }
+
+    if let Err(err) = try_ensure_desktop_entry() {
+        warn!("desktop_entry: failed to install application menu entry: {err}");
+    }
+}
+
+fn try_ensure_desktop_entry() -> Result<(), String> {
+    let exe_path =
+        std::env::current_exe().map_err(|e| format!("failed to resolve executable path: {e}"))?;
+    let icon_path = ensure_icon()?;
+
+    let applications_dir = applications_dir()?;
+    std::fs::create_dir_all(&applications_dir)
+        .map_err(|e| format!("failed to create {}: {e}", applications_dir.display()))?;
+    let desktop_path = applications_dir.join(DESKTOP_FILE_NAME);
+
+    let contents = format!(
+        "[Desktop Entry]\n\
+         Type=Application\n\
+         Name=HRS Launcher\n\
+         Comment=Community launcher for Hytale\n\
+         Exec=\"{}\"\n\
+         Icon={}\n\
+         Terminal=false\n\
+         Categories=Game;\n",
+        exe_path.display(),
+        icon_path.display(),
+    );
+
+    // Skip the write if nothing actually changed, so a normal launch
+    // doesn't bump the file's mtime (and trigger a desktop database
+    // rescan) every single time.
+    let unchanged = std::fs::read_to_string(&desktop_path)
+        .map(|existing| existing == contents)
+        .unwrap_or(false);
+    if unchanged {
+        return Ok(());
+    }
+
+    crate::util::write_atomic(&desktop_path, contents.as_bytes())
+        .map_err(|e| format!("failed to write {}: {e}", desktop_path.display()))
+}
+
+fn applications_dir() -> Result<PathBuf, String> {
+    std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .map(PathBuf::from)
+                .map(|home| home.join(".local/share"))
+        })
+        .map(|base| base.join("applications"))
+        .ok_or_else(|| "could not determine a home directory".to_owned())
+}
+
+/// Renders the embedded `.icns` app icon to a PNG under the cache dir (if
+/// not already there) and returns its path, for use as the desktop entry's
+/// `Icon=`.
+fn ensure_icon() -> Result<PathBuf, String> {
+    let icon_path = env::cache_dir().join(ICON_FILE_NAME);
+    if icon_path.exists() {
+        return Ok(icon_path);
+    }
+
+    std::fs::create_dir_all(env::cache_dir())
+        .map_err(|e| format!("failed to create cache dir: {e}"))?;
+
+    let icon = crate::app_icon();
+    let image = image::RgbaImage::from_raw(icon.width, icon.height, icon.rgba)
+        .ok_or_else(|| "app icon buffer did not match its reported dimensions".to_owned())?;
+    image
+        .save(&icon_path)
+        .map_err(|e| format!("failed to write icon PNG: {e}"))?;
+
+    Ok(icon_path)
+}
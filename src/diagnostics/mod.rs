@@ -19,6 +19,7 @@ pub struct DiagnosticReport {
     pub connectivity: ConnectivityInfo,
     pub game_status: GameStatusInfo,
     pub dependencies: DependenciesInfo,
+    pub crash_reports: CrashReportsInfo,
     pub timestamp: String,
 }
 
@@ -26,17 +27,113 @@ pub struct DiagnosticReport {
 pub struct PlatformInfo {
     pub os: String,
     pub arch: String,
+    /// OS key as reported to the patch server (e.g. `"darwin"`), as opposed
+    /// to `os` above which is Rust's own `std::env::consts::OS`. `"unknown"`
+    /// means the patch server has no build for this platform at all.
+    pub patch_os: String,
+    pub patch_arch: String,
     pub launcher_version: String,
+    pub log_file: String,
 }
 
+/// Cap on the resolved addresses reported for the primary patch host's DNS
+/// probe, to keep the report concise on hosts with many A/AAAA records.
+const MAX_REPORTED_DNS_ADDRESSES: usize = 2;
+
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct ConnectivityInfo {
     pub hytale_patches: bool,
-    pub github: bool,
-    pub itch_io: bool,
+    pub hytale_patches_mirrors: Vec<MirrorStatus>,
+    pub endpoints: Vec<EndpointStatus>,
+    /// Host the DNS probe resolved, i.e. the primary patch server.
+    pub dns_host: Option<String>,
+    /// IPs the primary patch host resolved to, capped at
+    /// [`MAX_REPORTED_DNS_ADDRESSES`]. Empty if resolution failed.
+    pub dns_addresses: Vec<String>,
+    pub tls_handshake: TlsHandshakeInfo,
     pub error: Option<String>,
 }
 
+/// Result of an HTTPS handshake attempt against the primary patch host,
+/// categorized so a corporate TLS-intercepting proxy shows up distinctly
+/// from a plain firewall block or a slow connection.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct TlsHandshakeInfo {
+    pub host: Option<String>,
+    pub ok: bool,
+    /// Why the handshake failed, e.g. "certificate error", "connection
+    /// refused", "timeout". `None` when `ok` is `true`.
+    pub reason: Option<String>,
+}
+
+/// Reachability of a single configured patch server mirror.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct MirrorStatus {
+    pub host: String,
+    pub reachable: bool,
+}
+
+/// Reachability of a single configured diagnostics endpoint (see
+/// [`DiagnosticsEndpoint`]), labeled for display in [`format_report`].
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct EndpointStatus {
+    pub label: String,
+    pub url: String,
+    pub reachable: bool,
+}
+
+/// A connectivity check the diagnostics report runs alongside the patch
+/// server mirrors, paired with the label shown for it in [`format_report`].
+#[derive(Debug, Clone, PartialEq)]
+struct DiagnosticsEndpoint {
+    label: String,
+    url: String,
+}
+
+impl DiagnosticsEndpoint {
+    fn new(label: &str, url: &str) -> Self {
+        Self {
+            label: label.into(),
+            url: url.into(),
+        }
+    }
+}
+
+/// Env var used to override the endpoints checked alongside the patch server
+/// mirrors, as a comma-separated `label=url` list, e.g.
+/// `GitHub API=https://api.github.com,Corporate Proxy=https://proxy.example.com`.
+/// Lets users behind corporate networks add checks for their proxy, and
+/// self-hosters verify their mirror is reachable.
+const DIAGNOSTICS_ENDPOINTS_ENV: &str = "HRS_LAUNCHER_DIAGNOSTICS_ENDPOINTS";
+
+fn default_diagnostics_endpoints() -> Vec<DiagnosticsEndpoint> {
+    vec![
+        DiagnosticsEndpoint::new("GitHub API", "https://api.github.com"),
+        DiagnosticsEndpoint::new("itch.io (Butler)", "https://broth.itch.zone"),
+    ]
+}
+
+fn configured_diagnostics_endpoints() -> Vec<DiagnosticsEndpoint> {
+    parse_diagnostics_endpoints(std::env::var(DIAGNOSTICS_ENDPOINTS_ENV).ok().as_deref())
+}
+
+/// Parses the `label=url` list read from [`DIAGNOSTICS_ENDPOINTS_ENV`],
+/// split out from [`configured_diagnostics_endpoints`] so the parsing logic
+/// can be unit tested without touching real process environment state.
+fn parse_diagnostics_endpoints(value: Option<&str>) -> Vec<DiagnosticsEndpoint> {
+    match value {
+        Some(value) if !value.trim().is_empty() => value
+            .split(',')
+            .filter_map(|entry| {
+                let (label, url) = entry.trim().split_once('=')?;
+                (!label.is_empty() && !url.is_empty())
+                    .then(|| DiagnosticsEndpoint::new(label.trim(), url.trim()))
+            })
+            .collect(),
+        _ => default_diagnostics_endpoints(),
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct GameStatusInfo {
     pub installed: bool,
@@ -51,11 +148,19 @@ pub struct DependenciesInfo {
     pub java_path: Option<String>,
     pub butler_installed: bool,
     pub butler_path: Option<String>,
+    pub butler_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct CrashReportsInfo {
+    pub count: usize,
+    pub newest: Option<String>,
 }
 
 pub struct Diagnostics {
     client: Client,
     launcher_version: String,
+    endpoints: Vec<DiagnosticsEndpoint>,
 }
 
 impl Diagnostics {
@@ -74,6 +179,7 @@ impl Diagnostics {
         Self {
             client,
             launcher_version: launcher_version.into(),
+            endpoints: configured_diagnostics_endpoints(),
         }
     }
 
@@ -83,42 +189,110 @@ impl Diagnostics {
             connectivity: self.check_connectivity().await,
             game_status: self.check_game_status(),
             dependencies: self.check_dependencies(),
+            crash_reports: self.check_crash_reports(),
             timestamp: format_timestamp(SystemTime::now()),
         }
     }
 
     fn platform_info(&self) -> PlatformInfo {
         debug!("diagnostics: collecting platform info");
+        let (patch_os, patch_arch) = crate::pwr::platform_keys();
         PlatformInfo {
             os: os_consts::OS.into(),
             arch: os_consts::ARCH.into(),
+            patch_os: patch_os.into(),
+            patch_arch: patch_arch.into(),
             launcher_version: self.launcher_version.clone(),
+            log_file: app_env::logs_dir().join("launcher.log").display().to_string(),
         }
     }
 
     async fn check_connectivity(&self) -> ConnectivityInfo {
         info!("diagnostics: checking connectivity");
+        let mut mirrors = Vec::new();
+        for host in crate::endpoints::patch_hosts() {
+            let reachable = self.endpoint_ok(host).await;
+            mirrors.push(MirrorStatus {
+                host: host.clone(),
+                reachable,
+            });
+        }
+        let hytale_patches = mirrors.iter().any(|mirror| mirror.reachable);
+
+        let mut endpoints = Vec::new();
+        for endpoint in &self.endpoints {
+            let reachable = self.endpoint_ok(&endpoint.url).await;
+            endpoints.push(EndpointStatus {
+                label: endpoint.label.clone(),
+                url: endpoint.url.clone(),
+                reachable,
+            });
+        }
+
         let mut info = ConnectivityInfo {
-            hytale_patches: self.endpoint_ok("https://game-patches.hytale.com").await,
-            github: self.endpoint_ok("https://api.github.com").await,
-            itch_io: self.endpoint_ok("https://broth.itch.zone").await,
+            hytale_patches,
+            hytale_patches_mirrors: mirrors,
+            endpoints,
             ..Default::default()
         };
 
-        // DNS probe
-        if ("game-patches.hytale.com", 443)
-            .to_socket_addrs()
-            .is_ok_and(|mut iter| iter.next().is_some())
+        // DNS probe against the primary mirror; the HTTP/TCP probes above
+        // already cover the rest of the configured mirrors.
+        if let Some(host) = crate::endpoints::patch_hosts()
+            .first()
+            .and_then(|url| Url::parse(url).ok())
+            .and_then(|url| url.host_str().map(str::to_owned))
         {
-            // ok
-        } else {
-            info.error = Some("DNS resolution failed for game-patches.hytale.com".into());
-            warn!("diagnostics: DNS resolution failed for game-patches.hytale.com");
+            info.dns_host = Some(host.clone());
+            info.dns_addresses = (host.as_str(), 443)
+                .to_socket_addrs()
+                .map(|addrs| {
+                    addrs
+                        .take(MAX_REPORTED_DNS_ADDRESSES)
+                        .map(|addr| addr.ip().to_string())
+                        .collect()
+                })
+                .unwrap_or_default();
+            if info.dns_addresses.is_empty() {
+                info.error = Some(format!("DNS resolution failed for {host}"));
+                warn!("diagnostics: DNS resolution failed for {host}");
+            }
+        }
+
+        if let Some(url) = crate::endpoints::patch_hosts().first() {
+            info.tls_handshake = self.check_tls_handshake(url).await;
         }
 
         info
     }
 
+    /// Attempts an HTTPS request against `url` purely to observe whether the
+    /// TLS handshake succeeds, categorizing a failure so a corporate
+    /// TLS-intercepting proxy shows up distinctly from a firewall block or a
+    /// slow connection.
+    async fn check_tls_handshake(&self, url: &str) -> TlsHandshakeInfo {
+        let host = Url::parse(url)
+            .ok()
+            .and_then(|parsed| parsed.host_str().map(str::to_owned));
+        debug!("diagnostics: TLS handshake probe {}", url);
+        match self.client.head(url).send().await {
+            Ok(_) => TlsHandshakeInfo {
+                host,
+                ok: true,
+                reason: None,
+            },
+            Err(err) => {
+                let reason = classify_tls_error(&err);
+                warn!("diagnostics: TLS handshake to {url} failed: {reason}");
+                TlsHandshakeInfo {
+                    host,
+                    ok: false,
+                    reason: Some(reason),
+                }
+            }
+        }
+    }
+
     fn check_game_status(&self) -> GameStatusInfo {
         info!("diagnostics: checking game status");
         let mut status = GameStatusInfo::default();
@@ -183,6 +357,7 @@ impl Diagnostics {
         if butler_bin.exists() {
             deps.butler_installed = true;
             deps.butler_path = Some(butler_bin.display().to_string());
+            deps.butler_version = crate::pwr::butler::cached_version();
         }
 
         debug!(
@@ -192,6 +367,38 @@ impl Diagnostics {
         deps
     }
 
+    fn check_crash_reports(&self) -> CrashReportsInfo {
+        info!("diagnostics: checking crash reports");
+        let mut info = CrashReportsInfo::default();
+
+        let Ok(entries) = fs::read_dir(app_env::crashes_dir()) else {
+            return info;
+        };
+
+        let mut newest: Option<SystemTime> = None;
+        for entry in entries.filter_map(Result::ok) {
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if !metadata.is_file() {
+                continue;
+            }
+            info.count += 1;
+            if let Ok(modified) = metadata.modified()
+                && newest.is_none_or(|current| modified > current)
+            {
+                newest = Some(modified);
+            }
+        }
+        info.newest = newest.map(format_timestamp);
+
+        debug!(
+            "diagnostics: crash report count={} newest={:?}",
+            info.count, info.newest
+        );
+        info
+    }
+
     async fn endpoint_ok(&self, url: &str) -> bool {
         let http_ok = self.http_probe(url).await;
         if http_ok {
@@ -274,11 +481,13 @@ pub fn format_report(report: &DiagnosticReport) -> String {
     if !report.connectivity.hytale_patches {
         connectivity_issues.push("Hytale patches server");
     }
-    if !report.connectivity.github {
-        connectivity_issues.push("GitHub API");
+    for endpoint in &report.connectivity.endpoints {
+        if !endpoint.reachable {
+            connectivity_issues.push(endpoint.label.as_str());
+        }
     }
-    if !report.connectivity.itch_io {
-        connectivity_issues.push("itch.io (Butler)");
+    if !report.connectivity.tls_handshake.ok && report.connectivity.tls_handshake.host.is_some() {
+        connectivity_issues.push("TLS handshake");
     }
     if let Some(err) = &report.connectivity.error {
         connectivity_issues.push(err.as_str());
@@ -304,11 +513,17 @@ pub fn format_report(report: &DiagnosticReport) -> String {
     let _ = writeln!(&mut output, "\n=== PLATFORM ===");
     let _ = writeln!(&mut output, "OS: {}", report.platform.os);
     let _ = writeln!(&mut output, "Arch: {}", report.platform.arch);
+    let _ = writeln!(
+        &mut output,
+        "Patch Server Keys: {}/{}",
+        report.platform.patch_os, report.platform.patch_arch
+    );
     let _ = writeln!(
         &mut output,
         "Launcher Version: {}",
         report.platform.launcher_version
     );
+    let _ = writeln!(&mut output, "Log File: {}", report.platform.log_file);
 
     let _ = writeln!(&mut output, "\n=== CONNECTIVITY ===");
     let _ = writeln!(
@@ -316,15 +531,43 @@ pub fn format_report(report: &DiagnosticReport) -> String {
         "Hytale Patches Server: {}",
         status(report.connectivity.hytale_patches)
     );
+    if report.connectivity.hytale_patches_mirrors.len() > 1 {
+        for mirror in &report.connectivity.hytale_patches_mirrors {
+            let _ = writeln!(
+                &mut output,
+                "  - {}: {}",
+                mirror.host,
+                status(mirror.reachable)
+            );
+        }
+    }
+    for endpoint in &report.connectivity.endpoints {
+        let _ = writeln!(
+            &mut output,
+            "{}: {}",
+            endpoint.label,
+            status(endpoint.reachable)
+        );
+    }
+    if let Some(host) = &report.connectivity.dns_host {
+        let resolved = if report.connectivity.dns_addresses.is_empty() {
+            "unresolved".to_owned()
+        } else {
+            report.connectivity.dns_addresses.join(", ")
+        };
+        let _ = writeln!(&mut output, "DNS: {host} → {resolved}");
+    }
     let _ = writeln!(
         &mut output,
-        "GitHub API: {}",
-        status(report.connectivity.github)
-    );
-    let _ = writeln!(
-        &mut output,
-        "itch.io (Butler): {}",
-        status(report.connectivity.itch_io)
+        "TLS Handshake: {}",
+        match (
+            report.connectivity.tls_handshake.ok,
+            &report.connectivity.tls_handshake.reason
+        ) {
+            (true, _) => "OK".to_owned(),
+            (false, Some(reason)) => format!("FAILED ({reason})"),
+            (false, None) => "FAILED".to_owned(),
+        }
     );
     let _ = writeln!(
         &mut output,
@@ -379,11 +622,171 @@ pub fn format_report(report: &DiagnosticReport) -> String {
         "Butler Path: {}",
         fallback(&report.dependencies.butler_path, "-")
     );
+    let _ = writeln!(
+        &mut output,
+        "Butler Version: {}",
+        fallback(&report.dependencies.butler_version, "unknown")
+    );
+
+    let _ = writeln!(&mut output, "\n=== CRASH REPORTS ===");
+    let _ = writeln!(&mut output, "Count: {}", report.crash_reports.count);
+    let _ = writeln!(
+        &mut output,
+        "Newest: {}",
+        fallback(&report.crash_reports.newest, "-")
+    );
 
     output
 }
 
+/// Bundles the launcher log, the most recent game log (if one is found),
+/// `report_text` (the output of [`format_report`]), and every file in
+/// `crashes_dir()` into a single zip under `env::logs_dir()`, for users to
+/// attach to an issue. Returns the path to the created zip.
+///
+/// # Errors
+/// Returns an error if the zip can't be created, or if writing any entry
+/// fails partway through.
+pub fn create_crash_report_zip(report_text: &str) -> Result<std::path::PathBuf, String> {
+    use std::io::Write as _;
+    use zip::write::SimpleFileOptions;
+
+    let logs_dir = app_env::logs_dir();
+    fs::create_dir_all(&logs_dir).map_err(|e| format!("failed to create logs dir: {e}"))?;
+
+    let zip_name = format!(
+        "crash-report-{}.zip",
+        chrono::Utc::now().format("%Y%m%d-%H%M%S")
+    );
+    let zip_path = logs_dir.join(zip_name);
+    let file = fs::File::create(&zip_path).map_err(|e| format!("failed to create zip: {e}"))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    zip.start_file("diagnostics-report.txt", options)
+        .map_err(|e| format!("failed to add diagnostics report: {e}"))?;
+    zip.write_all(report_text.as_bytes())
+        .map_err(|e| format!("failed to write diagnostics report: {e}"))?;
+
+    let launcher_log = logs_dir.join("launcher.log");
+    add_file_to_zip(&mut zip, &launcher_log, "launcher.log", options);
+
+    if let Some(game_log) = latest_game_log_path() {
+        add_file_to_zip(&mut zip, &game_log, "game.log", options);
+    }
+
+    if let Ok(entries) = fs::read_dir(app_env::crashes_dir()) {
+        for entry in entries.filter_map(Result::ok) {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+            let Some(name) = path.file_name().map(|n| n.to_string_lossy().into_owned()) else {
+                continue;
+            };
+            add_file_to_zip(&mut zip, &path, &format!("crashes/{name}"), options);
+        }
+    }
+
+    zip.finish().map_err(|e| format!("failed to finalize zip: {e}"))?;
+    info!("crash report bundled at {}", zip_path.display());
+    Ok(zip_path)
+}
+
+/// Adds `path`'s contents to the zip under `entry_name`, if it exists and
+/// can be read. Missing or unreadable files (e.g. no game log yet) are
+/// skipped rather than failing the whole bundle.
+fn add_file_to_zip<W: std::io::Write + std::io::Seek>(
+    zip: &mut zip::ZipWriter<W>,
+    path: &std::path::Path,
+    entry_name: &str,
+    options: zip::write::SimpleFileOptions,
+) {
+    let Ok(contents) = fs::read(path) else {
+        return;
+    };
+    if zip.start_file(entry_name, options).is_err() {
+        warn!("crash report: failed to start zip entry {entry_name}");
+        return;
+    }
+    if let Err(err) = std::io::Write::write_all(zip, &contents) {
+        warn!("crash report: failed to write zip entry {entry_name}: {err}");
+    }
+}
+
+/// Best-effort path to the game's own most recent log file, if the game has
+/// ever written one to its default profile's `UserData/logs` directory.
+/// `None` is not an error; many users will never have a game log to attach.
+fn latest_game_log_path() -> Option<std::path::PathBuf> {
+    let path = app_env::default_app_dir()
+        .join("UserData")
+        .join("logs")
+        .join("latest.log");
+    path.is_file().then_some(path)
+}
+
 fn format_timestamp(time: SystemTime) -> String {
     let dt: chrono::DateTime<chrono::Utc> = time.into();
     dt.to_rfc3339()
 }
+
+/// Categorizes a failed HTTPS request into a short, user-facing reason:
+/// a certificate error (the tell for a TLS-intercepting proxy), a refused
+/// connection (firewall block), a timeout, or reqwest's own message as a
+/// fallback.
+fn classify_tls_error(err: &reqwest::Error) -> String {
+    use std::error::Error as _;
+
+    let mut source = err.source();
+    while let Some(cause) = source {
+        let message = cause.to_string().to_lowercase();
+        if message.contains("certificate") || message.contains("invalid peer certificate") {
+            return "certificate error".into();
+        }
+        source = cause.source();
+    }
+
+    if err.is_timeout() {
+        "timeout".into()
+    } else if err.is_connect() {
+        "connection refused".into()
+    } else {
+        err.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_var_falls_back_to_defaults() {
+        assert_eq!(
+            parse_diagnostics_endpoints(None),
+            default_diagnostics_endpoints()
+        );
+        assert_eq!(
+            parse_diagnostics_endpoints(Some("")),
+            default_diagnostics_endpoints()
+        );
+        assert_eq!(
+            parse_diagnostics_endpoints(Some("   ")),
+            default_diagnostics_endpoints()
+        );
+    }
+
+    #[test]
+    fn malformed_entry_is_skipped() {
+        let endpoints = parse_diagnostics_endpoints(Some(
+            "GitHub=https://api.github.com,no-equals-sign,=missing-label,missing-url=,Proxy=https://proxy.example.com",
+        ));
+
+        assert_eq!(
+            endpoints,
+            vec![
+                DiagnosticsEndpoint::new("GitHub", "https://api.github.com"),
+                DiagnosticsEndpoint::new("Proxy", "https://proxy.example.com"),
+            ]
+        );
+    }
+}
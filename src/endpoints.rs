@@ -0,0 +1,80 @@
+use std::env;
+use std::sync::OnceLock;
+
+/// Base URLs for the external services this launcher talks to. Each one
+/// defaults to the shipped value but can be overridden with an env var, so
+/// integration tests can point them at a mock server and advanced users can
+/// point them at a mirror or self-hosted endpoint. Resolved once, on first
+/// use, so a value can't change mid-run even if the env var is mutated later.
+struct Endpoints {
+    patch_hosts: Vec<String>,
+    curse_forge_base: String,
+    news_url: String,
+    github_api_url: String,
+    jre_config_url: String,
+}
+
+static ENDPOINTS: OnceLock<Endpoints> = OnceLock::new();
+
+fn endpoints() -> &'static Endpoints {
+    ENDPOINTS.get_or_init(|| Endpoints {
+        patch_hosts: env_list(
+            "HRS_LAUNCHER_PATCH_HOSTS",
+            &["https://game-patches.hytale.com"],
+        ),
+        curse_forge_base: env_or(
+            "HRS_LAUNCHER_CURSE_FORGE_BASE",
+            "https://api.curseforge.com/v1",
+        ),
+        news_url: env_or("HRS_LAUNCHER_NEWS_URL", "https://hytale.com/news"),
+        github_api_url: env_or(
+            "HRS_LAUNCHER_GITHUB_API_URL",
+            "https://api.github.com/repos/RustedBytes/hrs-launcher/releases/latest",
+        ),
+        jre_config_url: env_or(
+            "HRS_LAUNCHER_JRE_CONFIG_URL",
+            "https://raw.githubusercontent.com/RustedBytes/hrs-launcher/main/assets/jre.json",
+        ),
+    })
+}
+
+fn env_or(key: &str, default: &str) -> String {
+    env::var(key)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| default.to_owned())
+}
+
+fn env_list(key: &str, default: &[&str]) -> Vec<String> {
+    match env::var(key) {
+        Ok(value) if !value.trim().is_empty() => value
+            .split(',')
+            .map(|host| host.trim().to_owned())
+            .filter(|host| !host.is_empty())
+            .collect(),
+        _ => default.iter().map(|host| (*host).to_owned()).collect(),
+    }
+}
+
+/// Patch server mirrors, tried in order until one responds. Ships with a
+/// single entry today, but callers should never assume there's only one.
+/// Override with a comma-separated list via `HRS_LAUNCHER_PATCH_HOSTS`.
+pub fn patch_hosts() -> &'static [String] {
+    &endpoints().patch_hosts
+}
+
+pub fn curse_forge_base() -> &'static str {
+    &endpoints().curse_forge_base
+}
+
+pub fn news_url() -> &'static str {
+    &endpoints().news_url
+}
+
+pub fn github_api_url() -> &'static str {
+    &endpoints().github_api_url
+}
+
+pub fn jre_config_url() -> &'static str {
+    &endpoints().jre_config_url
+}
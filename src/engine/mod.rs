@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
 use log::{debug, error, info, warn};
 use tokio::sync::mpsc;
@@ -9,15 +9,115 @@ use crate::diagnostics::Diagnostics;
 use crate::engine::models::LocalState;
 use crate::engine::state::{AppState, UserAction};
 use crate::env;
-use crate::jre::JreManager;
+use crate::jre::{JreManager, JreStage};
 use crate::mods::ModService;
-use crate::process::ProcessLauncher;
+use crate::process::{self, ProcessLauncher};
 use crate::pwr;
 use crate::storage::StorageManager;
 
 pub mod models;
 pub mod state;
 
+const LAST_PLAYED_FILE: &str = "last_played.txt";
+
+/// Reads the persisted timestamp of the last successful game launch, if one
+/// has been recorded yet.
+#[must_use]
+pub fn read_last_played() -> Option<chrono::DateTime<chrono::Utc>> {
+    let raw = std::fs::read_to_string(env::config_dir().join(LAST_PLAYED_FILE)).ok()?;
+    chrono::DateTime::parse_from_rfc3339(raw.trim())
+        .ok()
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+}
+
+/// Persists "now" as the last-played timestamp. Called after a successful
+/// `spawn`, so a launch that fails to start never overwrites a prior value.
+fn record_last_played() {
+    let path = env::config_dir().join(LAST_PLAYED_FILE);
+    if let Some(parent) = path.parent()
+        && let Err(err) = std::fs::create_dir_all(parent)
+    {
+        warn!("failed to create last-played settings dir: {err}");
+        return;
+    }
+    let timestamp = chrono::Utc::now().to_rfc3339();
+    if let Err(err) = crate::util::write_atomic(&path, timestamp.as_bytes()) {
+        warn!("failed to persist last-played timestamp: {err}");
+    }
+}
+
+const TOTAL_PLAY_TIME_FILE: &str = "total_play_time_seconds.txt";
+
+/// Reads the accumulated total play time, in seconds. `0` if no session has
+/// completed yet.
+#[must_use]
+pub fn read_total_play_time_seconds() -> u64 {
+    std::fs::read_to_string(env::config_dir().join(TOTAL_PLAY_TIME_FILE))
+        .ok()
+        .and_then(|raw| raw.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+fn add_play_session(session_seconds: u64) {
+    let path = env::config_dir().join(TOTAL_PLAY_TIME_FILE);
+    if let Some(parent) = path.parent()
+        && let Err(err) = std::fs::create_dir_all(parent)
+    {
+        warn!("failed to create play-time settings dir: {err}");
+        return;
+    }
+    let total = read_total_play_time_seconds().saturating_add(session_seconds);
+    if let Err(err) = crate::util::write_atomic(&path, total.to_string().as_bytes()) {
+        warn!("failed to persist play time: {err}");
+    }
+}
+
+/// Waits for the launched game process to exit and adds the session length
+/// to the persisted total play time. If the launcher itself exits first,
+/// this task is dropped along with it, so a session with no observed exit
+/// is never counted.
+///
+/// Also clears `running_game_pid` once the process exits, so this doubles
+/// as the exit-detection task backing the "game is already running" guard.
+fn spawn_play_time_tracker(mut child: std::process::Child, running_game_pid: Arc<AtomicU32>) {
+    let started = chrono::Utc::now();
+    let own_pid = child.id();
+    tokio::spawn(async move {
+        let result = tokio::task::spawn_blocking(move || child.wait()).await;
+        // Only clear the shared cell if it still holds this tracker's own
+        // pid: a "force launch another instance" click while this process
+        // was still running would have overwritten it with a newer pid that
+        // must not be cleared just because an older instance exited.
+        let _ = running_game_pid.compare_exchange(own_pid, 0, Ordering::SeqCst, Ordering::SeqCst);
+        let Ok(Ok(_status)) = result else {
+            return;
+        };
+        let session_seconds = (chrono::Utc::now() - started).num_seconds().max(0) as u64;
+        add_play_session(session_seconds);
+        info!("play session ended after {session_seconds}s");
+    });
+}
+
+/// How often [`spawn_external_game_watcher`] polls for the watched pid to
+/// exit. There's no process handle to `wait()` on for a game the launcher
+/// didn't itself spawn, so this falls back to polling.
+const EXTERNAL_GAME_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// Watches a game process the launcher detected already running at startup
+/// (rather than one it spawned itself) and clears `running_game_pid` once it
+/// exits, so Play re-enables without needing a "force launch another
+/// instance" click. No play-time session is recorded for it, since the
+/// launcher never observed it starting.
+pub fn spawn_external_game_watcher(pid: u32, running_game_pid: Arc<AtomicU32>) {
+    tokio::spawn(async move {
+        while process::pid_is_running(pid) {
+            tokio::time::sleep(EXTERNAL_GAME_POLL_INTERVAL).await;
+        }
+        let _ = running_game_pid.compare_exchange(pid, 0, Ordering::SeqCst, Ordering::SeqCst);
+        info!("externally-detected game process {pid} exited");
+    });
+}
+
 pub struct LauncherEngine {
     pub state: AppState,
     storage: StorageManager,
@@ -25,6 +125,13 @@ pub struct LauncherEngine {
     mods: ModService,
     jre: JreManager,
     cancel_flag: Arc<AtomicBool>,
+    /// PID of the currently running game process, or `0` if none. Shared
+    /// with the UI so it can disable Play while a launch is still alive.
+    running_game_pid: Arc<AtomicU32>,
+    /// Whether `ensure_jre_ready` may use a compatible `java` found on PATH
+    /// instead of downloading the bundled runtime. Set from the persisted UI
+    /// setting via [`UserAction::SetAllowSystemJava`].
+    allow_system_java: bool,
 }
 
 impl LauncherEngine {
@@ -32,6 +139,7 @@ impl LauncherEngine {
         storage: StorageManager,
         process: ProcessLauncher,
         cancel_flag: Arc<AtomicBool>,
+        running_game_pid: Arc<AtomicU32>,
     ) -> Self {
         let mods = ModService::new(storage.mods_dir());
         let jre = JreManager::default();
@@ -42,6 +150,8 @@ impl LauncherEngine {
             mods,
             jre,
             cancel_flag,
+            running_game_pid,
+            allow_system_java: false,
         }
     }
 
@@ -58,8 +168,7 @@ impl LauncherEngine {
             },
             _ => AppState::Idle,
         };
-        self.state = state.clone();
-        let _ = updates.send(state);
+        self.set_state(state, updates, "local state loaded");
     }
 
     pub async fn bootstrap(
@@ -68,12 +177,15 @@ impl LauncherEngine {
         updates: &mpsc::UnboundedSender<AppState>,
     ) {
         self.reset_cancel_flag();
-        updates.send(AppState::CheckingForUpdates).ok();
+        self.set_state(AppState::CheckingForUpdates, updates, "bootstrap: starting update check");
         info!("bootstrap: starting update check");
         if let Err(err) = self.ensure_jre_ready(updates).await {
-            let err_state = AppState::Error(err);
-            self.state = err_state.clone();
-            let _ = updates.send(err_state);
+            let err_state = if err == crate::jre::INTEGRITY_CHECK_FAILED_TWICE {
+                AppState::JreIntegrityFailed(err)
+            } else {
+                AppState::Error(err)
+            };
+            self.set_state(err_state, updates, "bootstrap: JRE not ready");
             error!(
                 "bootstrap: failed to ensure JRE ready: {}",
                 self.error_summary()
@@ -82,22 +194,19 @@ impl LauncherEngine {
         }
         if self.cancel_requested() {
             let err_state = AppState::Error("Download cancelled".into());
-            self.state = err_state.clone();
-            let _ = updates.send(err_state);
+            self.set_state(err_state, updates, "bootstrap: cancelled after JRE step");
             warn!("bootstrap: cancelled after JRE step");
             return;
         }
         match self.try_prepare_game(requested_version, updates).await {
             Ok(version) => {
                 let ready = AppState::ReadyToPlay { version };
-                self.state = ready.clone();
-                updates.send(ready).ok();
+                self.set_state(ready, updates, "bootstrap: game ready");
                 info!("bootstrap: game ready (version {})", self.state_version());
             }
             Err(err) => {
                 let err_state = AppState::Error(err);
-                self.state = err_state.clone();
-                updates.send(err_state).ok();
+                self.set_state(err_state, updates, "bootstrap: failed to prepare game");
                 error!(
                     "bootstrap: failed to prepare game: {}",
                     self.error_summary()
@@ -106,6 +215,26 @@ impl LauncherEngine {
         }
     }
 
+    /// Runs the full bootstrap flow (JRE setup, version check, download, and
+    /// verification) and resolves directly to the outcome, without requiring
+    /// the caller to plumb through an [`AppState`] channel. Intended for the
+    /// headless CLI and integration tests that only care about the end
+    /// result; callers that need progress updates should call
+    /// [`bootstrap`](Self::bootstrap) directly instead.
+    ///
+    /// Not wired up yet: nothing calls this until a headless CLI mode exists
+    /// to use it.
+    #[allow(dead_code)]
+    pub async fn download_and_prepare(&mut self, target_version: Option<u32>) -> Result<String, String> {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        self.bootstrap(target_version, &tx).await;
+        match &self.state {
+            AppState::ReadyToPlay { version } => Ok(version.clone()),
+            AppState::Error(err) => Err(err.clone()),
+            other => Err(format!("unexpected state after bootstrap: {other:?}")),
+        }
+    }
+
     pub async fn handle_action(
         &mut self,
         action: UserAction,
@@ -130,9 +259,36 @@ impl LauncherEngine {
                 );
                 self.bootstrap(target_version, updates).await;
             }
+            UserAction::Reinstall {
+                target_version,
+                clear_cache,
+            } => {
+                info!(
+                    "action: Reinstall (target={}, clear_cache={})",
+                    target_version
+                        .map(|v| v.to_string())
+                        .unwrap_or_else(|| "latest".into()),
+                    clear_cache
+                );
+                if let Err(err) = self.storage.remove_game_files(clear_cache).await {
+                    let err_state = AppState::Error(err);
+                    self.set_state(err_state, updates, "reinstall: failed to remove existing game files");
+                    error!(
+                        "reinstall: failed to remove existing game files: {}",
+                        self.error_summary()
+                    );
+                    return;
+                }
+                self.bootstrap(target_version, updates).await;
+            }
             UserAction::ClickPlay {
                 player_name,
                 auth_mode,
+                profile,
+                extra_args,
+                max_memory_gb,
+                min_memory_gb,
+                gc,
             } => match self.state.clone() {
                 AppState::ReadyToPlay { version } => {
                     info!(
@@ -141,12 +297,11 @@ impl LauncherEngine {
                     );
                     if let Err(err) = self.ensure_game_unpacked(&version, updates) {
                         let err_state = AppState::Error(err);
-                        self.state = err_state.clone();
-                        updates.send(err_state).ok();
+                        self.set_state(err_state, updates, "play: failed to unpack game");
                         error!("play failed: {}", self.error_summary());
                         return;
                     }
-                    
+
                     // Apply enabled mods before launching the game
                     info!("Applying enabled mods...");
                     if let Err(err) = self.mods.apply_enabled_mods().await {
@@ -155,21 +310,30 @@ impl LauncherEngine {
                     } else {
                         info!("Mods applied successfully");
                     }
-                    
-                    updates.send(AppState::Playing).ok();
-                    self.state = AppState::Playing;
-                    if let Err(err) =
-                        self.process
-                            .launch(&version, &player_name, auth_mode.arg_value())
-                    {
-                        let err_state = AppState::Error(err);
-                        self.state = err_state.clone();
-                        updates.send(err_state).ok();
-                        error!("launch failed: {}", self.error_summary());
-                    } else {
-                        self.state = AppState::Idle;
-                        updates.send(AppState::Idle).ok();
-                        info!("game launched successfully");
+
+                    self.set_state(AppState::Playing, updates, "play: launching game");
+                    match self.process.launch(
+                        &version,
+                        &player_name,
+                        auth_mode.arg_value(),
+                        &profile,
+                        &extra_args,
+                        max_memory_gb,
+                        min_memory_gb,
+                        gc,
+                    ) {
+                        Err(err) => {
+                            let err_state = AppState::Error(err);
+                            self.set_state(err_state, updates, "play: failed to launch process");
+                            error!("launch failed: {}", self.error_summary());
+                        }
+                        Ok(child) => {
+                            self.set_state(AppState::Idle, updates, "play: launch dispatched");
+                            record_last_played();
+                            self.running_game_pid.store(child.id(), Ordering::SeqCst);
+                            spawn_play_time_tracker(child, self.running_game_pid.clone());
+                            info!("game launched successfully");
+                        }
                     }
                 }
                 AppState::Error(_) => {
@@ -184,60 +348,118 @@ impl LauncherEngine {
             }
             UserAction::RunDiagnostics => {
                 info!("action: RunDiagnostics");
-                updates.send(AppState::DiagnosticsRunning).ok();
+                self.set_state(AppState::DiagnosticsRunning, updates, "running diagnostics");
                 let report = self.run_diagnostics().await;
                 let state = AppState::DiagnosticsReady { report };
-                self.state = state.clone();
-                updates.send(state).ok();
+                self.set_state(state, updates, "diagnostics completed");
                 info!("diagnostics completed");
             }
-            UserAction::UninstallGame => {
-                info!("action: UninstallGame");
-                updates.send(AppState::Uninstalling).ok();
-                self.state = AppState::Uninstalling;
-                match self.storage.uninstall_game().await {
+            UserAction::TestJava => {
+                info!("action: TestJava");
+                self.set_state(AppState::TestingJava, updates, "testing java");
+                match process::test_java().await {
+                    Ok(output) => {
+                        let state = AppState::JavaTestReady { output };
+                        self.set_state(state, updates, "java test completed");
+                        info!("java test completed");
+                    }
+                    Err(err) => {
+                        let err_state = AppState::Error(err);
+                        self.set_state(err_state, updates, "java test failed");
+                        error!("java test failed: {}", self.error_summary());
+                    }
+                }
+            }
+            UserAction::CreateCrashReport => {
+                info!("action: CreateCrashReport");
+                self.set_state(AppState::CreatingCrashReport, updates, "creating crash report");
+                match self.create_crash_report().await {
+                    Ok(path) => {
+                        let state = AppState::CrashReportReady { path };
+                        self.set_state(state, updates, "crash report created");
+                        info!("crash report created");
+                    }
+                    Err(err) => {
+                        let err_state = AppState::Error(err);
+                        self.set_state(err_state, updates, "crash report creation failed");
+                        error!("crash report creation failed: {}", self.error_summary());
+                    }
+                }
+            }
+            UserAction::UninstallGame { keep_jre, keep_user_data } => {
+                info!("action: UninstallGame (keep_jre={keep_jre}, keep_user_data={keep_user_data})");
+                let starting = AppState::Uninstalling { stage: "game" };
+                self.set_state(starting, updates, "uninstall started");
+                let options = crate::storage::UninstallOptions {
+                    keep_jre,
+                    keep_user_data,
+                };
+                let result = self
+                    .storage
+                    .uninstall_game(options, |stage| {
+                        let state = AppState::Uninstalling {
+                            stage: stage.as_str(),
+                        };
+                        let _ = updates.send(state);
+                        debug!("uninstall progress: stage={}", stage.as_str());
+                    })
+                    .await;
+                match result {
                     Ok(_) => {
-                        self.state = AppState::Idle;
-                        updates.send(AppState::Idle).ok();
+                        self.set_state(AppState::Idle, updates, "uninstall completed");
                         info!("uninstall completed");
                     }
                     Err(err) => {
                         let err_state = AppState::Error(err);
-                        self.state = err_state.clone();
-                        updates.send(err_state).ok();
+                        self.set_state(err_state, updates, "uninstall failed");
                         error!("uninstall failed: {}", self.error_summary());
                     }
                 }
             }
-            UserAction::DownloadMod { mod_id } => match self.download_mod(mod_id, updates).await {
-                Ok(_) => {
-                    let next_state = if let Some(local) = self.storage.read_local_state().await {
-                        AppState::ReadyToPlay {
-                            version: local.version,
-                        }
-                    } else {
-                        AppState::Idle
-                    };
-                    self.state = next_state.clone();
-                    updates.send(next_state).ok();
-                    info!("mod {} downloaded", mod_id);
-                }
-                Err(err) => {
-                    let err_state = AppState::Error(err);
-                    self.state = err_state.clone();
-                    updates.send(err_state).ok();
-                    error!("mod {} download failed: {}", mod_id, self.error_summary());
-                }
-            },
             UserAction::OpenGameFolder => {
                 info!("action: OpenGameFolder");
                 if let Err(err) = self.open_game_folder() {
                     let err_state = AppState::Error(err);
-                    self.state = err_state.clone();
-                    updates.send(err_state).ok();
+                    self.set_state(err_state, updates, "open game folder failed");
                     error!("open game folder failed: {}", self.error_summary());
                 }
             }
+            UserAction::OpenLogsFolder => {
+                info!("action: OpenLogsFolder");
+                if let Err(err) = self.open_logs_folder() {
+                    let err_state = AppState::Error(err);
+                    self.set_state(err_state, updates, "open logs folder failed");
+                    error!("open logs folder failed: {}", self.error_summary());
+                }
+            }
+            UserAction::OpenModsFolder => {
+                info!("action: OpenModsFolder");
+                if let Err(err) = self.open_mods_folder() {
+                    let err_state = AppState::Error(err);
+                    self.set_state(err_state, updates, "open mods folder failed");
+                    error!("open mods folder failed: {}", self.error_summary());
+                }
+            }
+            UserAction::OpenCrashesFolder => {
+                info!("action: OpenCrashesFolder");
+                if let Err(err) = self.open_crashes_folder() {
+                    let err_state = AppState::Error(err);
+                    self.set_state(err_state, updates, "open crashes folder failed");
+                    error!("open crashes folder failed: {}", self.error_summary());
+                }
+            }
+            UserAction::OpenCacheFolder => {
+                info!("action: OpenCacheFolder");
+                if let Err(err) = self.open_cache_folder() {
+                    let err_state = AppState::Error(err);
+                    self.set_state(err_state, updates, "open cache folder failed");
+                    error!("open cache folder failed: {}", self.error_summary());
+                }
+            }
+            UserAction::SetAllowSystemJava(allow) => {
+                info!("action: SetAllowSystemJava({allow})");
+                self.allow_system_java = allow;
+            }
         }
     }
 
@@ -252,7 +474,7 @@ impl LauncherEngine {
     pub async fn available_versions_with_storage(
         storage: StorageManager,
     ) -> pwr::VersionCheckResult {
-        let mut check = pwr::find_latest_version_with_details("release").await;
+        let mut check = Self::available_versions_server_only("release").await;
         if let Some(local) = storage.read_local_state().await
             && let Ok(parsed) = local.version.parse::<u32>()
             && !check.available_versions.contains(&parsed)
@@ -264,11 +486,33 @@ impl LauncherEngine {
         check
     }
 
+    /// What the patch server currently advertises, without merging in the
+    /// locally installed version the way [`available_versions_with_storage`]
+    /// does for the version picker. Intended for diagnostics and scripting,
+    /// where "what's on the server" shouldn't silently depend on local state.
+    pub async fn available_versions_server_only(version_type: &str) -> pwr::VersionCheckResult {
+        pwr::find_latest_version_with_details(version_type).await
+    }
+
     pub async fn run_diagnostics(&self) -> String {
         let diag = Diagnostics::new(env!("CARGO_PKG_VERSION")).run().await;
         crate::diagnostics::format_report(&diag)
     }
 
+    /// Bundles the launcher log, game log, diagnostics report, and any saved
+    /// crash files into a single zip, reveals it in the file manager, and
+    /// returns the path to the created zip.
+    async fn create_crash_report(&self) -> Result<String, String> {
+        let report_text = self.run_diagnostics().await;
+        let path = crate::diagnostics::create_crash_report_zip(&report_text)?;
+        if let Some(dir) = path.parent()
+            && let Err(err) = open::that(dir)
+        {
+            warn!("failed to reveal crash report folder: {err}");
+        }
+        Ok(path.display().to_string())
+    }
+
     async fn try_prepare_game(
         &mut self,
         requested_version: Option<u32>,
@@ -361,20 +605,8 @@ impl LauncherEngine {
         }
 
         let mut progress_cb = |update: pwr::ProgressUpdate| {
-            let label = update
-                .current_file
-                .clone()
-                .unwrap_or_else(|| update.stage.to_string());
-            let speed = update
-                .speed
-                .clone()
-                .unwrap_or_else(|| update.message.clone());
-            let state = AppState::Downloading {
-                file: label,
-                progress: update.progress,
-                speed,
-            };
-            let _ = updates.send(state.clone());
+            let state = progress_update_to_state(&update);
+            let _ = updates.send(state);
             debug!(
                 "download progress: stage={} file={:?} progress={:.1} speed={:?}",
                 update.stage, update.current_file, update.progress, update.speed
@@ -404,6 +636,14 @@ impl LauncherEngine {
             .await?;
         let _ = pwr::save_local_version(target_version);
 
+        // The patch just applied only touches the `release` folder (see
+        // `pwr::apply_pwr`), so it may have overwritten files any enabled
+        // mod previously copied there. Flag them as needing reapplication;
+        // `apply_enabled_mods` reapplies them on the next launch.
+        if let Err(err) = self.mods.flag_enabled_mods_for_reapply().await {
+            warn!("prepare_game: failed to flag mods for reapplication: {err}");
+        }
+
         Ok(version_str)
     }
 
@@ -411,49 +651,21 @@ impl LauncherEngine {
         &mut self,
         updates: &mpsc::UnboundedSender<AppState>,
     ) -> Result<(), String> {
-        let state = AppState::Downloading {
-            file: "Java Runtime".into(),
-            progress: 0.0,
-            speed: "starting".into(),
-        };
-        let _ = updates.send(state);
+        self.set_state(
+            AppState::PreparingRuntime { stage: JreStage::Downloading.as_str() },
+            updates,
+            "ensure_jre_ready: starting",
+        );
         info!("ensure_jre_ready: ensuring runtime");
-        self.jre.ensure_jre(Some(self.cancel_flag.as_ref())).await?;
+        self.jre
+            .ensure_jre(Some(self.cancel_flag.as_ref()), self.allow_system_java, |stage: JreStage| {
+                let _ = updates.send(AppState::PreparingRuntime { stage: stage.as_str() });
+            })
+            .await?;
         info!("ensure_jre_ready: runtime available");
         Ok(())
     }
 
-    async fn download_mod(
-        &mut self,
-        mod_id: i32,
-        updates: &mpsc::UnboundedSender<AppState>,
-    ) -> Result<(), String> {
-        if !self.client_path().exists() {
-            return Err("Install the game before installing mods.".into());
-        }
-        self.reset_cancel_flag();
-        let label = format!("mod-{mod_id}");
-        let start = AppState::Downloading {
-            file: label.clone(),
-            progress: 0.0,
-            speed: "starting".into(),
-        };
-        updates.send(start).ok();
-
-        self.mods
-            .download_latest(mod_id, Some(self.cancel_flag.clone()), |pct, message| {
-                let state = AppState::Downloading {
-                    file: label.clone(),
-                    progress: pct,
-                    speed: message.to_string(),
-                };
-                let _ = updates.send(state);
-                debug!("mod {} progress: {:.1}% ({})", mod_id, pct, message);
-            })
-            .await
-            .map(|_| ())
-    }
-
     fn ensure_game_unpacked(
         &self,
         version: &str,
@@ -501,9 +713,32 @@ impl LauncherEngine {
         value
     }
 
+    /// Single choke point for changing `self.state`: updates it, mirrors it
+    /// to the UI over `updates`, and logs the transition with `reason` so
+    /// state changes show up in the log without relying on every call site
+    /// remembering to log its own. Transitions between two states of the
+    /// same kind (e.g. successive `Downloading` progress ticks) are logged
+    /// at debug rather than info, so a normal run doesn't drown the log in
+    /// per-chunk noise.
+    ///
+    /// High-frequency progress callbacks (download/apply progress, uninstall
+    /// stage updates) intentionally bypass this and call `updates.send`
+    /// directly, both to avoid that noise and because they run from closures
+    /// that don't hold a `&mut self`.
+    fn set_state(&mut self, new: AppState, updates: &mpsc::UnboundedSender<AppState>, reason: &str) {
+        if std::mem::discriminant(&self.state) == std::mem::discriminant(&new) {
+            debug!("state: {} ({reason})", new.label());
+        } else {
+            info!("state: {} -> {} ({reason})", self.state.label(), new.label());
+        }
+        self.state = new.clone();
+        let _ = updates.send(new);
+    }
+
     fn error_summary(&self) -> String {
         match &self.state {
             AppState::Error(msg) => msg.clone(),
+            AppState::JreIntegrityFailed(msg) => msg.clone(),
             _ => "unknown error".into(),
         }
     }
@@ -523,4 +758,172 @@ impl LauncherEngine {
         open::that(&dir).map_err(|err| format!("failed to open game folder: {err}"))?;
         Ok(())
     }
+
+    fn open_logs_folder(&self) -> Result<(), String> {
+        let dir = env::logs_dir();
+        std::fs::create_dir_all(&dir)
+            .map_err(|err| format!("failed to create logs folder: {err}"))?;
+        open::that(&dir).map_err(|err| format!("failed to open logs folder: {err}"))?;
+        Ok(())
+    }
+
+    fn open_mods_folder(&self) -> Result<(), String> {
+        let dir = env::mods_dir();
+        std::fs::create_dir_all(&dir)
+            .map_err(|err| format!("failed to create mods folder: {err}"))?;
+        open::that(&dir).map_err(|err| format!("failed to open mods folder: {err}"))?;
+        Ok(())
+    }
+
+    fn open_crashes_folder(&self) -> Result<(), String> {
+        let dir = env::crashes_dir();
+        std::fs::create_dir_all(&dir)
+            .map_err(|err| format!("failed to create crashes folder: {err}"))?;
+        open::that(&dir).map_err(|err| format!("failed to open crashes folder: {err}"))?;
+        Ok(())
+    }
+
+    fn open_cache_folder(&self) -> Result<(), String> {
+        let dir = env::cache_dir();
+        std::fs::create_dir_all(&dir)
+            .map_err(|err| format!("failed to create cache folder: {err}"))?;
+        open::that(&dir).map_err(|err| format!("failed to open cache folder: {err}"))?;
+        Ok(())
+    }
+}
+
+/// Pure mapping from a [`pwr::ProgressUpdate`] to the [`AppState::Downloading`]
+/// it produces, split out of the `try_prepare_game` progress callback so the
+/// mapping can be tested without running a real download.
+fn progress_update_to_state(update: &pwr::ProgressUpdate) -> AppState {
+    let file = update
+        .current_file
+        .clone()
+        .unwrap_or_else(|| update.stage.to_string());
+    let speed = update
+        .speed
+        .clone()
+        .unwrap_or_else(|| update.message.clone());
+    AppState::Downloading {
+        file,
+        progress: update.progress,
+        speed,
+        eta: update.eta.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(stage: &'static str, current_file: Option<&str>, speed: Option<&str>) -> pwr::ProgressUpdate {
+        pwr::ProgressUpdate {
+            stage,
+            progress: 42.0,
+            message: "Doing the thing...".into(),
+            current_file: current_file.map(str::to_owned),
+            speed: speed.map(str::to_owned),
+            eta: None,
+        }
+    }
+
+    #[test]
+    fn download_stage_uses_file_name_and_speed_when_present() {
+        let state = progress_update_to_state(&sample("download", Some("21.pwr"), Some("1.2 MB/s")));
+        match state {
+            AppState::Downloading {
+                file,
+                progress,
+                speed,
+                eta,
+            } => {
+                assert_eq!(file, "21.pwr");
+                assert_eq!(progress, 42.0);
+                assert_eq!(speed, "1.2 MB/s");
+                assert_eq!(eta, None);
+            }
+            other => panic!("expected Downloading, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn install_stage_falls_back_to_stage_and_message_without_file_or_speed() {
+        let state = progress_update_to_state(&sample("install", None, None));
+        match state {
+            AppState::Downloading { file, speed, .. } => {
+                assert_eq!(file, "install");
+                assert_eq!(speed, "Doing the thing...");
+            }
+            other => panic!("expected Downloading, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn carries_eta_through_when_present() {
+        let mut update = sample("download", Some("21.pwr"), Some("1.2 MB/s"));
+        update.eta = Some("~3m 20s remaining".into());
+        let state = progress_update_to_state(&update);
+        match state {
+            AppState::Downloading { eta, .. } => {
+                assert_eq!(eta.as_deref(), Some("~3m 20s remaining"));
+            }
+            other => panic!("expected Downloading, got {other:?}"),
+        }
+    }
+
+    fn spawn_short_lived_process() -> std::process::Child {
+        let mut command = if cfg!(target_os = "windows") {
+            let mut command = std::process::Command::new("cmd");
+            command.args(["/C", "exit", "0"]);
+            command
+        } else {
+            std::process::Command::new("true")
+        };
+        command.spawn().expect("spawn short-lived process")
+    }
+
+    async fn wait_until(mut condition: impl FnMut() -> bool, timeout: std::time::Duration) -> bool {
+        let deadline = tokio::time::Instant::now() + timeout;
+        while !condition() {
+            if tokio::time::Instant::now() >= deadline {
+                return false;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        true
+    }
+
+    #[tokio::test]
+    async fn external_game_watcher_clears_pid_once_the_process_has_already_exited() {
+        let mut child = spawn_short_lived_process();
+        let pid = child.id();
+        child.wait().expect("wait for process to exit");
+
+        let running_game_pid = Arc::new(AtomicU32::new(pid));
+        spawn_external_game_watcher(pid, running_game_pid.clone());
+
+        assert!(
+            wait_until(
+                || running_game_pid.load(Ordering::SeqCst) == 0,
+                std::time::Duration::from_secs(2)
+            )
+            .await,
+            "watcher should have cleared running_game_pid after the process exited"
+        );
+    }
+
+    #[tokio::test]
+    async fn external_game_watcher_does_not_clear_a_pid_it_no_longer_owns() {
+        let mut child = spawn_short_lived_process();
+        let pid = child.id();
+        child.wait().expect("wait for process to exit");
+
+        // A newer launch already overwrote the shared cell by the time this
+        // watcher notices its own pid exited; it must not clobber it.
+        let running_game_pid = Arc::new(AtomicU32::new(pid + 1));
+        spawn_external_game_watcher(pid, running_game_pid.clone());
+
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        assert_eq!(running_game_pid.load(Ordering::SeqCst), pid + 1);
+    }
 }
@@ -1,3 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+use crate::process::GarbageCollector;
+
 // The central source of truth for your UI.
 #[derive(Clone, Debug)]
 pub enum AppState {
@@ -8,8 +12,19 @@ pub enum AppState {
         file: String,
         progress: f32,
         speed: String,
+        eta: Option<String>,
+    },
+    Uninstalling {
+        stage: &'static str,
+    },
+    /// Provisioning the bundled Java runtime, kept distinct from
+    /// [`AppState::Downloading`] so the UI can tell users they're waiting on
+    /// the (much smaller) JRE rather than the game itself. `stage` is a
+    /// stable, language-independent identifier (see [`crate::jre::JreStage`])
+    /// looked up by the UI for a translated message.
+    PreparingRuntime {
+        stage: &'static str,
     },
-    Uninstalling,
     ReadyToPlay {
         version: String,
     },
@@ -17,11 +32,52 @@ pub enum AppState {
     DiagnosticsReady {
         report: String,
     },
+    TestingJava,
+    JavaTestReady {
+        output: String,
+    },
+    CreatingCrashReport,
+    CrashReportReady {
+        path: String,
+    },
     Playing,
     Error(String),
+    /// A downloaded JRE archive failed its checksum twice in a row, i.e. a
+    /// retried download still doesn't match the published `sha256` — most
+    /// likely mirror tampering rather than a one-off transfer glitch.
+    /// Distinct from [`AppState::Error`] so the UI can point users at the
+    /// specific concern instead of a generic failure message.
+    JreIntegrityFailed(String),
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+impl AppState {
+    /// Short, stable name for this variant, used when logging state
+    /// transitions. Deliberately doesn't include the payload (some variants
+    /// carry full diagnostics reports or crash log paths that would be too
+    /// noisy to repeat on every transition).
+    pub fn label(&self) -> &'static str {
+        match self {
+            AppState::Idle => "Idle",
+            AppState::Initialising => "Initialising",
+            AppState::CheckingForUpdates => "CheckingForUpdates",
+            AppState::Downloading { .. } => "Downloading",
+            AppState::Uninstalling { .. } => "Uninstalling",
+            AppState::PreparingRuntime { .. } => "PreparingRuntime",
+            AppState::ReadyToPlay { .. } => "ReadyToPlay",
+            AppState::DiagnosticsRunning => "DiagnosticsRunning",
+            AppState::DiagnosticsReady { .. } => "DiagnosticsReady",
+            AppState::TestingJava => "TestingJava",
+            AppState::JavaTestReady { .. } => "JavaTestReady",
+            AppState::CreatingCrashReport => "CreatingCrashReport",
+            AppState::CrashReportReady { .. } => "CrashReportReady",
+            AppState::Playing => "Playing",
+            AppState::Error(_) => "Error",
+            AppState::JreIntegrityFailed(_) => "JreIntegrityFailed",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AuthMode {
     Offline,
     Online,
@@ -42,18 +98,43 @@ pub enum UserAction {
     ClickPlay {
         player_name: String,
         auth_mode: AuthMode,
+        profile: String,
+        extra_args: Vec<String>,
+        max_memory_gb: Option<u32>,
+        min_memory_gb: Option<u32>,
+        gc: GarbageCollector,
     },
     ClickCancelDownload,
     CheckForUpdates {
         target_version: Option<u32>,
     },
-    DownloadMod {
-        mod_id: i32,
-    },
     RunDiagnostics,
-    UninstallGame,
+    TestJava,
+    CreateCrashReport,
+    UninstallGame {
+        keep_jre: bool,
+        keep_user_data: bool,
+    },
     DownloadGame {
         target_version: Option<u32>,
     },
+    /// Wipes the installed game files and forces a full, non-incremental
+    /// redownload/reinstall. `clear_cache` additionally removes the bundled
+    /// JRE and download cache; normally those are left in place so the
+    /// reinstall doesn't have to redownload them too.
+    Reinstall {
+        target_version: Option<u32>,
+        clear_cache: bool,
+    },
     OpenGameFolder,
+    OpenLogsFolder,
+    OpenModsFolder,
+    OpenCrashesFolder,
+    /// Debug action: reveals the download cache (cached `.pwr` patches and
+    /// JRE archives) for troubleshooting incomplete or corrupted downloads.
+    OpenCacheFolder,
+    /// Persists whether `ensure_jre` may use a compatible `java` found on
+    /// PATH instead of downloading a bundled runtime. Takes effect on the
+    /// next bootstrap, not retroactively.
+    SetAllowSystemJava(bool),
 }
@@ -2,8 +2,18 @@ use std::env;
 use std::fs;
 use std::path::PathBuf;
 
-/// Returns the root directory used by the launcher (mirrors hrs-launcher defaults).
+use log::warn;
+
+const APP_DIR_NAME: &str = "hrs-launcher";
+
+/// Root directory for persistent game/JRE data. On Windows/macOS this is
+/// also the single root everything else lives under; on Linux it follows
+/// `$XDG_DATA_HOME` per the XDG Base Directory spec.
 pub fn default_app_dir() -> PathBuf {
+    data_root()
+}
+
+fn data_root() -> PathBuf {
     let base = match env::consts::OS {
         "windows" => env::var_os("LOCALAPPDATA")
             .or_else(|| env::var_os("APPDATA"))
@@ -11,17 +21,58 @@ pub fn default_app_dir() -> PathBuf {
         "macos" => env::var_os("HOME")
             .map(PathBuf::from)
             .map(|home| home.join("Library").join("Application Support")),
-        _ => env::var_os("HOME")
-            .map(PathBuf::from)
-            .map(|home| home.join(".local").join("share")),
+        _ => xdg_dir("XDG_DATA_HOME", ".local/share"),
     }
     .unwrap_or_else(|| PathBuf::from("."));
 
-    base.join("hrs-launcher")
+    base.join(APP_DIR_NAME)
+}
+
+/// Root directory for launcher settings (player name, language, theme,
+/// profiles). Follows `$XDG_CONFIG_HOME` on Linux; shares [`data_root`]'s
+/// single-location layout on Windows/macOS.
+fn config_root() -> PathBuf {
+    if env::consts::OS == "linux" {
+        xdg_dir("XDG_CONFIG_HOME", ".config")
+            .map(|base| base.join(APP_DIR_NAME))
+            .unwrap_or_else(data_root)
+    } else {
+        data_root()
+    }
+}
+
+/// Root directory for the download cache. Follows `$XDG_CACHE_HOME` on
+/// Linux; shares [`data_root`]'s single-location layout on Windows/macOS.
+fn cache_root() -> PathBuf {
+    if env::consts::OS == "linux" {
+        xdg_dir("XDG_CACHE_HOME", ".cache")
+            .map(|base| base.join(APP_DIR_NAME))
+            .unwrap_or_else(data_root)
+    } else {
+        data_root()
+    }
+}
+
+/// Resolves an XDG base directory: the env var if it's set to an absolute
+/// path, else `$HOME/<fallback_relative_to_home>`.
+fn xdg_dir(env_var: &str, fallback_relative_to_home: &str) -> Option<PathBuf> {
+    env::var_os(env_var)
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| {
+            env::var_os("HOME")
+                .map(PathBuf::from)
+                .map(|home| home.join(fallback_relative_to_home))
+        })
+}
+
+/// Root directory for settings files and profiles.
+pub fn config_dir() -> PathBuf {
+    config_root()
 }
 
 pub fn cache_dir() -> PathBuf {
-    default_app_dir().join("cache")
+    cache_root().join("cache")
 }
 
 pub fn logs_dir() -> PathBuf {
@@ -65,6 +116,7 @@ pub fn ensure_base_dirs() -> std::io::Result<()> {
     let root = default_app_dir();
     let folders = [
         root.clone(),
+        config_dir(),
         jre_dir(),
         butler_dir(),
         cache_dir(),
@@ -80,3 +132,53 @@ pub fn ensure_base_dirs() -> std::io::Result<()> {
     }
     Ok(())
 }
+
+/// Settings files and the `profiles` directory that used to live directly
+/// under [`data_root`] before settings were split out to [`config_root`].
+/// Moves them to their new home so upgrading users don't lose their
+/// player name, profiles, or other preferences. A no-op once migrated, and
+/// on platforms where config and data already share a root.
+pub fn migrate_legacy_config_files() {
+    let old_root = data_root();
+    let new_root = config_root();
+    if old_root == new_root {
+        return;
+    }
+
+    const LEGACY_SETTINGS_FILES: &[&str] = &[
+        "player_name.txt",
+        "selected_version.txt",
+        "active_profile.txt",
+        "extra_launch_args.txt",
+        "max_memory_gb.txt",
+        "min_memory_gb.txt",
+        "gc.txt",
+    ];
+
+    for name in LEGACY_SETTINGS_FILES {
+        migrate_legacy_entry(&old_root.join(name), &new_root.join(name));
+    }
+    migrate_legacy_entry(&old_root.join("profiles"), &new_root.join("profiles"));
+}
+
+fn migrate_legacy_entry(old_path: &std::path::Path, new_path: &std::path::Path) {
+    if !old_path.exists() || new_path.exists() {
+        return;
+    }
+    if let Some(parent) = new_path.parent()
+        && let Err(err) = fs::create_dir_all(parent)
+    {
+        warn!(
+            "env: failed to prepare config dir for migrating {}: {err}",
+            old_path.display()
+        );
+        return;
+    }
+    if let Err(err) = fs::rename(old_path, new_path) {
+        warn!(
+            "env: failed to migrate {} to {}: {err}",
+            old_path.display(),
+            new_path.display()
+        );
+    }
+}
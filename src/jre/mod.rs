@@ -16,12 +16,20 @@ use tokio::fs as async_fs;
 use tokio::io::AsyncWriteExt;
 use zip::read::ZipArchive;
 
-const JRE_CONFIG_URL: &str =
-    "https://raw.githubusercontent.com/RustedBytes/hrs-launcher/main/assets/jre.json";
 const LOCAL_JRE_CONFIG: &str = "jre.json";
+const SOURCE_MARKER_FILE: &str = ".jre-source";
+/// Records the path of a system `java` on PATH that [`JreManager::ensure_jre`]
+/// chose to use instead of downloading a bundled runtime, so
+/// [`resolve_java_binary`] can find it again on subsequent launches.
+const SYSTEM_JAVA_MARKER_FILE: &str = "system-java-path.txt";
 const JRE_VERSION: &str = "25";
 const EMBEDDED_JRE_CONFIG: &str = include_str!("../../assets/jre.json");
 const CANCELLED: &str = "Download cancelled";
+/// Returned by [`JreManager::ensure_jre`] when a downloaded archive fails
+/// its checksum twice in a row. Matched on by callers that want to route
+/// this specific failure to a distinct UI state instead of a generic error.
+pub const INTEGRITY_CHECK_FAILED_TWICE: &str =
+    "JRE integrity check failed twice — possible mirror tampering";
 
 #[derive(Debug, Clone, Deserialize)]
 struct JrePlatform {
@@ -42,6 +50,28 @@ enum ArchiveKind {
     Zip,
 }
 
+/// Coarse-grained phase of [`JreManager::ensure_jre`], reported to callers
+/// via its `on_stage` callback. There's no per-byte progress here the way
+/// there is for the game download (`ensure_jre` doesn't stream through a
+/// progress callback internally), but the phase alone is enough for the UI
+/// to show something more specific than "preparing runtime".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JreStage {
+    Downloading,
+    Extracting,
+}
+
+impl JreStage {
+    /// A stable, language-independent identifier for this stage, used by the
+    /// UI layer to look up a translated message.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            JreStage::Downloading => "downloading",
+            JreStage::Extracting => "extracting",
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct JreManager {
     cache_dir: PathBuf,
@@ -50,7 +80,8 @@ pub struct JreManager {
 }
 
 impl JreManager {
-    pub fn new(base_dir: impl AsRef<Path>) -> Self {
+    #[cfg(test)]
+    fn new(base_dir: impl AsRef<Path>) -> Self {
         let base = base_dir.as_ref();
         let cache_dir = base.join("cache");
         let jre_dir = base.join("jre");
@@ -62,23 +93,47 @@ impl JreManager {
     }
 
     pub fn default() -> Self {
-        Self::new(env::default_app_dir())
+        Self {
+            cache_dir: env::cache_dir(),
+            jre_dir: env::jre_dir(),
+            client: Client::new(),
+        }
     }
 
-    pub async fn ensure_jre(&self, cancel_flag: Option<&AtomicBool>) -> Result<PathBuf, String> {
+    pub async fn ensure_jre(
+        &self,
+        cancel_flag: Option<&AtomicBool>,
+        allow_system_java: bool,
+        mut on_stage: impl FnMut(JreStage),
+    ) -> Result<PathBuf, String> {
         info!("jre: ensuring runtime");
         check_cancel(cancel_flag)?;
         let java_path = self.java_path();
-        if java_path.exists() {
-            debug!("jre: runtime already present at {}", java_path.display());
-            return Ok(java_path);
-        }
-        if self.jre_dir.exists() {
+        if !java_path.exists() && self.jre_dir.exists() {
             self.normalize_layout()?;
-            if java_path.exists() {
-                debug!("jre: runtime found after layout normalization");
+        }
+
+        if java_path.exists() {
+            let expected_url = self.expected_source_url();
+            if self.read_source_marker().as_deref() == Some(expected_url.as_str()) {
+                debug!("jre: runtime already present and current at {}", java_path.display());
+                self.clear_system_java_marker();
                 return Ok(java_path);
             }
+            warn!("jre: installed runtime's source no longer matches configuration; re-provisioning");
+            fs::remove_dir_all(&self.jre_dir)
+                .map_err(|e| format!("failed to remove stale JRE dir: {e}"))?;
+        }
+
+        self.clear_system_java_marker();
+        if allow_system_java && let Some(system_java) = detect_system_java(min_system_java_major()) {
+            info!(
+                "jre: using system java on PATH instead of downloading a bundled runtime: {}",
+                system_java.display()
+            );
+            fs::create_dir_all(&self.jre_dir).map_err(|e| format!("unable to create JRE dir: {e}"))?;
+            self.write_system_java_marker(&system_java)?;
+            return Ok(system_java);
         }
 
         check_cancel(cancel_flag)?;
@@ -91,54 +146,95 @@ impl JreManager {
             .await
             .or_else(|_| self.load_local_config())?;
         check_cancel(cancel_flag)?;
-        let target = self
-            .pick_platform_target(&config)
+        let config_target = self.pick_platform_target(&config);
+        let mut target = config_target
+            .clone()
             .unwrap_or_else(|| self.adoptium_fallback());
-        info!("jre: selected target {}", target.url);
+        info!(
+            "jre: selected target {} (source: {})",
+            target.url,
+            if config_target.is_some() {
+                "config"
+            } else {
+                "adoptium fallback (platform not in config)"
+            }
+        );
 
-        let archive_path = self
-            .cache_dir
-            .join(format!("jre{}", target.archive.extension()));
-        let expected_checksum = target
-            .checksum
-            .as_deref()
-            .map(str::trim)
-            .filter(|value| !value.is_empty())
-            .map(str::to_owned);
-        let mut needs_download = !archive_path.exists();
-        if !needs_download
-            && let Some(expected) = expected_checksum.as_deref()
-            && self.verify_sha256(&archive_path, expected).is_err()
-        {
-            let _ = fs::remove_file(&archive_path);
-            needs_download = true;
-        }
-        if needs_download {
-            info!("jre: downloading archive to {}", archive_path.display());
-            self.download(&target.url, &archive_path, cancel_flag)
-                .await
-                .map_err(|e| {
-                    if e == CANCELLED {
-                        e
-                    } else {
-                        format!("failed to download JRE: {e}")
-                    }
-                })?;
-        }
-        check_cancel(cancel_flag)?;
-        if let Some(expected) = expected_checksum.as_deref() {
-            self.verify_sha256(&archive_path, expected)?;
-        }
+        on_stage(JreStage::Downloading);
+        let archive_path = match self.download_verified(&target, cancel_flag).await {
+            Ok(path) => path,
+            Err(err) if err == CANCELLED => return Err(err),
+            // Only retry once, and only when the config actually offered a
+            // target of its own; a fallback target failing has nowhere left
+            // to fall back to.
+            Err(err) if config_target.is_some() => {
+                warn!("jre: configured source failed ({err}); retrying via Adoptium fallback");
+                target = self.adoptium_fallback();
+                info!("jre: selected target {} (source: adoptium fallback after config failure)", target.url);
+                self.download_verified(&target, cancel_flag).await?
+            }
+            Err(err) => return Err(err),
+        };
 
         check_cancel(cancel_flag)?;
+        on_stage(JreStage::Extracting);
         self.extract_archive(&archive_path, target.archive)?;
         check_cancel(cancel_flag)?;
         self.normalize_layout()?;
+        crate::util::clear_quarantine(&self.jre_dir);
+        self.write_source_marker(&target.url)?;
 
         info!("jre: ready at {}", java_path.display());
         Ok(java_path)
     }
 
+    /// The source URL the currently pinned config would pick, used to
+    /// detect a version pin or `jre.json` change without needing a network
+    /// round trip on every launch (that still happens below if we actually
+    /// need to re-provision).
+    fn expected_source_url(&self) -> String {
+        self.load_local_config()
+            .ok()
+            .and_then(|config| self.pick_platform_target(&config))
+            .unwrap_or_else(|| self.adoptium_fallback())
+            .url
+    }
+
+    fn source_marker_path(&self) -> PathBuf {
+        self.jre_dir.join(SOURCE_MARKER_FILE)
+    }
+
+    fn read_source_marker(&self) -> Option<String> {
+        fs::read_to_string(self.source_marker_path())
+            .ok()
+            .map(|contents| contents.trim().to_owned())
+            .filter(|value| !value.is_empty())
+    }
+
+    fn write_source_marker(&self, url: &str) -> Result<(), String> {
+        fs::write(self.source_marker_path(), url)
+            .map_err(|e| format!("unable to persist JRE source marker: {e}"))
+    }
+
+    fn system_java_marker_path(&self) -> PathBuf {
+        self.jre_dir.join(SYSTEM_JAVA_MARKER_FILE)
+    }
+
+    /// Records that `java_path` (a java found on PATH) is being used instead
+    /// of a bundled runtime, so [`resolve_java_binary`] can find it again
+    /// without re-scanning PATH on every launch.
+    fn write_system_java_marker(&self, java_path: &Path) -> Result<(), String> {
+        crate::util::write_atomic(&self.system_java_marker_path(), java_path.to_string_lossy().as_bytes())
+            .map_err(|e| format!("unable to persist system java marker: {e}"))
+    }
+
+    /// Removes the system-java marker, if any. Called whenever a bundled
+    /// runtime is confirmed present and current, so a stale marker can't
+    /// shadow it after `allow_system_java` is turned back off.
+    fn clear_system_java_marker(&self) {
+        let _ = fs::remove_file(self.system_java_marker_path());
+    }
+
     fn java_path(&self) -> PathBuf {
         let bin = if cfg!(target_os = "windows") {
             Path::new("bin").join("java.exe")
@@ -151,7 +247,7 @@ impl JreManager {
     async fn fetch_remote_config(&self) -> Result<JreConfig, String> {
         let resp = self
             .client
-            .get(JRE_CONFIG_URL)
+            .get(crate::endpoints::jre_config_url())
             .send()
             .await
             .map_err(|e| format!("config request failed: {e}"))?
@@ -208,6 +304,81 @@ impl JreManager {
         }
     }
 
+    /// Downloads `target`'s archive if it isn't already cached with a
+    /// matching checksum, then verifies the checksum. Returns the path to
+    /// the (now-verified) cached archive.
+    ///
+    /// A checksum mismatch on a freshly downloaded archive is retried once
+    /// before being treated as [`INTEGRITY_CHECK_FAILED_TWICE`], since a lone
+    /// mismatch is usually just a corrupted transfer rather than a tampered
+    /// mirror.
+    async fn download_verified(
+        &self,
+        target: &DownloadTarget,
+        cancel_flag: Option<&AtomicBool>,
+    ) -> Result<PathBuf, String> {
+        let archive_path = self
+            .cache_dir
+            .join(format!("jre{}", target.archive.extension()));
+        let expected_checksum = target
+            .checksum
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_owned);
+        let mut needs_download = !archive_path.exists();
+        if !needs_download
+            && let Some(expected) = expected_checksum.as_deref()
+            && self.verify_sha256(&archive_path, expected).is_err()
+        {
+            let _ = fs::remove_file(&archive_path);
+            needs_download = true;
+        }
+        if needs_download {
+            self.download_fresh(&archive_path, target, cancel_flag)
+                .await?;
+        }
+        check_cancel(cancel_flag)?;
+        let Some(expected) = expected_checksum.as_deref() else {
+            return Ok(archive_path);
+        };
+        if self.verify_sha256(&archive_path, expected).is_ok() {
+            return Ok(archive_path);
+        }
+
+        warn!("jre: checksum mismatch after download; retrying once");
+        let _ = fs::remove_file(&archive_path);
+        check_cancel(cancel_flag)?;
+        self.download_fresh(&archive_path, target, cancel_flag)
+            .await?;
+        check_cancel(cancel_flag)?;
+        if self.verify_sha256(&archive_path, expected).is_ok() {
+            return Ok(archive_path);
+        }
+
+        let _ = fs::remove_file(&archive_path);
+        warn!("jre: checksum mismatch persisted after retry; possible mirror tampering");
+        Err(INTEGRITY_CHECK_FAILED_TWICE.into())
+    }
+
+    async fn download_fresh(
+        &self,
+        archive_path: &Path,
+        target: &DownloadTarget,
+        cancel_flag: Option<&AtomicBool>,
+    ) -> Result<(), String> {
+        info!("jre: downloading archive to {}", archive_path.display());
+        self.download(&target.url, archive_path, cancel_flag)
+            .await
+            .map_err(|e| {
+                if e == CANCELLED {
+                    e
+                } else {
+                    format!("failed to download JRE: {e}")
+                }
+            })
+    }
+
     async fn download(
         &self,
         url: &str,
@@ -313,54 +484,35 @@ impl JreManager {
         Ok(())
     }
 
+    /// Flattens the extracted JRE into `jre_dir` so `jre_dir/bin/java` exists.
+    /// Archives don't always extract with `bin/` at the top level: Adoptium
+    /// tar.gz/zip builds wrap everything in a versioned directory, that
+    /// directory may itself sit alongside unrelated top-level files (e.g. a
+    /// license), and macOS builds nest the real runtime under
+    /// `<bundle>/Contents/Home`. Search a couple of levels deep for the
+    /// directory that actually contains `bin/java` and flatten from there.
     fn normalize_layout(&self) -> Result<(), String> {
         debug!("jre: normalizing layout in {}", self.jre_dir.display());
-        let mut entries =
-            fs::read_dir(&self.jre_dir).map_err(|e| format!("read jre dir error: {e}"))?;
-        let first = match entries.next() {
-            Some(Ok(entry)) => entry,
-            _ => return Ok(()),
-        };
-        if entries.next().is_some() {
-            return Ok(()); // already flat enough
+        if has_java_bin(&self.jre_dir) {
+            return Ok(()); // already flat
         }
 
-        if !first.file_type().map_err(|e| e.to_string())?.is_dir() {
+        let Some(root) = find_jre_root(&self.jre_dir, JRE_ROOT_SEARCH_DEPTH)? else {
+            debug!(
+                "jre: no bin/java found within {} levels under {}; leaving layout as-is",
+                JRE_ROOT_SEARCH_DEPTH,
+                self.jre_dir.display()
+            );
             return Ok(());
-        }
+        };
 
-        #[cfg_attr(not(target_os = "macos"), allow(unused_mut))]
-        let mut subdir = self.jre_dir.join(first.file_name());
-        #[cfg(target_os = "macos")]
-        {
-            let mac_home = subdir.join("Contents").join("Home");
-            if mac_home.exists() {
-                subdir = mac_home;
-            }
-        }
+        flatten_dir(&root, &self.jre_dir)?;
 
-        let sub_entries = fs::read_dir(&subdir).map_err(|e| format!("read subdir error: {e}"))?;
-        for entry in sub_entries {
-            let entry = entry.map_err(|e| format!("subdir entry error: {e}"))?;
-            let from = entry.path();
-            let to = self.jre_dir.join(entry.file_name());
-            match fs::rename(&from, &to) {
-                Ok(_) => {}
-                Err(_) => {
-                    // Fallback to copy if rename crosses devices.
-                    match entry.file_type() {
-                        Ok(ft) if ft.is_dir() => copy_dir(&from, &to)?,
-                        _ => {
-                            fs::copy(&from, &to).map_err(|e| format!("copy file error: {e}"))?;
-                        }
-                    }
-                    // Best-effort cleanup old path if rename failed.
-                    let _ = fs::remove_file(&from);
-                }
-            }
+        // Best-effort cleanup of the now-empty wrapper directory the archive
+        // extracted into (and anything else nested inside it).
+        if let Some(top_level) = top_level_ancestor(&self.jre_dir, &root) {
+            let _ = fs::remove_dir_all(top_level);
         }
-
-        let _ = fs::remove_dir_all(subdir);
         Ok(())
     }
 }
@@ -379,6 +531,89 @@ fn is_cancelled(cancel_flag: Option<&AtomicBool>) -> bool {
         .unwrap_or(false)
 }
 
+/// How many directory levels under `jre_dir` to search for `bin/java`.
+const JRE_ROOT_SEARCH_DEPTH: u32 = 2;
+
+fn has_java_bin(dir: &Path) -> bool {
+    dir.join("bin").join("java").exists() || dir.join("bin").join("java.exe").exists()
+}
+
+fn mac_home_if_present(dir: &Path) -> Option<PathBuf> {
+    let home = dir.join("Contents").join("Home");
+    has_java_bin(&home).then_some(home)
+}
+
+/// Recursively searches `dir` (and up to `max_depth` levels of subdirectories)
+/// for a directory containing `bin/java`, checking the macOS `Contents/Home`
+/// layout at every level along the way.
+fn find_jre_root(dir: &Path, max_depth: u32) -> Result<Option<PathBuf>, String> {
+    if has_java_bin(dir) {
+        return Ok(Some(dir.to_path_buf()));
+    }
+    if let Some(mac_home) = mac_home_if_present(dir) {
+        return Ok(Some(mac_home));
+    }
+    if max_depth == 0 {
+        return Ok(None);
+    }
+
+    let entries = fs::read_dir(dir).map_err(|e| format!("read jre dir error: {e}"))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("jre dir entry error: {e}"))?;
+        let path = entry.path();
+        if entry.file_type().map_err(|e| e.to_string())?.is_dir()
+            && let Some(found) = find_jre_root(&path, max_depth - 1)?
+        {
+            return Ok(Some(found));
+        }
+    }
+    Ok(None)
+}
+
+/// Moves every entry of `src` into `dest`, falling back to copy when the
+/// rename crosses devices.
+fn flatten_dir(src: &Path, dest: &Path) -> Result<(), String> {
+    if src == dest {
+        return Ok(());
+    }
+    let entries = fs::read_dir(src).map_err(|e| format!("read subdir error: {e}"))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("subdir entry error: {e}"))?;
+        let from = entry.path();
+        let to = dest.join(entry.file_name());
+        match fs::rename(&from, &to) {
+            Ok(_) => {}
+            Err(_) => {
+                match entry.file_type() {
+                    Ok(ft) if ft.is_dir() => copy_dir(&from, &to)?,
+                    _ => {
+                        fs::copy(&from, &to).map_err(|e| format!("copy file error: {e}"))?;
+                    }
+                }
+                // Best-effort cleanup old path if rename failed.
+                let _ = fs::remove_file(&from);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the direct child of `base` on the path down to `nested`, i.e. the
+/// top-level wrapper directory the archive extracted into.
+fn top_level_ancestor(base: &Path, nested: &Path) -> Option<PathBuf> {
+    if nested == base {
+        return None;
+    }
+    let mut current = nested.to_path_buf();
+    loop {
+        let parent = current.parent()?.to_path_buf();
+        if parent == base {
+            return Some(current);
+        }
+        current = parent;
+    }
+}
+
 fn copy_dir(from: &Path, to: &Path) -> Result<(), String> {
     fs::create_dir_all(to).map_err(|e| format!("copy dir create error: {e}"))?;
     for entry in fs::read_dir(from).map_err(|e| format!("copy dir read error: {e}"))? {
@@ -411,6 +646,10 @@ fn platform_keys() -> (&'static str, &'static str, ArchiveKind) {
         "x64"
     } else if cfg!(target_arch = "aarch64") {
         "arm64"
+    } else if cfg!(target_arch = "x86") {
+        "x86"
+    } else if cfg!(target_arch = "arm") {
+        "arm"
     } else {
         std::env::consts::ARCH
     };
@@ -437,6 +676,10 @@ fn adoptium_platform() -> (&'static str, &'static str, ArchiveKind) {
         "x64"
     } else if cfg!(target_arch = "aarch64") {
         "aarch64"
+    } else if cfg!(target_arch = "x86") {
+        "x86"
+    } else if cfg!(target_arch = "arm") {
+        "arm"
     } else {
         std::env::consts::ARCH
     };
@@ -460,6 +703,7 @@ fn guess_archive_kind(url: &str) -> Option<ArchiveKind> {
     }
 }
 
+#[derive(Clone)]
 struct DownloadTarget {
     url: String,
     checksum: Option<String>,
@@ -474,3 +718,311 @@ impl ArchiveKind {
         }
     }
 }
+
+/// The lowest major Java version [`detect_system_java`] will accept, derived
+/// from [`JRE_VERSION`] so "compatible" always tracks whatever version the
+/// bundled runtime itself targets.
+pub(crate) fn min_system_java_major() -> u32 {
+    JRE_VERSION.parse().unwrap_or(21)
+}
+
+/// Looks for a `java` binary on PATH whose reported major version is at
+/// least `min_major`, for the "use system Java instead of downloading one"
+/// option. Returns `None` (rather than an error) whenever no suitable java
+/// is found, since "nothing on PATH" is the expected, unremarkable case.
+pub fn detect_system_java(min_major: u32) -> Option<PathBuf> {
+    let java_path = which_java()?;
+    let output = std::process::Command::new(&java_path)
+        .arg("-version")
+        .output()
+        .ok()?;
+    let report = if !output.stderr.is_empty() {
+        String::from_utf8_lossy(&output.stderr).into_owned()
+    } else {
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+    let major = parse_java_major_version(&report)?;
+    if major < min_major {
+        debug!("jre: system java at {} is version {major}, below the required {min_major}", java_path.display());
+        return None;
+    }
+    debug!("jre: found compatible system java {major} at {}", java_path.display());
+    Some(java_path)
+}
+
+/// Searches PATH for a `java`/`java.exe` binary, mirroring how a shell would
+/// resolve the bare command name.
+fn which_java() -> Option<PathBuf> {
+    let name = if cfg!(target_os = "windows") {
+        "java.exe"
+    } else {
+        "java"
+    };
+    let path_var = std::env::var_os("PATH")?;
+    std::env::split_paths(&path_var)
+        .map(|dir| dir.join(name))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Parses the major version out of `java -version`'s output, e.g.
+/// `openjdk version "21.0.2" 2024-01-16` -> `21`, or the legacy
+/// `java version "1.8.0_401"` -> `8`.
+fn parse_java_major_version(report: &str) -> Option<u32> {
+    let version = report.split('"').nth(1)?;
+    let mut parts = version.split('.');
+    let first: u32 = parts.next()?.parse().ok()?;
+    if first == 1 {
+        // Legacy scheme: "1.8.0_401" means Java 8.
+        return parts.next()?.parse().ok();
+    }
+    Some(first)
+}
+
+/// Resolves which `java` binary the launcher should use: a system java on
+/// PATH if [`JreManager::ensure_jre`] was configured to use one and recorded
+/// it, otherwise the bundled runtime.
+pub fn resolve_java_binary() -> PathBuf {
+    let marker_path = env::jre_dir().join(SYSTEM_JAVA_MARKER_FILE);
+    if let Ok(recorded) = fs::read_to_string(&marker_path) {
+        let recorded = PathBuf::from(recorded.trim());
+        if recorded.is_file() {
+            return recorded;
+        }
+    }
+
+    let bin = if cfg!(target_os = "windows") {
+        Path::new("bin").join("java.exe")
+    } else {
+        Path::new("bin").join("java")
+    };
+    env::jre_dir().join(bin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering as AtomicOrdering};
+
+    static TEMP_DIR_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    /// A fresh, empty temp dir for one test. Cleaned up best-effort on drop.
+    struct TempBaseDir(PathBuf);
+
+    impl TempBaseDir {
+        fn new(tag: &str) -> Self {
+            let id = TEMP_DIR_COUNTER.fetch_add(1, AtomicOrdering::SeqCst);
+            let dir = std::env::temp_dir().join(format!(
+                "hrs-launcher-jre-test-{tag}-{}-{id}",
+                std::process::id()
+            ));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).expect("create temp base dir");
+            Self(dir)
+        }
+
+        fn jre_manager(&self) -> JreManager {
+            JreManager::new(&self.0)
+        }
+    }
+
+    impl Drop for TempBaseDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn parses_modern_version_string() {
+        let report = "openjdk version \"21.0.2\" 2024-01-16\nOpenJDK Runtime Environment";
+        assert_eq!(parse_java_major_version(report), Some(21));
+    }
+
+    #[test]
+    fn parses_legacy_1_dot_x_version_string() {
+        let report = "java version \"1.8.0_401\"\nJava(TM) SE Runtime Environment";
+        assert_eq!(parse_java_major_version(report), Some(8));
+    }
+
+    #[test]
+    fn parse_java_major_version_rejects_unrecognized_output() {
+        assert_eq!(parse_java_major_version("command not found"), None);
+    }
+
+    fn touch_java_bin(dir: &Path) {
+        let bin = dir.join("bin");
+        fs::create_dir_all(&bin).expect("create bin dir");
+        fs::write(bin.join("java"), b"#!/bin/sh\necho stub\n").expect("write java stub");
+        fs::write(bin.join("java.exe"), b"stub").expect("write java.exe stub");
+    }
+
+    #[test]
+    fn leaves_already_flat_layout_untouched() {
+        let base = TempBaseDir::new("flat");
+        let manager = base.jre_manager();
+        touch_java_bin(&manager.jre_dir);
+
+        manager.normalize_layout().expect("normalize_layout");
+
+        assert!(manager.java_path().exists());
+    }
+
+    #[test]
+    fn flattens_targz_style_single_nested_dir_alongside_extra_files() {
+        // Mirrors Adoptium's tar.gz layout: one versioned top-level dir with
+        // bin/java directly inside, plus an unrelated file (e.g. a license)
+        // sitting next to it at the top level.
+        let base = TempBaseDir::new("targz");
+        let manager = base.jre_manager();
+        let nested = manager.jre_dir.join("jdk-25.0.1+9-jre");
+        fs::create_dir_all(&nested).expect("create nested dir");
+        touch_java_bin(&nested);
+        fs::write(nested.join("release"), b"JAVA_VERSION=25").expect("write release file");
+        fs::create_dir_all(&manager.jre_dir).expect("ensure jre dir");
+        fs::write(manager.jre_dir.join("LICENSE"), b"license text").expect("write license file");
+
+        manager.normalize_layout().expect("normalize_layout");
+
+        assert!(manager.java_path().exists());
+        assert!(manager.jre_dir.join("release").exists());
+        assert!(manager.jre_dir.join("LICENSE").exists());
+        assert!(!nested.exists());
+    }
+
+    #[test]
+    fn flattens_zip_style_double_nested_dir() {
+        // Some zip tools wrap the archive's own top-level dir in an extra
+        // extraction directory, putting bin/java two levels deep.
+        let base = TempBaseDir::new("zip");
+        let manager = base.jre_manager();
+        let nested = manager.jre_dir.join("extracted").join("jdk-25.0.1+9-jre");
+        fs::create_dir_all(&nested).expect("create nested dir");
+        touch_java_bin(&nested);
+        fs::write(nested.join("release"), b"JAVA_VERSION=25").expect("write release file");
+
+        manager.normalize_layout().expect("normalize_layout");
+
+        assert!(manager.java_path().exists());
+        assert!(manager.jre_dir.join("release").exists());
+        assert!(!manager.jre_dir.join("extracted").exists());
+    }
+
+    #[test]
+    fn flattens_macos_contents_home_layout() {
+        let base = TempBaseDir::new("macos");
+        let manager = base.jre_manager();
+        let home = manager
+            .jre_dir
+            .join("jdk-25.0.1+9-jre.jdk")
+            .join("Contents")
+            .join("Home");
+        fs::create_dir_all(&home).expect("create Contents/Home dir");
+        touch_java_bin(&home);
+        fs::write(home.join("release"), b"JAVA_VERSION=25").expect("write release file");
+
+        manager.normalize_layout().expect("normalize_layout");
+
+        assert!(manager.java_path().exists());
+        assert!(manager.jre_dir.join("release").exists());
+        assert!(!manager.jre_dir.join("jdk-25.0.1+9-jre.jdk").exists());
+    }
+
+    #[test]
+    fn leaves_layout_untouched_when_no_java_binary_found() {
+        let base = TempBaseDir::new("missing");
+        let manager = base.jre_manager();
+        let nested = manager.jre_dir.join("unrelated");
+        fs::create_dir_all(&nested).expect("create unrelated dir");
+        fs::write(nested.join("readme.txt"), b"nothing here").expect("write readme");
+
+        manager.normalize_layout().expect("normalize_layout");
+
+        assert!(!manager.java_path().exists());
+        assert!(nested.exists());
+    }
+
+    /// A minimal single-purpose HTTP mock, mirroring the one in
+    /// `pwr::tests`: the download code only needs a status line and a body.
+    struct MockServer {
+        addr: std::net::SocketAddr,
+    }
+
+    impl MockServer {
+        fn start<F>(handler: F) -> Self
+        where
+            F: Fn() -> Vec<u8> + Send + Sync + 'static,
+        {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+            let addr = listener.local_addr().expect("mock server local addr");
+            let handler = std::sync::Arc::new(handler);
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let Ok(stream) = stream else { continue };
+                    let handler = handler.clone();
+                    std::thread::spawn(move || Self::serve_one(stream, handler.as_ref()));
+                }
+            });
+            Self { addr }
+        }
+
+        fn serve_one(mut stream: std::net::TcpStream, handler: &(dyn Fn() -> Vec<u8> + Send + Sync)) {
+            use std::io::{Read, Write};
+            let mut buf = [0u8; 8192];
+            let _ = stream.read(&mut buf);
+            let body = handler();
+            let head = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            );
+            let _ = stream.write_all(head.as_bytes());
+            let _ = stream.write_all(&body);
+        }
+
+        fn url(&self) -> String {
+            format!("http://{}/jre.tar.gz", self.addr)
+        }
+    }
+
+    #[tokio::test]
+    async fn download_verified_fails_after_two_checksum_mismatches() {
+        let server = MockServer::start(|| b"corrupted bytes".to_vec());
+        let base = TempBaseDir::new("integrity");
+        let manager = base.jre_manager();
+        let target = DownloadTarget {
+            url: server.url(),
+            checksum: Some(format!("{:064x}", 0)),
+            archive: ArchiveKind::TarGz,
+        };
+
+        let result = manager.download_verified(&target, None).await;
+
+        assert_eq!(result, Err(INTEGRITY_CHECK_FAILED_TWICE.to_string()));
+    }
+
+    #[tokio::test]
+    async fn download_verified_recovers_after_one_checksum_mismatch() {
+        let attempt = std::sync::Arc::new(AtomicU32::new(0));
+        let attempt_for_handler = attempt.clone();
+        let server = MockServer::start(move || {
+            if attempt_for_handler.fetch_add(1, AtomicOrdering::SeqCst) == 0 {
+                b"corrupted bytes".to_vec()
+            } else {
+                b"good bytes".to_vec()
+            }
+        });
+        let expected = format!("{:x}", Sha256::digest(b"good bytes"));
+        let base = TempBaseDir::new("recovers");
+        let manager = base.jre_manager();
+        let target = DownloadTarget {
+            url: server.url(),
+            checksum: Some(expected),
+            archive: ArchiveKind::TarGz,
+        };
+
+        let archive_path = manager
+            .download_verified(&target, None)
+            .await
+            .expect("second attempt should verify");
+
+        assert_eq!(fs::read(archive_path).expect("read archive"), b"good bytes");
+    }
+}
@@ -0,0 +1,98 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use env_logger::{Builder, Env, Target};
+
+use crate::env as app_env;
+
+const LOG_FILE_NAME: &str = "launcher.log";
+const MAX_LOG_BYTES: u64 = 5 * 1024 * 1024;
+const MAX_ROTATED_FILES: u32 = 4;
+
+/// Initializes logging to stderr and a size-rotated file under `env::logs_dir()`,
+/// combined with the usual `RUST_LOG` env-filter. Returns the active log file
+/// path so it can be surfaced in the diagnostics report.
+///
+/// # Errors
+/// Returns an error if the logs directory or log file cannot be created.
+pub fn init() -> io::Result<PathBuf> {
+    fs::create_dir_all(app_env::logs_dir())?;
+    let path = app_env::logs_dir().join(LOG_FILE_NAME);
+    let file = RotatingFileWriter::new(path.clone())?;
+
+    Builder::from_env(Env::default().default_filter_or("info"))
+        .target(Target::Pipe(Box::new(TeeWriter { file })))
+        .init();
+
+    Ok(path)
+}
+
+/// Writes log output to stderr and a size-rotated file at the same time.
+struct TeeWriter {
+    file: RotatingFileWriter,
+}
+
+impl Write for TeeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let _ = io::stderr().write_all(buf);
+        self.file.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let _ = io::stderr().flush();
+        self.file.flush()
+    }
+}
+
+/// Appends to `launcher.log`, rotating to `launcher.log.1`, `.2`, ... once the
+/// active file grows past `MAX_LOG_BYTES`, keeping at most `MAX_ROTATED_FILES`
+/// rotated files around.
+struct RotatingFileWriter {
+    path: PathBuf,
+    file: File,
+    size: u64,
+}
+
+impl RotatingFileWriter {
+    fn new(path: PathBuf) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        Ok(Self { path, file, size })
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        for index in (1..MAX_ROTATED_FILES).rev() {
+            let from = rotated_path(&self.path, index);
+            let to = rotated_path(&self.path, index + 1);
+            if from.exists() {
+                fs::rename(&from, &to)?;
+            }
+        }
+        fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        self.file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+fn rotated_path(path: &Path, index: u32) -> PathBuf {
+    let mut rotated = path.as_os_str().to_owned();
+    rotated.push(format!(".{index}"));
+    PathBuf::from(rotated)
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.size >= MAX_LOG_BYTES {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
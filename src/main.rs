@@ -3,15 +3,21 @@ use std::io::Cursor;
 use clap::Parser;
 use env_logger::Env;
 use icns::{IconFamily, PixelFormat};
+use log::warn;
 
+mod desktop_entry;
 mod diagnostics;
+mod endpoints;
 mod engine;
 mod env;
 mod jre;
+mod logging;
 mod mods;
 mod process;
+mod profile;
 mod pwr;
 mod storage;
+mod tray;
 mod ui;
 mod updater;
 mod util;
@@ -30,7 +36,10 @@ struct Cli {
 }
 
 fn main() -> eframe::Result<()> {
-    env_logger::Builder::from_env(Env::default().default_filter_or("info")).init();
+    if let Err(err) = logging::init() {
+        env_logger::Builder::from_env(Env::default().default_filter_or("info")).init();
+        warn!("failed to set up file logging ({}); logging to stderr only", err);
+    }
 
     let cli = Cli::parse();
     if cli.version_only {
@@ -51,7 +60,7 @@ fn main() -> eframe::Result<()> {
     )
 }
 
-fn app_icon() -> eframe::egui::IconData {
+pub(crate) fn app_icon() -> eframe::egui::IconData {
     load_app_icon().unwrap_or_else(default_icon)
 }
 
@@ -13,13 +13,15 @@ use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
 use walkdir::WalkDir;
 use zip::ZipArchive;
 
 use crate::env;
-use crate::util::{cancel_requested, format_speed};
+use crate::util::{SpeedTracker, cancel_requested, format_speed};
+
+pub mod queue;
 
-const CURSE_FORGE_BASE: &str = "https://api.curseforge.com/v1";
 const HYTALE_GAME_ID: u32 = 70216;
 // Public key used by hrs-launcher for browsing CurseForge.
 const CF_API_KEY: &str = "$2a$10$bL4bIL5pUWqfcO7KQtnMReakwtfHbNKh6v1uTpKlzhwoueEJQnPnm";
@@ -30,6 +32,41 @@ pub struct ModManifest {
     pub version: String,
 }
 
+/// What [`ModService::reconcile`] found and fixed.
+#[derive(Debug, Clone, Default)]
+pub struct ReconcileReport {
+    /// Names of manifest entries dropped because their file was missing.
+    pub removed_missing: Vec<String>,
+    /// File names present in the mods directory but not referenced by any
+    /// manifest entry.
+    pub untracked_files: Vec<String>,
+}
+
+impl ReconcileReport {
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.removed_missing.is_empty() && self.untracked_files.is_empty()
+    }
+}
+
+/// A single entry in an exported mod list: just enough to re-download the
+/// mod on another machine, with no local file paths.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedMod {
+    /// CurseForge mod id, or `-1` for a local-file install that can't be
+    /// re-fetched.
+    pub curseforge_id: i32,
+    pub name: String,
+    pub version: String,
+}
+
+/// Portable snapshot produced by [`ModService::export_manifest`] and
+/// consumed by [`ModService::import_manifest`].
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ModExport {
+    pub mods: Vec<ExportedMod>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InstalledMod {
     pub id: String,
@@ -48,6 +85,12 @@ pub struct InstalledMod {
     pub icon_url: Option<String>,
     pub downloads: i64,
     pub category: Option<String>,
+    #[serde(default)]
+    pub size_bytes: u64,
+    /// When this mod's files were last copied into the game's release folder
+    /// by `apply_enabled_mods`. `None` until the first launch after install.
+    #[serde(default)]
+    pub applied_at: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -106,12 +149,30 @@ pub struct ModFile {
     pub downloadUrl: String,
     #[serde(default)]
     pub fileDate: String,
+    #[serde(default)]
+    pub dependencies: Vec<ModFileDependency>,
+    #[serde(default)]
+    pub gameVersions: Vec<String>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct ModFileDependency {
+    pub modId: i32,
+    pub relationType: i32,
+}
+
+// CurseForge's FileRelationType: 3 is "RequiredDependency".
+const REQUIRED_DEPENDENCY_RELATION: i32 = 3;
+
 #[derive(Clone)]
 pub struct ModService {
     client: Client,
     mods_dir: PathBuf,
+    // Serializes manifest read-modify-write cycles so concurrent mod
+    // operations (the download queue, imports, removals) can't clobber
+    // each other's writes. Scope it tightly around load+save only, never
+    // around network downloads.
+    manifest_lock: Arc<Mutex<()>>,
 }
 
 impl ModService {
@@ -126,7 +187,11 @@ impl ModService {
                 );
                 Client::new()
             });
-        Self { client, mods_dir }
+        Self {
+            client,
+            mods_dir,
+            manifest_lock: Arc::new(Mutex::new(())),
+        }
     }
 
     pub async fn search(
@@ -135,7 +200,8 @@ impl ModService {
         page: u32,
     ) -> Result<CurseForgeResponse<Vec<CurseForgeMod>>, String> {
         let url = format!(
-            "{CURSE_FORGE_BASE}/mods/search?gameId={HYTALE_GAME_ID}&searchFilter={query}&pageSize=20&index={}",
+            "{}/mods/search?gameId={HYTALE_GAME_ID}&searchFilter={query}&pageSize=20&index={}",
+            crate::endpoints::curse_forge_base(),
             page * 20
         );
         let resp = self
@@ -153,7 +219,7 @@ impl ModService {
     }
 
     pub async fn mod_details(&self, mod_id: i32) -> Result<CurseForgeMod, String> {
-        let url = format!("{CURSE_FORGE_BASE}/mods/{mod_id}");
+        let url = format!("{}/mods/{mod_id}", crate::endpoints::curse_forge_base());
         let resp = self
             .client
             .get(&url)
@@ -170,13 +236,57 @@ impl ModService {
         Ok(wrapped.data)
     }
 
+    /// Fetches mod details and returns the newest available file for
+    /// `game_version` (see [`pick_latest_file`]), or `None` if the mod has
+    /// no files at all. Used to check whether an installed mod has an
+    /// update before fetching its changelog.
+    pub async fn latest_file(
+        &self,
+        mod_id: i32,
+        game_version: Option<&str>,
+    ) -> Result<Option<ModFile>, String> {
+        let details = self.mod_details(mod_id).await?;
+        Ok(pick_latest_file(&details, game_version))
+    }
+
+    /// Fetches the "what's new" changelog for a specific mod file, with any
+    /// HTML markup stripped down to plain text. Returns an empty string
+    /// (rather than an error) when CurseForge reports no changelog for the
+    /// file, so callers can show a "nothing to show" message instead of
+    /// treating it as a failure.
+    pub async fn file_changelog(&self, mod_id: i32, file_id: i32) -> Result<String, String> {
+        let url = format!(
+            "{}/mods/{mod_id}/files/{file_id}/changelog",
+            crate::endpoints::curse_forge_base()
+        );
+        let resp = self
+            .client
+            .get(&url)
+            .header("x-api-key", CF_API_KEY)
+            .send()
+            .await
+            .map_err(|e| format!("changelog request failed: {e}"))?
+            .error_for_status()
+            .map_err(|e| format!("changelog status error: {e}"))?;
+        let wrapped: CurseForgeResponse<String> = resp
+            .json()
+            .await
+            .map_err(|e| format!("changelog parse error: {e}"))?;
+        Ok(strip_html(&wrapped.data))
+    }
+
     /// Download the latest available file for the given mod and record it in the manifest.
+    ///
+    /// Returns the installed mod alongside the CurseForge mod IDs of any required
+    /// dependencies that are not yet installed, so callers can warn the user or
+    /// offer to install them.
     pub async fn download_latest<F>(
         &self,
         mod_id: i32,
+        game_version: Option<u32>,
         cancel: Option<Arc<AtomicBool>>,
         mut progress: F,
-    ) -> Result<InstalledMod, String>
+    ) -> Result<(InstalledMod, Vec<i32>), String>
     where
         F: FnMut(f32, &str),
     {
@@ -184,7 +294,9 @@ impl ModService {
             return Err("Download cancelled".into());
         }
         let details = self.mod_details(mod_id).await?;
-        let latest = pick_latest_file(&details).ok_or("no downloadable files for this mod")?;
+        let game_version = game_version.map(|v| v.to_string());
+        let latest = pick_latest_file(&details, game_version.as_deref())
+            .ok_or("no downloadable files for this mod")?;
         if latest.downloadUrl.is_empty() {
             return Err("mod author disabled downloads".into());
         }
@@ -210,6 +322,11 @@ impl ModService {
         )
         .await?;
 
+        let size_bytes = fs::metadata(&dest)
+            .await
+            .map(|m| m.len())
+            .unwrap_or(latest.fileLength);
+
         let author = details
             .authors
             .first()
@@ -243,12 +360,29 @@ impl ModService {
             icon_url: icon,
             downloads: details.downloadCount,
             category,
+            size_bytes,
+            applied_at: None,
         };
 
         self.upsert_manifest_entry(installed.clone()).await?;
         progress(100.0, &format!("Installed {} successfully", details.name));
 
-        Ok(installed)
+        let installed_cf_ids: std::collections::HashSet<i32> = self
+            .installed_mods()
+            .await
+            .unwrap_or_default()
+            .iter()
+            .map(|m| m.curseforge_id)
+            .collect();
+        let missing_dependencies = latest
+            .dependencies
+            .iter()
+            .filter(|dep| dep.relationType == REQUIRED_DEPENDENCY_RELATION)
+            .map(|dep| dep.modId)
+            .filter(|id| !installed_cf_ids.contains(id))
+            .collect();
+
+        Ok((installed, missing_dependencies))
     }
 
     /// Install a mod from a locally available archive by copying it into the mods directory
@@ -304,18 +438,171 @@ impl ModService {
             icon_url: None,
             downloads: 0,
             category: None,
+            size_bytes: metadata.len(),
+            applied_at: None,
         };
 
         self.upsert_manifest_entry(installed.clone()).await?;
         Ok(installed)
     }
 
+    /// Installs a mod from an arbitrary URL, for mods that don't live on
+    /// CurseForge. A CurseForge project URL (e.g.
+    /// `https://www.curseforge.com/hytale/mods/<slug>`) is resolved to its
+    /// mod id and routed through [`Self::download_latest`] for proper
+    /// metadata and dependency handling. Any other `http(s)` URL is
+    /// downloaded directly and recorded with a generic author/description,
+    /// mirroring [`Self::install_from_path`]; the response is rejected
+    /// unless the file name looks like a mod archive.
+    pub async fn install_from_url<F>(
+        &self,
+        url: &str,
+        game_version: Option<u32>,
+        cancel: Option<Arc<AtomicBool>>,
+        mut progress: F,
+    ) -> Result<(InstalledMod, Vec<i32>), String>
+    where
+        F: FnMut(f32, &str),
+    {
+        let parsed = reqwest::Url::parse(url.trim()).map_err(|e| format!("invalid URL: {e}"))?;
+        if parsed.scheme() != "http" && parsed.scheme() != "https" {
+            return Err("only http(s) URLs are supported".into());
+        }
+
+        if let Some(slug) = curseforge_slug_from_url(&parsed) {
+            let mod_id = self.mod_id_by_slug(&slug).await?;
+            return self.download_latest(mod_id, game_version, cancel, progress).await;
+        }
+
+        let file_name = parsed
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .filter(|name| !name.is_empty())
+            .unwrap_or("mod-download")
+            .to_owned();
+        if !has_mod_archive_extension(Path::new(&file_name)) {
+            return Err(format!(
+                "'{file_name}' doesn't look like a mod archive (.zip or .jar)"
+            ));
+        }
+
+        fs::create_dir_all(&self.mods_dir)
+            .await
+            .map_err(|e| format!("unable to create mods dir: {e}"))?;
+        let dest = self
+            .next_available_destination(&file_name)
+            .await
+            .map_err(|e| format!("unable to determine destination for mod file: {e}"))?;
+
+        progress(0.0, &format!("Downloading {file_name}..."));
+        self.download_file(parsed.as_str(), &dest, 0, cancel, |downloaded, total, speed| {
+            let pct = match total {
+                Some(total) if total > 0 => (downloaded as f32 / total as f32) * 100.0,
+                _ => 0.0,
+            };
+            progress(pct, &format!("Downloading {file_name}... {speed}"));
+        })
+        .await?;
+
+        let metadata = fs::metadata(&dest)
+            .await
+            .map_err(|e| format!("failed to read downloaded mod metadata: {e}"))?;
+        let now = Utc::now();
+        let timestamp = now.to_rfc3339();
+        let base_name = Path::new(&file_name)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .map(str::to_owned)
+            .unwrap_or_else(|| file_name.clone());
+        let slug = slugify(&base_name);
+
+        let installed = InstalledMod {
+            id: format!("url-{slug}-{}", now.timestamp_millis()),
+            name: base_name,
+            slug,
+            version: file_version_label(&metadata),
+            author: "Unknown".into(),
+            description: "Installed from a direct download URL.".into(),
+            download_url: parsed.to_string(),
+            curseforge_id: -1,
+            file_id: 0,
+            enabled: true,
+            installed_at: timestamp.clone(),
+            updated_at: timestamp,
+            file_path: dest.display().to_string(),
+            icon_url: None,
+            downloads: 0,
+            category: None,
+            size_bytes: metadata.len(),
+            applied_at: None,
+        };
+
+        self.upsert_manifest_entry(installed.clone()).await?;
+        progress(100.0, &format!("Installed {} successfully", installed.name));
+        Ok((installed, Vec::new()))
+    }
+
+    /// Resolves a CurseForge slug to its mod id via the search endpoint,
+    /// since the public API doesn't expose a direct slug lookup.
+    async fn mod_id_by_slug(&self, slug: &str) -> Result<i32, String> {
+        let results = self.search(slug, 0).await?;
+        results
+            .data
+            .into_iter()
+            .find(|m| m.slug == slug)
+            .map(|m| m.id)
+            .ok_or_else(|| format!("no CurseForge mod found for slug '{slug}'"))
+    }
+
     pub async fn installed_mods(&self) -> Result<Vec<InstalledMod>, String> {
         let manifest = self.load_manifest().await?;
         Ok(manifest.mods)
     }
 
+    /// Returns a portable JSON snapshot of the installed mods (CurseForge ids
+    /// and display versions only, no local file paths) so it can be moved to
+    /// another machine. Local-file installs are included with
+    /// `curseforge_id: -1`; [`Self::import_manifest`] skips those since they
+    /// can't be re-fetched.
+    pub async fn export_manifest(&self) -> Result<String, String> {
+        let installed = self.installed_mods().await?;
+        let export = ModExport {
+            mods: installed
+                .into_iter()
+                .map(|m| ExportedMod {
+                    curseforge_id: m.curseforge_id,
+                    name: m.name,
+                    version: m.version,
+                })
+                .collect(),
+        };
+        serde_json::to_string_pretty(&export).map_err(|e| format!("failed to export mod list: {e}"))
+    }
+
+    /// Parses an exported mod list and returns the CurseForge mod ids that
+    /// still need downloading. Local-file entries (`curseforge_id < 0`) are
+    /// skipped since they can't be re-fetched, and ids already present in the
+    /// manifest are skipped since they're already installed. Callers queue
+    /// the returned ids through the existing `download_latest` path.
+    pub async fn import_manifest(&self, json: &str) -> Result<Vec<i32>, String> {
+        let export: ModExport =
+            serde_json::from_str(json).map_err(|e| format!("failed to parse mod list: {e}"))?;
+        let installed_ids: std::collections::HashSet<i32> = self
+            .installed_mods()
+            .await?
+            .iter()
+            .map(|m| m.curseforge_id)
+            .collect();
+        Ok(export
+            .mods
+            .into_iter()
+            .map(|m| m.curseforge_id)
+            .filter(|id| *id >= 0 && !installed_ids.contains(id))
+            .collect())
+    }
+
     pub async fn remove_installed(&self, mod_id: &str) -> Result<(), String> {
+        let _guard = self.manifest_lock.lock().await;
         let mut manifest = self.load_manifest().await?;
         if let Some(entry) = manifest.mods.iter().find(|m| m.id == mod_id) {
             let path = PathBuf::from(&entry.file_path);
@@ -333,7 +620,82 @@ impl ModService {
         self.save_manifest(&manifest).await
     }
 
+    /// Deletes every installed mod's file and clears the manifest, leaving the
+    /// game itself untouched. Useful for ruling out a misbehaving mod without
+    /// a full reinstall.
+    pub async fn remove_all_mods(&self) -> Result<(), String> {
+        let _guard = self.manifest_lock.lock().await;
+        let manifest = self.load_manifest().await?;
+        for entry in &manifest.mods {
+            let path = PathBuf::from(&entry.file_path);
+            if path.exists() {
+                fs::remove_file(&path)
+                    .await
+                    .map_err(|e| format!("failed to delete mod file {}: {e}", path.display()))?;
+            }
+        }
+        self.save_manifest(&ModManifest::default()).await
+    }
+
+    /// Reconciles the manifest with what's actually on disk: drops entries
+    /// whose file has disappeared and reports files in the mods directory
+    /// the manifest doesn't know about, so both kinds of drift can be shown
+    /// to the user instead of silently rotting the installed list.
+    pub async fn reconcile(&self) -> Result<ReconcileReport, String> {
+        let _guard = self.manifest_lock.lock().await;
+        let mut manifest = self.load_manifest().await?;
+
+        let mut removed_missing = Vec::new();
+        manifest.mods.retain(|entry| {
+            let exists = Path::new(&entry.file_path).exists();
+            if !exists {
+                removed_missing.push(entry.name.clone());
+            }
+            exists
+        });
+        if !removed_missing.is_empty() {
+            self.save_manifest(&manifest).await?;
+        }
+
+        let known_paths: std::collections::HashSet<PathBuf> = manifest
+            .mods
+            .iter()
+            .map(|entry| PathBuf::from(&entry.file_path))
+            .collect();
+
+        let mut untracked_files = Vec::new();
+        let mut dir_entries = fs::read_dir(&self.mods_dir)
+            .await
+            .map_err(|e| format!("failed to read mods dir: {e}"))?;
+        while let Some(entry) = dir_entries
+            .next_entry()
+            .await
+            .map_err(|e| format!("failed to read mods dir entry: {e}"))?
+        {
+            let path = entry.path();
+            if fs::metadata(&path).await.map(|m| m.is_dir()).unwrap_or(false) {
+                continue;
+            }
+            let file_name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or_default();
+            if file_name == "manifest.json" || file_name == "manifest.json.bak" {
+                continue;
+            }
+            if !known_paths.contains(&path) {
+                untracked_files.push(file_name.to_owned());
+            }
+        }
+
+        Ok(ReconcileReport {
+            removed_missing,
+            untracked_files,
+        })
+    }
+
     async fn upsert_manifest_entry(&self, mod_entry: InstalledMod) -> Result<(), String> {
+        let _guard = self.manifest_lock.lock().await;
         let mut manifest = self.load_manifest().await?;
         if let Some(existing) = manifest.mods.iter_mut().find(|m| m.id == mod_entry.id) {
             *existing = mod_entry;
@@ -353,7 +715,17 @@ impl ModService {
             }
             Err(err) => return Err(format!("failed to read mod manifest: {err}")),
         };
-        serde_json::from_slice(&bytes).map_err(|e| format!("failed to parse mod manifest: {e}"))
+        match serde_json::from_slice(&bytes) {
+            Ok(manifest) => Ok(manifest),
+            Err(err) => {
+                warn!("mod manifest is corrupt, resetting to empty: {err}");
+                let backup_path = self.mods_dir.join("manifest.json.bak");
+                if let Err(err) = fs::rename(&path, &backup_path).await {
+                    warn!("failed to back up corrupt mod manifest: {err}");
+                }
+                Ok(ModManifest::default())
+            }
+        }
     }
 
     async fn save_manifest(&self, manifest: &ModManifest) -> Result<(), String> {
@@ -365,7 +737,7 @@ impl ModService {
                 .await
                 .map_err(|e| format!("failed to create manifest dir: {e}"))?;
         }
-        fs::write(&path, &bytes)
+        crate::util::write_atomic_async(&path, &bytes)
             .await
             .map_err(|e| format!("failed to write manifest: {e}"))
     }
@@ -432,7 +804,7 @@ impl ModService {
         let mut stream = resp.bytes_stream();
         let mut downloaded: u64 = 0;
         let mut last_tick = Instant::now();
-        let mut last_bytes = 0u64;
+        let mut speed_tracker = SpeedTracker::new();
 
         while let Some(chunk) = stream.next().await {
             if cancel_requested(&cancel) {
@@ -446,11 +818,10 @@ impl ModService {
             downloaded += chunk.len() as u64;
 
             if last_tick.elapsed().as_secs_f32() > 0.2 {
-                let speed = (downloaded - last_bytes) as f32 / last_tick.elapsed().as_secs_f32();
+                let speed = speed_tracker.record(downloaded).unwrap_or(0.0);
                 let speed_text = format_speed(speed);
                 progress(downloaded, total, speed_text);
                 last_tick = Instant::now();
-                last_bytes = downloaded;
             }
         }
 
@@ -474,12 +845,13 @@ impl ModService {
     pub async fn apply_enabled_mods(&self) -> Result<(), String> {
         let manifest = self.load_manifest().await?;
         let game_release_dir = env::default_app_dir().join("release");
-        
+
         // Verify game installation by checking key directories
         if !game_release_dir.exists() || !game_release_dir.join("package").exists() {
             return Err("Game not installed. Install the game before applying mods.".into());
         }
 
+        let mut applied_ids = Vec::new();
         for mod_entry in manifest.mods.iter().filter(|m| m.enabled) {
             let mod_path = PathBuf::from(&mod_entry.file_path);
             if !mod_path.exists() {
@@ -495,11 +867,46 @@ impl ModService {
             self.extract_and_apply_mod(&mod_path, &game_release_dir)
                 .await
                 .map_err(|e| format!("Failed to apply mod {}: {}", mod_entry.name, e))?;
+            applied_ids.push(mod_entry.id.clone());
+        }
+
+        if !applied_ids.is_empty() {
+            self.mark_applied(&applied_ids).await?;
         }
 
         Ok(())
     }
 
+    /// Clears `applied_at` for every enabled mod, so [`apply_enabled_mods`]
+    /// treats them as needing reapplication. Intended to be called after a
+    /// game version change, since the patched `release` folder may have
+    /// clobbered mod files copied there by a previous apply.
+    ///
+    /// [`apply_enabled_mods`]: Self::apply_enabled_mods
+    pub async fn flag_enabled_mods_for_reapply(&self) -> Result<(), String> {
+        let _guard = self.manifest_lock.lock().await;
+        let mut manifest = self.load_manifest().await?;
+        for mod_entry in manifest.mods.iter_mut().filter(|m| m.enabled) {
+            mod_entry.applied_at = None;
+        }
+        self.save_manifest(&manifest).await
+    }
+
+    /// Records the current time as `applied_at` for the given mod IDs.
+    async fn mark_applied(&self, mod_ids: &[String]) -> Result<(), String> {
+        let _guard = self.manifest_lock.lock().await;
+        let mut manifest = self.load_manifest().await?;
+        let now = Utc::now().to_rfc3339();
+        for mod_entry in manifest
+            .mods
+            .iter_mut()
+            .filter(|m| mod_ids.contains(&m.id))
+        {
+            mod_entry.applied_at = Some(now.clone());
+        }
+        self.save_manifest(&manifest).await
+    }
+
     /// Extract a mod archive and apply it to the game folder.
     /// Looks for "install/release" structure inside the mod archive.
     async fn extract_and_apply_mod(
@@ -617,6 +1024,14 @@ impl ModService {
     }
 
     /// Recursively copy a directory and its contents to a destination.
+    ///
+    /// Uses a filesystem reflink (copy-on-write clone) per file when the
+    /// destination volume supports one, falling back to a plain copy
+    /// otherwise or across devices. `src` here is always our own temp
+    /// extraction dir rather than the mod's downloaded archive, so a reflink
+    /// shares blocks with a file we're about to delete anyway - the game
+    /// later writing to its copy breaks the copy-on-write sharing rather
+    /// than touching anything else.
     async fn copy_dir_recursive(&self, src: &Path, dst: &Path) -> Result<(), String> {
         let src = src.to_owned();
         let dst = dst.to_owned();
@@ -625,7 +1040,7 @@ impl ModService {
             for entry in WalkDir::new(&src).min_depth(1) {
                 let entry = entry.map_err(|e| format!("Failed to read directory entry: {e}"))?;
                 let path = entry.path();
-                
+
                 let relative_path = path
                     .strip_prefix(&src)
                     .map_err(|e| format!("Failed to compute relative path: {e}"))?;
@@ -639,7 +1054,12 @@ impl ModService {
                         std::fs::create_dir_all(parent)
                             .map_err(|e| format!("Failed to create parent directory: {e}"))?;
                     }
-                    std::fs::copy(path, &target_path)
+                    if target_path.exists() {
+                        std::fs::remove_file(&target_path).map_err(|e| {
+                            format!("Failed to replace existing file {}: {e}", target_path.display())
+                        })?;
+                    }
+                    reflink_copy::reflink_or_copy(path, &target_path)
                         .map_err(|e| format!("Failed to copy file {}: {e}", path.display()))?;
                 }
             }
@@ -656,7 +1076,53 @@ impl ModService {
     }
 }
 
-fn pick_latest_file(details: &CurseForgeMod) -> Option<ModFile> {
+/// Strips HTML markup from a CurseForge changelog, keeping one line per
+/// `<p>`/`<li>` and falling back to the raw text content when neither is
+/// present. Whitespace within each line is collapsed to single spaces.
+fn strip_html(html: &str) -> String {
+    let fragment = scraper::Html::parse_fragment(html);
+    let Ok(selector) = scraper::Selector::parse("li, p") else {
+        return String::new();
+    };
+    let lines: Vec<String> = fragment
+        .select(&selector)
+        .map(|el| el.text().collect::<Vec<_>>().join(" ").split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect();
+    if !lines.is_empty() {
+        return lines.join("\n");
+    }
+    fragment
+        .root_element()
+        .text()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Picks the newest file, preferring one compatible with `game_version` (as
+/// advertised by CurseForge's `gameVersions` list) when one is given. Falls
+/// back to the newest file overall if none declare support for it.
+fn pick_latest_file(details: &CurseForgeMod, game_version: Option<&str>) -> Option<ModFile> {
+    if let Some(version) = game_version {
+        let compatible = details
+            .latestFiles
+            .iter()
+            .filter(|f| f.gameVersions.iter().any(|v| v == version))
+            .max_by_key(|f| &f.fileDate)
+            .cloned();
+        if compatible.is_some() {
+            return compatible;
+        }
+        if !details.latestFiles.is_empty() {
+            warn!(
+                "mods: no file of '{}' declares support for game version {version}; falling back to newest overall",
+                details.name
+            );
+        }
+    }
     details
         .latestFiles
         .iter()
@@ -664,6 +1130,25 @@ fn pick_latest_file(details: &CurseForgeMod) -> Option<ModFile> {
         .cloned()
 }
 
+/// Extracts the project slug from a CurseForge project URL, e.g.
+/// `https://www.curseforge.com/hytale/mods/<slug>` (with an optional
+/// trailing `/files/...`). Returns `None` for any other host.
+fn curseforge_slug_from_url(url: &reqwest::Url) -> Option<String> {
+    let host = url.host_str()?;
+    if !host.eq_ignore_ascii_case("curseforge.com") && !host.eq_ignore_ascii_case("www.curseforge.com") {
+        return None;
+    }
+    let segments: Vec<&str> = url.path_segments()?.filter(|s| !s.is_empty()).collect();
+    let mods_index = segments.iter().position(|s| *s == "mods")?;
+    segments.get(mods_index + 1).map(|s| (*s).to_owned())
+}
+
+fn has_mod_archive_extension(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("zip") || ext.eq_ignore_ascii_case("jar"))
+}
+
 fn slugify(name: &str) -> String {
     let mut slug = String::with_capacity(name.len());
     let mut last_dash = false;
@@ -695,3 +1180,125 @@ fn file_version_label(metadata: &std::fs::Metadata) -> String {
         .map(|dt| format!("local {}", dt.format("%Y-%m-%d %H:%M")))
         .unwrap_or_else(|| "local file".into())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering as AtomicOrdering};
+
+    static TEMP_DIR_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    /// A fresh, empty temp dir for one test. Cleaned up best-effort on drop.
+    struct TempModsDir(PathBuf);
+
+    impl TempModsDir {
+        fn new() -> Self {
+            let id = TEMP_DIR_COUNTER.fetch_add(1, AtomicOrdering::SeqCst);
+            let dir = std::env::temp_dir().join(format!(
+                "hrs-launcher-mods-test-{}-{id}",
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).expect("create temp mods dir");
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempModsDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn sample_mod(id: &str) -> InstalledMod {
+        InstalledMod {
+            id: id.to_owned(),
+            name: id.to_owned(),
+            slug: id.to_owned(),
+            version: "1.0".into(),
+            author: "tester".into(),
+            description: String::new(),
+            download_url: String::new(),
+            curseforge_id: 0,
+            file_id: 0,
+            enabled: true,
+            installed_at: "2024-01-01T00:00:00Z".into(),
+            updated_at: "2024-01-01T00:00:00Z".into(),
+            file_path: String::new(),
+            icon_url: None,
+            downloads: 0,
+            category: None,
+            size_bytes: 0,
+            applied_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn concurrent_upserts_all_survive() {
+        let temp = TempModsDir::new();
+        let service = ModService::new(temp.0.clone());
+
+        let handles: Vec<_> = (0..20)
+            .map(|i| {
+                let service = service.clone();
+                tokio::spawn(async move {
+                    service
+                        .upsert_manifest_entry(sample_mod(&format!("mod-{i}")))
+                        .await
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle
+                .await
+                .expect("task should not panic")
+                .expect("upsert should succeed");
+        }
+
+        let manifest = service.load_manifest().await.expect("manifest should load");
+        assert_eq!(manifest.mods.len(), 20);
+    }
+
+    #[test]
+    fn extracts_curseforge_slug_from_project_url() {
+        let url = reqwest::Url::parse("https://www.curseforge.com/hytale/mods/some-cool-mod").unwrap();
+        assert_eq!(curseforge_slug_from_url(&url), Some("some-cool-mod".to_owned()));
+
+        let with_files = reqwest::Url::parse("https://curseforge.com/hytale/mods/some-cool-mod/files/123").unwrap();
+        assert_eq!(curseforge_slug_from_url(&with_files), Some("some-cool-mod".to_owned()));
+    }
+
+    #[test]
+    fn ignores_non_curseforge_urls_for_slug_extraction() {
+        let url = reqwest::Url::parse("https://example.com/downloads/mod.zip").unwrap();
+        assert_eq!(curseforge_slug_from_url(&url), None);
+    }
+
+    #[test]
+    fn recognizes_archive_extensions_case_insensitively() {
+        assert!(has_mod_archive_extension(Path::new("mod.zip")));
+        assert!(has_mod_archive_extension(Path::new("Mod.JAR")));
+        assert!(!has_mod_archive_extension(Path::new("readme.txt")));
+        assert!(!has_mod_archive_extension(Path::new("mod")));
+    }
+
+    #[test]
+    fn strip_html_joins_paragraphs_and_list_items_one_per_line() {
+        let html = "<p>Fixed a crash.</p><ul><li>Improved   load times</li><li>Updated translations</li></ul>";
+        assert_eq!(
+            strip_html(html),
+            "Fixed a crash.\nImproved load times\nUpdated translations"
+        );
+    }
+
+    #[test]
+    fn strip_html_falls_back_to_raw_text_without_p_or_li_tags() {
+        assert_eq!(strip_html("<div>Just some text</div>"), "Just some text");
+    }
+
+    #[test]
+    fn strip_html_handles_empty_input() {
+        assert_eq!(strip_html(""), "");
+    }
+}
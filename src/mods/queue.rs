@@ -0,0 +1,249 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+use tokio::sync::{Mutex, Notify, Semaphore};
+
+use crate::mods::{InstalledMod, ModService};
+
+/// How many mods download at once. Kept low since CurseForge mod files and
+/// the game client compete for the same bandwidth.
+const MAX_CONCURRENT_DOWNLOADS: usize = 2;
+
+/// Result of one mod finishing its download, plus how far the batch it
+/// belongs to has progressed.
+#[derive(Debug)]
+pub struct ModDownloadOutcome {
+    pub mod_id: i32,
+    pub result: Result<InstalledMod, String>,
+    /// CurseForge mod IDs of required dependencies that aren't installed yet.
+    /// Only ever non-empty when `result` is `Ok`.
+    pub missing_dependencies: Vec<i32>,
+    pub completed: u32,
+    pub total: u32,
+}
+
+struct Inner {
+    pending: Mutex<VecDeque<(i32, Option<u32>)>>,
+    notify: Notify,
+    semaphore: Arc<Semaphore>,
+    cancelled: Arc<AtomicBool>,
+    total: AtomicU32,
+    completed: AtomicU32,
+    /// Set by `run` once it starts, so `cancel` can report an outcome for
+    /// mods it drops before they ever reach `run`'s worker task.
+    outcomes: Mutex<Option<tokio::sync::mpsc::UnboundedSender<ModDownloadOutcome>>>,
+}
+
+/// Queue of mod IDs awaiting download, processed with bounded concurrency
+/// so installing several mods from search results doesn't serialize behind
+/// a single download. Intended to be built once and shared; `run` drives it
+/// forever in a background task while `enqueue`/`cancel` are called from
+/// the UI as the user queues or cancels installs.
+#[derive(Clone)]
+pub struct ModDownloadQueue {
+    inner: Arc<Inner>,
+}
+
+impl ModDownloadQueue {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                pending: Mutex::new(VecDeque::new()),
+                notify: Notify::new(),
+                semaphore: Arc::new(Semaphore::new(MAX_CONCURRENT_DOWNLOADS)),
+                cancelled: Arc::new(AtomicBool::new(false)),
+                total: AtomicU32::new(0),
+                completed: AtomicU32::new(0),
+                outcomes: Mutex::new(None),
+            }),
+        }
+    }
+
+    /// Queues a mod for download, un-cancelling the queue first so a fresh
+    /// install request after a previous cancel is honoured. `game_version`
+    /// is used to prefer a file built for that version, if one exists.
+    pub async fn enqueue(&self, mod_id: i32, game_version: Option<u32>) {
+        self.inner.cancelled.store(false, Ordering::SeqCst);
+        self.inner.total.fetch_add(1, Ordering::SeqCst);
+        self.inner
+            .pending
+            .lock()
+            .await
+            .push_back((mod_id, game_version));
+        // `notify_one` (unlike `notify_waiters`) stores a permit when `run`
+        // hasn't registered its wait yet, so a wakeup can never be dropped
+        // in the gap between `run` finding `pending` empty and it awaiting
+        // `notified()`. `run` is the only consumer, so a single permit is
+        // always enough to wake it.
+        self.inner.notify.notify_one();
+    }
+
+    /// Drops every mod that hasn't started downloading yet and marks
+    /// in-flight downloads cancelled so they stop at their next check.
+    /// Reports a cancelled outcome for each dropped mod so the UI can clear
+    /// its per-mod "downloading" state for ids that never reached `run`'s
+    /// worker task.
+    pub async fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        let dropped: Vec<i32> = {
+            let mut pending = self.inner.pending.lock().await;
+            self.inner
+                .total
+                .fetch_sub(pending.len() as u32, Ordering::SeqCst);
+            pending.drain(..).map(|(mod_id, _)| mod_id).collect()
+        };
+        if dropped.is_empty() {
+            return;
+        }
+        let Some(outcomes) = self.inner.outcomes.lock().await.clone() else {
+            return;
+        };
+        let completed = self.inner.completed.load(Ordering::SeqCst);
+        let total = self.inner.total.load(Ordering::SeqCst).max(completed);
+        for mod_id in dropped {
+            let _ = outcomes.send(ModDownloadOutcome {
+                mod_id,
+                result: Err("download cancelled".to_string()),
+                missing_dependencies: Vec::new(),
+                completed,
+                total,
+            });
+        }
+    }
+
+    /// Runs forever, handing queued mod IDs to `service.download_latest`
+    /// with at most [`MAX_CONCURRENT_DOWNLOADS`] in flight. Meant to be
+    /// spawned once; each finished download is reported on `outcomes`.
+    pub async fn run(&self, service: ModService, outcomes: tokio::sync::mpsc::UnboundedSender<ModDownloadOutcome>) {
+        *self.inner.outcomes.lock().await = Some(outcomes.clone());
+        loop {
+            let next = self.inner.pending.lock().await.pop_front();
+            let Some((mod_id, game_version)) = next else {
+                self.inner.notify.notified().await;
+                continue;
+            };
+            if self.inner.cancelled.load(Ordering::SeqCst) {
+                self.inner.total.fetch_sub(1, Ordering::SeqCst);
+                continue;
+            }
+
+            let Ok(permit) = self.inner.semaphore.clone().acquire_owned().await else {
+                return;
+            };
+            let service = service.clone();
+            let outcomes = outcomes.clone();
+            let inner = self.inner.clone();
+            tokio::spawn(async move {
+                let _permit = permit;
+                let cancel = inner.cancelled.clone();
+                let (result, missing_dependencies) = match service
+                    .download_latest(mod_id, game_version, Some(cancel), |_, _| {})
+                    .await
+                {
+                    Ok((installed, missing)) => (Ok(installed), missing),
+                    Err(err) => (Err(err), Vec::new()),
+                };
+                let completed = inner.completed.fetch_add(1, Ordering::SeqCst) + 1;
+                let total = inner.total.load(Ordering::SeqCst).max(completed);
+                let _ = outcomes.send(ModDownloadOutcome {
+                    mod_id,
+                    result,
+                    missing_dependencies,
+                    completed,
+                    total,
+                });
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn drain_outcomes(
+        rx: &mut tokio::sync::mpsc::UnboundedReceiver<ModDownloadOutcome>,
+    ) -> Vec<ModDownloadOutcome> {
+        let mut outcomes = Vec::new();
+        while let Ok(outcome) = rx.try_recv() {
+            outcomes.push(outcome);
+        }
+        outcomes
+    }
+
+    #[tokio::test]
+    async fn enqueue_then_cancel_before_run_reports_cancelled_outcome_for_pending_items() {
+        let queue = ModDownloadQueue::new();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        *queue.inner.outcomes.lock().await = Some(tx);
+
+        queue.enqueue(1, None).await;
+        queue.enqueue(2, None).await;
+        queue.cancel().await;
+
+        let outcomes = drain_outcomes(&mut rx);
+        let mut ids: Vec<i32> = outcomes.iter().map(|o| o.mod_id).collect();
+        ids.sort();
+        assert_eq!(ids, vec![1, 2]);
+        assert!(outcomes.iter().all(|o| o.result.is_err()));
+        assert_eq!(queue.inner.total.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn cancel_during_in_flight_download_does_not_double_decrement_total() {
+        let queue = ModDownloadQueue::new();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        *queue.inner.outcomes.lock().await = Some(tx);
+
+        queue.enqueue(1, None).await;
+        // Simulate `run` having already popped this mod off `pending` to
+        // start its download, the way it does at the top of its loop.
+        let popped = queue.inner.pending.lock().await.pop_front();
+        assert!(popped.is_some());
+
+        queue.cancel().await;
+
+        // The mod is no longer in `pending`, so `cancel` must leave `total`
+        // alone for it; only the worker task that finishes it should ever
+        // account for it again.
+        assert_eq!(queue.inner.total.load(Ordering::SeqCst), 1);
+        assert!(drain_outcomes(&mut rx).is_empty());
+
+        let completed = queue.inner.completed.fetch_add(1, Ordering::SeqCst) + 1;
+        assert_eq!(queue.inner.total.load(Ordering::SeqCst).max(completed), 1);
+    }
+
+    #[tokio::test]
+    async fn burst_of_enqueues_before_run_starts_never_loses_an_item() {
+        let queue = ModDownloadQueue::new();
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+
+        for mod_id in 0..50 {
+            queue.enqueue(mod_id, None).await;
+        }
+        assert_eq!(queue.inner.total.load(Ordering::SeqCst), 50);
+        // Marking the queue cancelled makes `run` drop every pending mod
+        // with a plain decrement instead of reaching `service.download_latest`,
+        // so this test can drive the real pop/notify loop without a network.
+        queue.inner.cancelled.store(true, Ordering::SeqCst);
+
+        let run_queue = queue.clone();
+        let service = ModService::new(std::env::temp_dir());
+        let handle = tokio::spawn(async move { run_queue.run(service, tx).await });
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+        while queue.inner.total.load(Ordering::SeqCst) != 0 && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert_eq!(
+            queue.inner.total.load(Ordering::SeqCst),
+            0,
+            "every burst-enqueued mod should have been drained; none should be lost to a missed notify"
+        );
+        handle.abort();
+    }
+}
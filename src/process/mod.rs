@@ -1,11 +1,57 @@
 use std::{
     env as std_env,
+    ffi::OsStr,
+    path::Path,
     process::{Command, Stdio},
 };
 
 use crate::env;
+use crate::profile::{self, DEFAULT_PROFILE_NAME};
 use log::{debug, info, warn};
-use sysinfo::System;
+use sysinfo::{ProcessesToUpdate, System};
+
+/// JVM garbage collector choice, injected as a `-XX:+UseXXXGC` flag.
+/// `Default` leaves the JVM's own default collector in place.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GarbageCollector {
+    #[default]
+    Default,
+    G1,
+    Zgc,
+    Shenandoah,
+}
+
+impl GarbageCollector {
+    /// The stable key used to persist this choice to disk.
+    #[must_use]
+    pub fn key(self) -> &'static str {
+        match self {
+            GarbageCollector::Default => "default",
+            GarbageCollector::G1 => "g1",
+            GarbageCollector::Zgc => "zgc",
+            GarbageCollector::Shenandoah => "shenandoah",
+        }
+    }
+
+    #[must_use]
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "g1" => GarbageCollector::G1,
+            "zgc" => GarbageCollector::Zgc,
+            "shenandoah" => GarbageCollector::Shenandoah,
+            _ => GarbageCollector::Default,
+        }
+    }
+
+    fn flag(self) -> Option<&'static str> {
+        match self {
+            GarbageCollector::Default => None,
+            GarbageCollector::G1 => Some("-XX:+UseG1GC"),
+            GarbageCollector::Zgc => Some("-XX:+UseZGC"),
+            GarbageCollector::Shenandoah => Some("-XX:+UseShenandoahGC"),
+        }
+    }
+}
 
 #[derive(Clone, Default)]
 pub struct ProcessLauncher;
@@ -15,7 +61,20 @@ impl ProcessLauncher {
         Self
     }
 
-    pub fn launch(&self, version: &str, player_name: &str, auth_mode: &str) -> Result<(), String> {
+    #[allow(clippy::too_many_arguments)]
+    // Launch pulls together every per-run override; a params struct would
+    // just move the same fields one level out for a single call site.
+    pub fn launch(
+        &self,
+        version: &str,
+        player_name: &str,
+        auth_mode: &str,
+        profile: &str,
+        extra_args: &[String],
+        max_memory_gb: Option<u32>,
+        min_memory_gb: Option<u32>,
+        gc: GarbageCollector,
+    ) -> Result<std::process::Child, String> {
         let base_dir = env::default_app_dir();
         let version_dir = env::game_version_dir(version);
         let game_dir = if version_dir.exists() {
@@ -45,15 +104,18 @@ impl ProcessLauncher {
             ));
         }
 
-        let user_dir = base_dir.join("UserData");
+        // The default profile keeps the pre-multi-profile path for backward compatibility.
+        let user_dir = if profile == DEFAULT_PROFILE_NAME {
+            base_dir.join("UserData")
+        } else {
+            base_dir
+                .join("UserData")
+                .join(profile::sanitize_dir_name(profile))
+        };
         std::fs::create_dir_all(&user_dir)
             .map_err(|e| format!("failed to ensure user data dir: {e}"))?;
 
-        let jre_path = if cfg!(target_os = "windows") {
-            env::jre_dir().join("bin").join("java.exe")
-        } else {
-            env::jre_dir().join("bin").join("java")
-        };
+        let jre_path = crate::jre::resolve_java_binary();
         if !jre_path.exists() {
             warn!("launch: Java runtime missing at {}", jre_path.display());
             return Err(format!("Java runtime not found at {}", jre_path.display()));
@@ -64,13 +126,14 @@ impl ProcessLauncher {
             version, player_name, auth_mode
         );
         debug!(
-            "launch: game_dir={} jre_path={} user_dir={}",
+            "launch: game_dir={} jre_path={} user_dir={} extra_args={:?}",
             game_dir.display(),
             jre_path.display(),
-            user_dir.display()
+            user_dir.display(),
+            extra_args
         );
 
-        let java_env = compute_java_options()
+        let java_env = compute_java_options(max_memory_gb, min_memory_gb, gc)
             .map(|opts| merge_java_options(std_env::var("JDK_JAVA_OPTIONS").ok(), &opts));
 
         let mut cmd = if cfg!(target_os = "macos") {
@@ -90,7 +153,8 @@ impl ProcessLauncher {
                 .arg("--uuid")
                 .arg("00000000-1337-1337-1337-000000000000")
                 .arg("--name")
-                .arg(player_name);
+                .arg(player_name)
+                .args(extra_args);
             command
         } else {
             let mut command = Command::new(&client_path);
@@ -106,7 +170,8 @@ impl ProcessLauncher {
                 .arg("--uuid")
                 .arg("00000000-1337-1337-1337-000000000000")
                 .arg("--name")
-                .arg(player_name);
+                .arg(player_name)
+                .args(extra_args);
 
             #[cfg(target_os = "windows")]
             {
@@ -138,36 +203,153 @@ impl ProcessLauncher {
             debug!("launch: skipping JDK_JAVA_OPTIONS; unable to determine system resources");
         }
 
-        cmd.spawn()
+        let child = cmd
+            .spawn()
             .map_err(|e| format!("failed to start game process: {e}"))?;
         info!("launch: process started");
-        Ok(())
+        Ok(child)
     }
 }
 
-fn compute_java_options() -> Option<String> {
-    // Derive JVM tuning flags from available system resources.
+/// Runs `<jre>/bin/java -version` and returns the reported version string.
+/// Useful for catching a corrupted JRE extraction before it causes a
+/// confusing game launch failure.
+pub async fn test_java() -> Result<String, String> {
+    tokio::task::spawn_blocking(|| {
+        let jre_path = crate::jre::resolve_java_binary();
+        if !jre_path.exists() {
+            return Err(format!("Java runtime not found at {}", jre_path.display()));
+        }
+
+        let output = Command::new(&jre_path)
+            .arg("-version")
+            .output()
+            .map_err(|e| format!("failed to run java -version: {e}"))?;
+
+        // `java -version` reports to stderr on every JDK we've seen; fall
+        // back to stdout in case that ever changes.
+        let mut report = String::from_utf8_lossy(&output.stderr).trim().to_owned();
+        if report.is_empty() {
+            report = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+        }
+
+        if output.status.success() {
+            Ok(report)
+        } else if cfg!(target_os = "macos") {
+            Err(format!(
+                "java failed to start ({}): {}. If the runtime was just downloaded, \
+                 macOS Gatekeeper may be blocking it; try running \
+                 `xattr -dr com.apple.quarantine \"{}\"` and launching again.",
+                output.status,
+                if report.is_empty() { "no output" } else { &report },
+                jre_path.display()
+            ))
+        } else if report.is_empty() {
+            Err(format!("java -version exited with status {}", output.status))
+        } else {
+            Err(report)
+        }
+    })
+    .await
+    .map_err(|e| {
+        if e.is_panic() {
+            format!("java test task panicked: {e}")
+        } else {
+            format!("java test task cancelled: {e}")
+        }
+    })?
+}
+
+/// Total system memory in whole gigabytes, for validating user-supplied
+/// memory overrides. `None` if the host's memory couldn't be determined.
+#[must_use]
+pub fn system_memory_gb() -> Option<u32> {
     let mut system = System::new();
     system.refresh_memory();
-
     let total_bytes = system.total_memory();
-    let available_bytes = system.available_memory();
-    if total_bytes == 0 || available_bytes == 0 {
-        return None;
-    }
+    (total_bytes > 0).then_some((total_bytes / (1024 * 1024 * 1024)) as u32)
+}
+
+/// PID of a running game client process, if one is found. Used at launcher
+/// startup to detect a game started outside the launcher (or left over from
+/// a previous launcher session), so the Play button and "game running"
+/// indicator are correct from the first frame.
+///
+/// Matches on the exact process name and, where the OS reports it, cross-checks
+/// the executable's file name to avoid false positives from an unrelated
+/// process that happens to share the name.
+#[must_use]
+pub fn find_running_game_pid() -> Option<u32> {
+    let expected_name: &OsStr = if cfg!(target_os = "windows") {
+        OsStr::new("HytaleClient.exe")
+    } else {
+        OsStr::new("HytaleClient")
+    };
+
+    let mut system = System::new_all();
+    system.refresh_processes(ProcessesToUpdate::All, true);
+
+    system
+        .processes_by_exact_name(expected_name)
+        .find(|process| {
+            process
+                .exe()
+                .and_then(Path::file_name)
+                .is_none_or(|exe_name| exe_name == expected_name)
+        })
+        .map(|process| process.pid().as_u32())
+}
 
-    let max_ram_percent =
-        ((available_bytes as f64 / total_bytes as f64) * 100.0 - 10.0).clamp(40.0, 80.0);
-    let initial_ram_percent = (max_ram_percent * 0.6).clamp(25.0, 60.0);
+/// Whether a process with the given pid is still alive. Used to watch a
+/// game process the launcher didn't itself spawn (so there's no `Child` to
+/// `wait()` on), such as one detected already running at startup.
+#[must_use]
+pub fn pid_is_running(pid: u32) -> bool {
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::Some(&[sysinfo::Pid::from_u32(pid)]), true);
+    system.process(sysinfo::Pid::from_u32(pid)).is_some()
+}
+
+fn compute_java_options(
+    max_memory_gb: Option<u32>,
+    min_memory_gb: Option<u32>,
+    gc: GarbageCollector,
+) -> Option<String> {
     let cpu_count = std::thread::available_parallelism()
         .map(|count| count.get())
         .unwrap_or(1);
 
-    Some(format!(
-        "-XX:+UseStringDeduplication -XX:ActiveProcessorCount={} \
-         -XX:MaxRAMPercentage={:.1} -XX:InitialRAMPercentage={:.1}",
-        cpu_count, max_ram_percent, initial_ram_percent
-    ))
+    // Explicit -Xmx/-Xms take priority over the adaptive percentages below.
+    let memory_flags = if let Some(max_gb) = max_memory_gb {
+        let min_gb = min_memory_gb.unwrap_or(max_gb);
+        format!("-Xmx{max_gb}g -Xms{min_gb}g")
+    } else {
+        let mut system = System::new();
+        system.refresh_memory();
+
+        let total_bytes = system.total_memory();
+        let available_bytes = system.available_memory();
+        if total_bytes == 0 || available_bytes == 0 {
+            return None;
+        }
+
+        let max_ram_percent =
+            ((available_bytes as f64 / total_bytes as f64) * 100.0 - 10.0).clamp(40.0, 80.0);
+        let initial_ram_percent = (max_ram_percent * 0.6).clamp(25.0, 60.0);
+        format!(
+            "-XX:MaxRAMPercentage={max_ram_percent:.1} -XX:InitialRAMPercentage={initial_ram_percent:.1}"
+        )
+    };
+
+    let mut options = format!(
+        "-XX:+UseStringDeduplication -XX:ActiveProcessorCount={cpu_count} {memory_flags}"
+    );
+    if let Some(gc_flag) = gc.flag() {
+        options.push(' ');
+        options.push_str(gc_flag);
+    }
+
+    Some(options)
 }
 
 fn merge_java_options(existing: Option<String>, computed: &str) -> String {
@@ -177,6 +359,8 @@ fn merge_java_options(existing: Option<String>, computed: &str) -> String {
     let skip_cpu = merged.contains("ActiveProcessorCount");
     let skip_dedupe = merged.contains("UseStringDeduplication");
     let skip_gc = merged.contains("Use") && merged.contains("GC");
+    let skip_xmx = merged.contains("-Xmx");
+    let skip_xms = merged.contains("-Xms");
 
     for token in computed.split_whitespace() {
         let include = match token {
@@ -185,6 +369,8 @@ fn merge_java_options(existing: Option<String>, computed: &str) -> String {
             opt if opt.contains("InitialRAMPercentage") => !skip_initial,
             opt if opt.contains("ActiveProcessorCount") => !skip_cpu,
             opt if opt.contains("UseStringDeduplication") => !skip_dedupe,
+            opt if opt.starts_with("-Xmx") => !skip_xmx,
+            opt if opt.starts_with("-Xms") => !skip_xms,
             _ => true,
         };
         if include {
@@ -197,3 +383,33 @@ fn merge_java_options(existing: Option<String>, computed: &str) -> String {
 
     merged
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn_short_lived_process() -> std::process::Child {
+        let mut command = if cfg!(target_os = "windows") {
+            let mut command = Command::new("cmd");
+            command.args(["/C", "exit", "0"]);
+            command
+        } else {
+            Command::new("true")
+        };
+        command.spawn().expect("spawn short-lived process")
+    }
+
+    #[test]
+    fn pid_is_running_reports_true_for_the_current_process() {
+        assert!(pid_is_running(std::process::id()));
+    }
+
+    #[test]
+    fn pid_is_running_reports_false_once_the_process_has_exited() {
+        let mut child = spawn_short_lived_process();
+        let pid = child.id();
+        child.wait().expect("wait for process to exit");
+
+        assert!(!pid_is_running(pid));
+    }
+}
@@ -0,0 +1,92 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::engine::state::AuthMode;
+use crate::env;
+
+/// Name of the implicit profile used when a player has never created one.
+pub const DEFAULT_PROFILE_NAME: &str = "Default";
+
+/// A named set of player settings, persisted independently so multiple
+/// people can share one installation without clobbering each other's state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    pub player_name: String,
+    pub auth_mode: AuthMode,
+    pub selected_version: Option<u32>,
+}
+
+impl Profile {
+    #[must_use]
+    pub fn new(name: impl Into<String>, player_name: String, auth_mode: AuthMode, selected_version: Option<u32>) -> Self {
+        Self {
+            name: name.into(),
+            player_name,
+            auth_mode,
+            selected_version,
+        }
+    }
+}
+
+fn profiles_dir() -> PathBuf {
+    env::config_dir().join("profiles")
+}
+
+/// Sanitizes a profile name for use as a filesystem path segment.
+#[must_use]
+pub fn sanitize_dir_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' || c == '-' { c } else { '_' })
+        .collect()
+}
+
+fn profile_file_name(name: &str) -> String {
+    format!("{}.json", sanitize_dir_name(name))
+}
+
+fn profile_path(name: &str) -> PathBuf {
+    profiles_dir().join(profile_file_name(name))
+}
+
+/// Lists the names of every profile saved on disk, in no particular order.
+#[must_use]
+pub fn list_profiles() -> Vec<String> {
+    let Ok(entries) = fs::read_dir(profiles_dir()) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                return None;
+            }
+            let profile = load_profile_file(&path)?;
+            Some(profile.name)
+        })
+        .collect()
+}
+
+fn load_profile_file(path: &std::path::Path) -> Option<Profile> {
+    let raw = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&raw).ok()
+}
+
+/// Loads a profile by name, if it has been saved before.
+#[must_use]
+pub fn load_profile(name: &str) -> Option<Profile> {
+    load_profile_file(&profile_path(name))
+}
+
+/// Saves a profile to disk as JSON, creating the profiles directory if needed.
+pub fn save_profile(profile: &Profile) -> Result<(), String> {
+    let dir = profiles_dir();
+    fs::create_dir_all(&dir).map_err(|err| format!("failed to create profiles dir: {err}"))?;
+    let contents = serde_json::to_string_pretty(profile)
+        .map_err(|err| format!("failed to serialize profile: {err}"))?;
+    crate::util::write_atomic(&profile_path(&profile.name), contents.as_bytes())
+        .map_err(|err| format!("failed to save profile {}: {err}", profile.name))
+}
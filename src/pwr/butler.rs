@@ -10,29 +10,45 @@ use tokio::io::AsyncWriteExt;
 use zip::read::ZipArchive;
 
 use crate::env;
-use crate::util::{format_speed, progress_percent};
+use crate::util::{SpeedTracker, format_eta, format_speed, progress_percent};
 
 use super::{ProgressCallback, ProgressUpdate};
 
 const BROTH_URL: &str = "https://broth.itch.zone/butler/{os}-{arch}/LATEST/archive/default";
+const VERSION_MARKER_FILE: &str = "verified_version.txt";
 
-/// Ensure the Butler binary is available, downloading and extracting if needed.
+/// Ensure the Butler binary is available, downloading and extracting if
+/// needed, and confirm it actually runs (corrupt downloads or, on macOS,
+/// Gatekeeper quarantine can leave a binary on disk that won't execute).
 pub async fn install_butler(mut progress: ProgressCallback<'_>) -> Result<PathBuf, String> {
     let dir = env::butler_dir();
     let path = butler_path(&dir);
 
     if path.exists() {
-        emit_progress(
-            &mut progress,
-            ProgressUpdate {
-                stage: "butler",
-                progress: 100.0,
-                message: "Butler ready".into(),
-                current_file: None,
-                speed: None,
-            },
-        );
-        return Ok(path);
+        match verify_butler(&path).await {
+            Ok(version) => {
+                cache_verified_version(&dir, &version);
+                emit_progress(
+                    &mut progress,
+                    ProgressUpdate {
+                        stage: "butler",
+                        progress: 100.0,
+                        message: "Butler ready".into(),
+                        current_file: None,
+                        speed: None,
+                        eta: None,
+                    },
+                );
+                return Ok(path);
+            }
+            Err(err) => {
+                warn!(
+                    "install_butler: existing butler failed verification ({}); reinstalling",
+                    err
+                );
+                let _ = fs::remove_dir_all(&dir);
+            }
+        }
     }
 
     fs::create_dir_all(&dir).map_err(|e| format!("failed to create butler directory: {e}"))?;
@@ -41,32 +57,12 @@ pub async fn install_butler(mut progress: ProgressCallback<'_>) -> Result<PathBu
     let url = BROTH_URL.replace("{os}", os).replace("{arch}", arch);
     let cache_path = env::cache_dir().join("butler.zip");
 
-    // Retry once on a bad ZIP to recover from truncated downloads.
-    for attempt in 0..2 {
-        download_with_progress(&url, &cache_path, &mut progress).await?;
-        match extract_zip(&cache_path, &dir) {
-            Ok(_) => break,
-            Err(err) if attempt == 0 => {
-                warn!(
-                    "install_butler: zip extract failed ({}); redownloading once",
-                    err
-                );
-                let _ = fs::remove_file(&cache_path);
-                let _ = fs::remove_dir_all(&dir);
-                fs::create_dir_all(&dir)
-                    .map_err(|e| format!("failed to recreate butler directory: {e}"))?;
-            }
-            Err(err) => return Err(err),
-        }
-    }
+    // Retry once on a bad ZIP or a binary that fails to run, to recover from
+    // truncated downloads or a blocked/corrupt binary.
+    let version = loop_install_attempts(&mut progress, &url, &cache_path, &dir, &path).await?;
 
     let _ = fs::remove_file(&cache_path);
-
-    #[cfg(not(target_os = "windows"))]
-    {
-        use std::os::unix::fs::PermissionsExt;
-        let _ = fs::set_permissions(&path, fs::Permissions::from_mode(0o755));
-    }
+    cache_verified_version(&dir, &version);
 
     emit_progress(
         &mut progress,
@@ -76,12 +72,109 @@ pub async fn install_butler(mut progress: ProgressCallback<'_>) -> Result<PathBu
             message: "Butler installed".into(),
             current_file: None,
             speed: None,
+            eta: None,
         },
     );
 
     Ok(path)
 }
 
+async fn loop_install_attempts(
+    progress: &mut ProgressCallback<'_>,
+    url: &str,
+    cache_path: &Path,
+    dir: &Path,
+    path: &Path,
+) -> Result<String, String> {
+    for attempt in 0..2 {
+        download_with_progress(url, cache_path, progress).await?;
+        let installed = extract_zip(cache_path, dir).and_then(|()| {
+            #[cfg(not(target_os = "windows"))]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+                    .map_err(|e| format!("failed to mark butler as executable: {e}"))?;
+            }
+            crate::util::clear_quarantine(dir);
+            Ok(())
+        });
+
+        let verified = match installed {
+            Ok(()) => verify_butler(path).await,
+            Err(err) => Err(err),
+        };
+
+        match verified {
+            Ok(version) => return Ok(version),
+            Err(err) if attempt == 0 => {
+                warn!(
+                    "install_butler: attempt failed ({}); redownloading once",
+                    err
+                );
+                let _ = fs::remove_file(cache_path);
+                let _ = fs::remove_dir_all(dir);
+                fs::create_dir_all(dir)
+                    .map_err(|e| format!("failed to recreate butler directory: {e}"))?;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    unreachable!("loop always returns or errors on its second attempt")
+}
+
+/// Run `butler version` and confirm the binary actually executes, returning
+/// the version string it reports. Catches corrupt downloads and, on macOS,
+/// binaries Gatekeeper refuses to launch.
+async fn verify_butler(path: &Path) -> Result<String, String> {
+    let output = tokio::process::Command::new(path)
+        .arg("version")
+        .output()
+        .await
+        .map_err(|e| format!("butler binary did not run: {e}"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return if cfg!(target_os = "macos") {
+            Err(format!(
+                "butler failed to start ({}): {}. macOS Gatekeeper may be blocking it; \
+                 try running `xattr -dr com.apple.quarantine \"{}\"` and retrying.",
+                output.status,
+                stderr.trim(),
+                path.display()
+            ))
+        } else {
+            Err(format!(
+                "butler version check failed ({}): {}",
+                output.status,
+                stderr.trim()
+            ))
+        };
+    }
+
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+    if version.is_empty() {
+        return Err("butler version check returned no output".into());
+    }
+    Ok(version)
+}
+
+fn cache_verified_version(dir: &Path, version: &str) {
+    if let Err(err) = fs::write(dir.join(VERSION_MARKER_FILE), version) {
+        warn!("install_butler: failed to cache verified version: {err}");
+    }
+}
+
+/// The last verified `butler version` output, if Butler has been installed
+/// and confirmed to run. Used by diagnostics; never triggers a download.
+#[must_use]
+pub fn cached_version() -> Option<String> {
+    fs::read_to_string(env::butler_dir().join(VERSION_MARKER_FILE))
+        .ok()
+        .map(|contents| contents.trim().to_owned())
+        .filter(|value| !value.is_empty())
+}
+
 fn butler_path(dir: &Path) -> PathBuf {
     if cfg!(target_os = "windows") {
         dir.join("butler.exe")
@@ -104,6 +197,8 @@ fn butler_platform_keys() -> (&'static str, &'static str) {
         "amd64"
     } else if cfg!(target_arch = "aarch64") {
         "arm64"
+    } else if cfg!(target_arch = "x86") {
+        "386"
     } else {
         std::env::consts::ARCH
     };
@@ -129,6 +224,7 @@ async fn download_with_progress(
             message: "Downloading Butler...".into(),
             current_file: dest.file_name().map(|n| n.to_string_lossy().into()),
             speed: None,
+            eta: None,
         },
     );
 
@@ -152,7 +248,7 @@ async fn download_with_progress(
 
     let mut downloaded: u64 = 0;
     let mut last_tick = Instant::now();
-    let mut last_bytes = 0u64;
+    let mut speed_tracker = SpeedTracker::new();
 
     while let Some(chunk) = stream.next().await {
         let chunk = chunk.map_err(|e| format!("stream error: {e}"))?;
@@ -161,9 +257,8 @@ async fn download_with_progress(
             .map_err(|e| format!("write error: {e}"))?;
         downloaded += chunk.len() as u64;
 
-        let elapsed = last_tick.elapsed().as_secs_f32();
-        if elapsed > 0.2 {
-            let speed = (downloaded - last_bytes) as f32 / elapsed;
+        if last_tick.elapsed().as_secs_f32() > 0.2 {
+            let speed = speed_tracker.record(downloaded).unwrap_or(0.0);
             emit_progress(
                 progress,
                 ProgressUpdate {
@@ -172,10 +267,10 @@ async fn download_with_progress(
                     message: "Downloading Butler...".into(),
                     current_file: dest.file_name().map(|n| n.to_string_lossy().into()),
                     speed: Some(format_speed(speed)),
+                    eta: Some(format_eta(downloaded, total, speed)),
                 },
             );
             last_tick = Instant::now();
-            last_bytes = downloaded;
         }
     }
 
@@ -1,5 +1,6 @@
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::process::Stdio;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::time::{Duration, Instant};
@@ -8,16 +9,15 @@ use futures_util::StreamExt;
 use futures_util::future::join_all;
 use log::{debug, info, warn};
 use reqwest::Client;
+use serde::Deserialize;
 use tokio::fs::File;
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
 
 use crate::env;
-use crate::util::{cancel_requested, format_speed, progress_percent};
+use crate::util::{SpeedTracker, cancel_requested, format_eta, format_speed, progress_percent};
 
 pub mod butler;
 
-const PATCH_HOST: &str = "https://game-patches.hytale.com";
-
 #[derive(Clone, Debug, Default)]
 pub struct VersionCheckResult {
     pub latest_version: u32,
@@ -34,6 +34,10 @@ pub struct ProgressUpdate {
     pub message: String,
     pub current_file: Option<String>,
     pub speed: Option<String>,
+    /// Human-friendly time remaining, e.g. "~3m 20s remaining". Only known
+    /// once both a total size and a measured speed are available; `None`
+    /// otherwise (start/finish of a stage, or stages with no size total).
+    pub eta: Option<String>,
 }
 
 pub type ProgressCallback<'a> = Option<&'a mut (dyn FnMut(ProgressUpdate) + Send)>;
@@ -44,7 +48,35 @@ fn emit_progress(cb: &mut ProgressCallback<'_>, update: ProgressUpdate) {
     }
 }
 
+/// One line of butler's `-j` NDJSON progress output. Butler emits several
+/// message types on stdout (progress, log, error); we only act on
+/// `progress` and silently ignore everything else, including lines that
+/// fail to parse at all, so a butler format change degrades to the coarse
+/// progress updates instead of failing the install.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+enum ButlerMessage {
+    #[serde(rename = "progress")]
+    Progress { percentage: f32 },
+    #[serde(other)]
+    Other,
+}
+
 pub async fn find_latest_version_with_details(version_type: &str) -> VersionCheckResult {
+    let hosts: Vec<&str> = crate::endpoints::patch_hosts()
+        .iter()
+        .map(String::as_str)
+        .collect();
+    find_latest_version_with_details_for_hosts(&hosts, version_type).await
+}
+
+/// Same as [`find_latest_version_with_details`] but probes an explicit list
+/// of mirror hosts instead of [`crate::endpoints::patch_hosts`], so tests can
+/// point it at a local mock server instead of the real patch service.
+async fn find_latest_version_with_details_for_hosts(
+    hosts: &[&str],
+    version_type: &str,
+) -> VersionCheckResult {
     let (os, arch) = platform_keys();
     if os == "unknown" {
         warn!("version probe: unsupported operating system");
@@ -73,36 +105,21 @@ pub async fn find_latest_version_with_details(version_type: &str) -> VersionChec
         }
     };
 
-    let mut checks = Vec::new();
-    for version in 1..=start_version {
-        let url = format!(
-            "{PATCH_HOST}/patches/{}/{}/{}/0/{}.pwr",
-            os, arch, api_version_type, version
-        );
-        let c = client.clone();
-        checks.push(async move {
-            match c.head(&url).send().await {
-                Ok(resp) => (version, url, resp.status().is_success(), None),
-                Err(err) => (version, url, false, Some(err.to_string())),
-            }
-        });
-    }
-
     let mut result = VersionCheckResult::default();
     let mut had_request_errors = false;
-    for (version, url, exists, request_error) in join_all(checks).await {
-        result.checked_urls.push(url.clone());
-        if let Some(err) = request_error {
-            had_request_errors = true;
-            warn!("version probe failed for {}: {}", url, err);
-        }
-        if exists && version > result.latest_version {
-            result.latest_version = version;
-            result.success_url = Some(url);
-        }
-        if exists {
-            result.available_versions.push(version);
+    for host in hosts {
+        let (latest_version, mut available_versions, success_url, checked_urls, host_had_errors) =
+            probe_versions_on_host(&client, host, os, arch, &api_version_type, start_version).await;
+        result.checked_urls.extend(checked_urls);
+        had_request_errors |= host_had_errors;
+
+        if latest_version > 0 {
+            result.latest_version = latest_version;
+            result.success_url = success_url;
+            result.available_versions.append(&mut available_versions);
+            break;
         }
+        warn!("version probe: mirror {host} had no available versions, trying next mirror");
     }
     debug!(
         "version probe: latest={} success_url={:?}",
@@ -126,6 +143,53 @@ pub async fn find_latest_version_with_details(version_type: &str) -> VersionChec
     result
 }
 
+/// HEAD-probes versions `1..=start_version` against a single mirror host,
+/// returning `(latest_version, available_versions, success_url, checked_urls,
+/// had_request_errors)`. Split out of [`find_latest_version_with_details`] so
+/// it can be repeated per configured mirror.
+async fn probe_versions_on_host(
+    client: &Client,
+    host: &str,
+    os: &str,
+    arch: &str,
+    api_version_type: &str,
+    start_version: u32,
+) -> (u32, Vec<u32>, Option<String>, Vec<String>, bool) {
+    let mut checks = Vec::new();
+    for version in 1..=start_version {
+        let url = format!("{host}/patches/{}/{}/{}/0/{}.pwr", os, arch, api_version_type, version);
+        let c = client.clone();
+        checks.push(async move {
+            match crate::util::send_with_retry(|| c.head(&url)).await {
+                Ok(resp) => (version, url, resp.status().is_success(), None),
+                Err(err) => (version, url, false, Some(err)),
+            }
+        });
+    }
+
+    let mut latest_version = 0;
+    let mut available_versions = Vec::new();
+    let mut success_url = None;
+    let mut checked_urls = Vec::new();
+    let mut had_request_errors = false;
+    for (version, url, exists, request_error) in join_all(checks).await {
+        checked_urls.push(url.clone());
+        if let Some(err) = request_error {
+            had_request_errors = true;
+            warn!("version probe failed for {}: {}", url, err);
+        }
+        if exists && version > latest_version {
+            latest_version = version;
+            success_url = Some(url);
+        }
+        if exists {
+            available_versions.push(version);
+        }
+    }
+
+    (latest_version, available_versions, success_url, checked_urls, had_request_errors)
+}
+
 pub async fn download_pwr(
     version_type: &str,
     from_version: u32,
@@ -148,64 +212,92 @@ pub async fn download_pwr(
         .build()
         .map_err(|e| format!("failed to build HTTP client: {e}"))?;
 
-    // Prefer incremental patch when possible, otherwise fall back to full package.
-    let url = format!(
-        "{PATCH_HOST}/patches/{}/{}/{}/{}/{}.pwr",
-        os, arch, api_version_type, from_version, to_version
-    );
-
-    let url = if from_version == 0 || !head_available(&client, &url).await? {
-        format!(
-            "{PATCH_HOST}/patches/{}/{}/{}/0/{}.pwr",
-            os, arch, api_version_type, to_version
-        )
-    } else {
-        url
-    };
-
-    let expected_size = content_length(&client, &url).await.unwrap_or(0);
-
     let cache_dir = env::cache_dir();
     fs::create_dir_all(&cache_dir).map_err(|e| format!("failed to create cache directory: {e}"))?;
-
     let dest = cache_dir.join(format!("{}.pwr", to_version));
-    debug!(
-        "download_pwr: target={} expected_size={:?}",
-        dest.display(),
-        expected_size
-    );
-    if let Ok(info) = fs::metadata(&dest) {
-        if expected_size > 0 && info.len() == expected_size {
-            info!("download_pwr: cache hit for version {}", to_version);
-            return Ok(dest);
+
+    let mut last_err = None;
+    for host in crate::endpoints::patch_hosts() {
+        // Prefer incremental patch when possible, otherwise fall back to full package.
+        let url = format!(
+            "{host}/patches/{}/{}/{}/{}/{}.pwr",
+            os, arch, api_version_type, from_version, to_version
+        );
+        let url = if from_version == 0 || !head_available(&client, &url).await? {
+            format!(
+                "{host}/patches/{}/{}/{}/0/{}.pwr",
+                os, arch, api_version_type, to_version
+            )
+        } else {
+            url
+        };
+
+        let expected_size = content_length(&client, &url).await.unwrap_or(0);
+        let sidecar_size = read_size_sidecar(&dest);
+        debug!(
+            "download_pwr: trying mirror {host}, target={} expected_size={:?} sidecar_size={:?}",
+            dest.display(),
+            expected_size,
+            sidecar_size
+        );
+        if let Ok(info) = fs::metadata(&dest) {
+            if cache_hit(info.len(), expected_size, sidecar_size) {
+                info!("download_pwr: cache hit for version {}", to_version);
+                return Ok(dest);
+            }
+            // Neither the server's authoritative size nor a recorded sidecar
+            // confirms this file is complete; a truncated partial download
+            // can still be large, so don't trust size alone. Resuming via a
+            // range request would avoid re-fetching it, but that isn't
+            // implemented yet, so fall through to a fresh download.
+            let _ = fs::remove_file(&dest);
+            let _ = fs::remove_file(size_sidecar_path(&dest));
         }
-        if expected_size == 0 && info.len() > 1_024 * 1_024 * 1_024 {
-            info!(
-                "download_pwr: cache hit (size heuristic) for version {}",
-                to_version
-            );
-            return Ok(dest);
+
+        match download_from_url(&client, &url, &dest, expected_size, &cancel, &mut progress).await {
+            Ok(()) => {
+                info!("download_pwr: completed {} via {host}", dest.display());
+                return Ok(dest);
+            }
+            Err(err) => {
+                warn!("download_pwr: mirror {host} failed: {err}");
+                last_err = Some(err);
+            }
         }
-        let _ = fs::remove_file(&dest);
     }
 
-    if cancel_requested(&cancel) {
+    Err(last_err.unwrap_or_else(|| "no patch mirrors configured".into()))
+}
+
+/// Downloads `url` into `dest`, reporting progress along the way. Separated
+/// from [`download_pwr`] so the mirror loop there can retry the whole
+/// transfer against the next host on failure.
+async fn download_from_url(
+    client: &Client,
+    url: &str,
+    dest: &Path,
+    expected_size: u64,
+    cancel: &Option<Arc<AtomicBool>>,
+    progress: &mut ProgressCallback<'_>,
+) -> Result<(), String> {
+    if cancel_requested(cancel) {
         warn!("download_pwr: cancelled before HTTP request");
         return Err("Download cancelled".into());
     }
     emit_progress(
-        &mut progress,
+        progress,
         ProgressUpdate {
             stage: "download",
             progress: 0.0,
             message: "Downloading Hytale...".into(),
             current_file: dest.file_name().map(|n| n.to_string_lossy().into()),
             speed: None,
+            eta: None,
         },
     );
 
     let request = client
-        .get(&url)
+        .get(url)
         .header(
             reqwest::header::USER_AGENT,
             "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
@@ -219,24 +311,24 @@ pub async fn download_pwr(
         .map_err(|e| format!("failed to download patch: {e}"))?
         .error_for_status()
         .map_err(|e| format!("patch not available: {e}"))?;
-    if cancel_requested(&cancel) {
-        let _ = fs::remove_file(&dest);
+    if cancel_requested(cancel) {
+        let _ = fs::remove_file(dest);
         return Err("Download cancelled".into());
     }
 
     let total = response.content_length().or(Some(expected_size));
     let mut stream = response.bytes_stream();
-    let mut file = File::create(&dest)
+    let mut file = File::create(dest)
         .await
         .map_err(|e| format!("failed to create patch file: {e}"))?;
 
     let mut downloaded: u64 = 0;
     let mut last_tick = Instant::now();
-    let mut last_bytes = 0u64;
+    let mut speed_tracker = SpeedTracker::new();
 
     while let Some(chunk) = stream.next().await {
-        if cancel_requested(&cancel) {
-            let _ = fs::remove_file(&dest);
+        if cancel_requested(cancel) {
+            let _ = fs::remove_file(dest);
             return Err("Download cancelled".into());
         }
         let chunk = chunk.map_err(|e| format!("stream error: {e}"))?;
@@ -245,21 +337,20 @@ pub async fn download_pwr(
             .map_err(|e| format!("write error: {e}"))?;
         downloaded += chunk.len() as u64;
 
-        let elapsed = last_tick.elapsed().as_secs_f32();
-        if elapsed > 0.2 {
-            let speed = (downloaded - last_bytes) as f32 / elapsed;
+        if last_tick.elapsed().as_secs_f32() > 0.2 {
+            let speed = speed_tracker.record(downloaded).unwrap_or(0.0);
             emit_progress(
-                &mut progress,
+                progress,
                 ProgressUpdate {
                     stage: "download",
                     progress: progress_percent(downloaded, total),
                     message: "Downloading game patch...".into(),
                     current_file: dest.file_name().map(|n| n.to_string_lossy().into()),
                     speed: Some(format_speed(speed)),
+                    eta: Some(format_eta(downloaded, total, speed)),
                 },
             );
             last_tick = Instant::now();
-            last_bytes = downloaded;
             debug!(
                 "download_pwr: downloaded {} bytes of {:?} ({:.1}%)",
                 downloaded,
@@ -270,30 +361,40 @@ pub async fn download_pwr(
     }
 
     emit_progress(
-        &mut progress,
+        progress,
         ProgressUpdate {
             stage: "download",
             progress: 100.0,
             message: "Download complete".into(),
             current_file: dest.file_name().map(|n| n.to_string_lossy().into()),
             speed: Some("0 B/s".into()),
+            eta: None,
         },
     );
 
     if let Some(total) = total
         && downloaded < total
     {
-        let _ = fs::remove_file(&dest);
+        let _ = fs::remove_file(dest);
         return Err(format!(
             "download incomplete: got {} of {} bytes",
             downloaded, total
         ));
     }
 
-    info!("download_pwr: completed {}", dest.display());
-    Ok(dest)
+    if let Err(err) = crate::util::write_atomic(&size_sidecar_path(dest), downloaded.to_string().as_bytes()) {
+        warn!("download_pwr: failed to record size sidecar for {}: {err}", dest.display());
+    }
+
+    Ok(())
 }
 
+/// Applies a downloaded `.pwr` patch via butler, installing or updating the
+/// game in place. Only ever touches `env::game_latest_dir()` (the `release`
+/// folder) plus its own `staging-temp` subdirectory — `UserData` and every
+/// other top-level directory under the app's base dir live outside that
+/// path and are never passed to butler, so they survive a version change
+/// untouched.
 pub async fn apply_pwr(pwr_file: &Path, mut progress: ProgressCallback<'_>) -> Result<(), String> {
     let game_dir = env::game_latest_dir();
     let staging_dir = game_dir.join("staging-temp");
@@ -308,6 +409,7 @@ pub async fn apply_pwr(pwr_file: &Path, mut progress: ProgressCallback<'_>) -> R
                 message: "Game already installed".into(),
                 current_file: None,
                 speed: None,
+                eta: None,
             },
         );
         return Ok(());
@@ -323,6 +425,7 @@ pub async fn apply_pwr(pwr_file: &Path, mut progress: ProgressCallback<'_>) -> R
             message: "Preparing installation...".into(),
             current_file: None,
             speed: None,
+            eta: None,
         },
     );
 
@@ -339,34 +442,75 @@ pub async fn apply_pwr(pwr_file: &Path, mut progress: ProgressCallback<'_>) -> R
             message: "Applying game patch...".into(),
             current_file: None,
             speed: None,
+            eta: None,
         },
     );
 
-    let mut cmd = std::process::Command::new(&butler_path);
-    cmd.arg("apply").arg("--staging-dir").arg(&staging_dir);
+    let mut cmd = tokio::process::Command::new(&butler_path);
+    cmd.arg("apply")
+        .arg("--staging-dir")
+        .arg(&staging_dir)
+        .arg("-j");
     if cfg!(target_os = "windows") {
         cmd.arg("--save-interval=60");
     }
-    cmd.arg(pwr_file).arg(&game_dir);
+    cmd.arg(pwr_file)
+        .arg(&game_dir)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
     info!(
         "apply_pwr: running butler for {} into {}",
         pwr_file.display(),
         game_dir.display()
     );
 
-    let output = cmd
-        .output()
-        .map_err(|e| format!("failed to run butler: {e}"))?;
-    if !output.status.success() {
+    let mut child = cmd.spawn().map_err(|e| format!("failed to run butler: {e}"))?;
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| "butler: missing stdout pipe".to_string())?;
+    let mut stderr = child
+        .stderr
+        .take()
+        .ok_or_else(|| "butler: missing stderr pipe".to_string())?;
+
+    let stderr_task = tokio::spawn(async move {
+        let mut buf = String::new();
+        let _ = stderr.read_to_string(&mut buf).await;
+        buf
+    });
+
+    let mut lines = BufReader::new(stdout).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        if let Ok(ButlerMessage::Progress { percentage }) = serde_json::from_str(&line) {
+            emit_progress(
+                &mut progress,
+                ProgressUpdate {
+                    stage: "install",
+                    progress: (5.0 + percentage.clamp(0.0, 100.0) * 0.95).min(99.0),
+                    message: "Applying game patch...".into(),
+                    current_file: None,
+                    speed: None,
+                    eta: None,
+                },
+            );
+        }
+    }
+
+    let status = child
+        .wait()
+        .await
+        .map_err(|e| format!("failed to wait for butler: {e}"))?;
+    let stderr_output = stderr_task.await.unwrap_or_default();
+
+    if !status.success() {
         clean_staging_directory(&game_dir).ok();
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        let stdout = String::from_utf8_lossy(&output.stdout);
         return Err(format!(
             "butler apply failed: {}",
-            if stderr.trim().is_empty() {
-                stdout.trim().to_owned()
+            if stderr_output.trim().is_empty() {
+                status.to_string()
             } else {
-                stderr.trim().to_owned()
+                stderr_output.trim().to_owned()
             }
         ));
     }
@@ -388,6 +532,7 @@ pub async fn apply_pwr(pwr_file: &Path, mut progress: ProgressCallback<'_>) -> R
             message: "Hytale installed successfully".into(),
             current_file: None,
             speed: None,
+            eta: None,
         },
     );
 
@@ -398,10 +543,34 @@ pub async fn apply_pwr(pwr_file: &Path, mut progress: ProgressCallback<'_>) -> R
 pub fn save_local_version(version: u32) -> Result<(), String> {
     env::ensure_base_dirs().map_err(|e| format!("failed to prepare directories: {e}"))?;
     let version_file = env::default_app_dir().join("version.txt");
-    fs::write(&version_file, version.to_string())
+    crate::util::write_atomic(&version_file, version.to_string().as_bytes())
         .map_err(|e| format!("failed to save version: {e}"))
 }
 
+/// Path of the sidecar file recording the verified byte length of a
+/// completed download at `dest`, used to confirm cache hits once the server
+/// no longer reports a `Content-Length` for the same URL.
+fn size_sidecar_path(dest: &Path) -> PathBuf {
+    let mut name = dest.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    name.push_str(".size");
+    dest.with_file_name(name)
+}
+
+fn read_size_sidecar(dest: &Path) -> Option<u64> {
+    fs::read_to_string(size_sidecar_path(dest)).ok()?.trim().parse().ok()
+}
+
+/// Whether an existing cached file of `existing_len` bytes can be reused
+/// instead of re-downloading. Only trusts a size that's backed by the
+/// server's authoritative `Content-Length` (`expected_size`, 0 if unknown)
+/// or a sidecar recorded after a previously verified download; a large file
+/// with neither is just as likely a truncated partial download, so it's
+/// never treated as a hit on size alone.
+fn cache_hit(existing_len: u64, expected_size: u64, sidecar_size: Option<u64>) -> bool {
+    let known_size = if expected_size > 0 { Some(expected_size) } else { sidecar_size };
+    known_size.is_some_and(|size| existing_len == size)
+}
+
 async fn head_available(client: &Client, url: &str) -> Result<bool, String> {
     let exists = match client.head(url).send().await {
         Ok(resp) => resp.status().is_success(),
@@ -449,26 +618,31 @@ fn clean_staging_directory(game_dir: &Path) -> Result<(), String> {
     Ok(())
 }
 
-fn platform_keys() -> (&'static str, &'static str) {
-    let os = if cfg!(target_os = "windows") {
-        "windows"
-    } else if cfg!(target_os = "macos") {
-        "darwin"
-    } else if cfg!(target_os = "linux") {
-        "linux"
-    } else {
-        "unknown"
+pub(crate) fn platform_keys() -> (&'static str, &'static str) {
+    platform_keys_for(std::env::consts::OS, std::env::consts::ARCH)
+}
+
+/// Maps Rust's own `std::env::consts::OS`/`ARCH` strings to the keys the
+/// patch server uses. Split out of [`platform_keys`] so every `(os, arch)`
+/// combination can be exercised by tests without recompiling for each
+/// target.
+fn platform_keys_for(os: &'static str, arch: &'static str) -> (&'static str, &'static str) {
+    let os_key = match os {
+        "windows" => "windows",
+        "macos" => "darwin",
+        "linux" => "linux",
+        _ => "unknown",
     };
 
-    let arch = if cfg!(target_arch = "x86_64") {
-        "amd64"
-    } else if cfg!(target_arch = "aarch64") {
-        "arm64"
-    } else {
-        std::env::consts::ARCH
+    let arch_key = match arch {
+        "x86_64" => "amd64",
+        "aarch64" => "arm64",
+        "x86" => "386",
+        "arm" => "arm",
+        _ => arch,
     };
 
-    (os, arch)
+    (os_key, arch_key)
 }
 
 fn normalize_version_type(value: &str) -> String {
@@ -493,3 +667,189 @@ fn game_client_path(game_dir: &Path) -> PathBuf {
         game_dir.join("Client").join("HytaleClient")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncated_but_large_file_is_not_a_cache_hit_without_a_known_size() {
+        // A partial download that happens to be >1GB used to be trusted by a
+        // size heuristic; with no Content-Length and no sidecar it must now
+        // be treated as unverified.
+        assert!(!cache_hit(2 * 1_024 * 1_024 * 1_024, 0, None));
+    }
+
+    #[test]
+    fn matches_server_reported_content_length() {
+        assert!(cache_hit(1_000, 1_000, None));
+        assert!(!cache_hit(999, 1_000, None));
+    }
+
+    #[test]
+    fn falls_back_to_sidecar_when_content_length_is_unknown() {
+        assert!(cache_hit(1_000, 0, Some(1_000)));
+        assert!(!cache_hit(999, 0, Some(1_000)));
+    }
+
+    #[test]
+    fn content_length_takes_priority_over_a_stale_sidecar() {
+        assert!(cache_hit(1_000, 1_000, Some(500)));
+    }
+
+    #[test]
+    fn maps_supported_os_and_arch_combinations_to_patch_server_keys() {
+        for os in ["windows", "macos", "linux"] {
+            let expected_os = match os {
+                "windows" => "windows",
+                "macos" => "darwin",
+                "linux" => "linux",
+                _ => unreachable!(),
+            };
+            for (arch, expected_arch) in [
+                ("x86_64", "amd64"),
+                ("aarch64", "arm64"),
+                ("x86", "386"),
+                ("arm", "arm"),
+            ] {
+                assert_eq!(
+                    platform_keys_for(os, arch),
+                    (expected_os, expected_arch),
+                    "os={os} arch={arch}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn falls_back_to_unknown_os_and_raw_arch_for_unsupported_targets() {
+        assert_eq!(platform_keys_for("freebsd", "x86_64"), ("unknown", "amd64"));
+        assert_eq!(platform_keys_for("linux", "riscv64"), ("linux", "riscv64"));
+    }
+
+    /// A minimal single-purpose HTTP mock, since the only things the probing
+    /// and download code needs from a server are a status line, a
+    /// `Content-Length`, and a body. Avoids pulling in a full HTTP-mocking
+    /// dependency for a handful of tests.
+    type MockHandler = dyn Fn(&str, &str) -> (u16, Vec<u8>) + Send + Sync;
+
+    struct MockServer {
+        addr: std::net::SocketAddr,
+    }
+
+    impl MockServer {
+        fn start<F>(handler: F) -> Self
+        where
+            F: Fn(&str, &str) -> (u16, Vec<u8>) + Send + Sync + 'static,
+        {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+            let addr = listener.local_addr().expect("mock server local addr");
+            let handler = std::sync::Arc::new(handler);
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let Ok(stream) = stream else { continue };
+                    let handler = handler.clone();
+                    std::thread::spawn(move || Self::serve_one(stream, handler.as_ref()));
+                }
+            });
+            Self { addr }
+        }
+
+        fn serve_one(mut stream: std::net::TcpStream, handler: &MockHandler) {
+            use std::io::{Read, Write};
+            let mut buf = [0u8; 8192];
+            let Ok(n) = stream.read(&mut buf) else { return };
+            let request = String::from_utf8_lossy(&buf[..n]);
+            let mut parts = request.lines().next().unwrap_or_default().split_whitespace();
+            let method = parts.next().unwrap_or("GET").to_owned();
+            let path = parts.next().unwrap_or("/").to_owned();
+
+            let (status, body) = handler(&method, &path);
+            let status_text = if status == 200 { "OK" } else { "Not Found" };
+            let head = format!(
+                "HTTP/1.1 {status} {status_text}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            );
+            let _ = stream.write_all(head.as_bytes());
+            if method != "HEAD" {
+                let _ = stream.write_all(&body);
+            }
+        }
+
+        fn host(&self) -> String {
+            format!("http://{}", self.addr)
+        }
+    }
+
+    #[tokio::test]
+    async fn probes_mirror_and_reports_the_latest_available_version() {
+        let server = MockServer::start(|_method, path| {
+            let version: u32 = path
+                .rsplit('/')
+                .next()
+                .and_then(|segment| segment.strip_suffix(".pwr"))
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0);
+            if version > 0 && version <= 7 { (200, Vec::new()) } else { (404, Vec::new()) }
+        });
+
+        let result = find_latest_version_with_details_for_hosts(&[&server.host()], "release").await;
+
+        assert_eq!(result.latest_version, 7);
+        assert!(result.available_versions.contains(&7));
+        assert_eq!(result.success_url, Some(format!("{}/patches/linux/amd64/release/0/7.pwr", server.host())));
+    }
+
+    #[tokio::test]
+    async fn falls_through_to_the_next_mirror_when_the_first_has_no_versions() {
+        let dead_mirror = MockServer::start(|_method, _path| (404, Vec::new()));
+        let live_mirror = MockServer::start(|_method, path| {
+            if path.ends_with("/3.pwr") { (200, Vec::new()) } else { (404, Vec::new()) }
+        });
+
+        let hosts = [dead_mirror.host(), live_mirror.host()];
+        let host_refs: Vec<&str> = hosts.iter().map(String::as_str).collect();
+        let result = find_latest_version_with_details_for_hosts(&host_refs, "release").await;
+
+        assert_eq!(result.latest_version, 3);
+        assert_eq!(result.success_url, Some(format!("{}/patches/linux/amd64/release/0/3.pwr", live_mirror.host())));
+    }
+
+    #[tokio::test]
+    async fn downloads_the_full_body_and_records_a_size_sidecar() {
+        let body = b"fake pwr package contents".to_vec();
+        let server = MockServer::start(move |_method, _path| (200, body.clone()));
+        let client = Client::new();
+        let dir = std::env::temp_dir().join(format!("hrs-launcher-test-{:?}", std::thread::current().id()));
+        fs::create_dir_all(&dir).expect("create temp dir");
+        let dest = dir.join("downloaded.pwr");
+        let mut progress: ProgressCallback<'_> = None;
+
+        let url = format!("{}/patches/linux/amd64/release/0/1.pwr", server.host());
+        download_from_url(&client, &url, &dest, 0, &None, &mut progress)
+            .await
+            .expect("download should succeed");
+
+        let contents = fs::read(&dest).expect("downloaded file should exist");
+        assert_eq!(contents, b"fake pwr package contents");
+        let sidecar = read_size_sidecar(&dest);
+        assert_eq!(sidecar, Some(contents.len() as u64));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn download_from_url_honors_a_pre_set_cancel_flag() {
+        let server = MockServer::start(|_method, _path| (200, b"irrelevant".to_vec()));
+        let client = Client::new();
+        let dest = std::env::temp_dir().join("hrs-launcher-test-cancelled.pwr");
+        let cancel = Some(Arc::new(AtomicBool::new(true)));
+        let mut progress: ProgressCallback<'_> = None;
+
+        let url = format!("{}/patches/linux/amd64/release/0/1.pwr", server.host());
+        let result = download_from_url(&client, &url, &dest, 0, &cancel, &mut progress).await;
+
+        assert_eq!(result, Err("Download cancelled".to_string()));
+        assert!(!dest.exists());
+    }
+}
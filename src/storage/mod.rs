@@ -1,12 +1,57 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use log::warn;
 use tokio::fs;
 
 use crate::engine::models::LocalState;
 use crate::env;
+use crate::pwr::{ProgressCallback, ProgressUpdate};
 
 const LOCAL_STATE_FILE: &str = "version.txt";
 
+fn emit_progress(cb: &mut ProgressCallback<'_>, update: ProgressUpdate) {
+    if let Some(callback) = cb.as_deref_mut() {
+        callback(update);
+    }
+}
+
+/// Which optional categories to preserve during
+/// [`StorageManager::uninstall_game`]. The game files and saved version are
+/// always removed; everything else defaults to a full wipe unless opted out.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UninstallOptions {
+    pub keep_jre: bool,
+    pub keep_user_data: bool,
+}
+
+/// A directory category removed during [`StorageManager::uninstall_game`],
+/// reported to `on_stage` before that category is deleted so the UI can show
+/// which part of a (potentially multi-GB) uninstall is in progress.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UninstallStage {
+    Game,
+    Jre,
+    Cache,
+    Butler,
+    UserData,
+    SavedVersion,
+}
+
+impl UninstallStage {
+    /// A stable, language-independent identifier for this stage, used by the
+    /// UI layer to look up a translated message.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            UninstallStage::Game => "game",
+            UninstallStage::Jre => "jre",
+            UninstallStage::Cache => "cache",
+            UninstallStage::Butler => "butler",
+            UninstallStage::UserData => "user_data",
+            UninstallStage::SavedVersion => "saved_version",
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct StorageManager {
     base_dir: PathBuf,
@@ -35,7 +80,7 @@ impl StorageManager {
                 .await
                 .map_err(|e| format!("unable to create state dir: {e}"))?;
         }
-        fs::write(&path, state.version.as_bytes())
+        crate::util::write_atomic_async(&path, state.version.as_bytes())
             .await
             .map_err(|e| format!("unable to persist version: {e}"))
     }
@@ -48,40 +93,86 @@ impl StorageManager {
         env::mods_dir()
     }
 
-    pub async fn uninstall_game(&self) -> Result<(), String> {
+    /// Removes the game files and, unless opted out via `options`, the
+    /// bundled JRE, download cache, Butler state, and UserData. The saved
+    /// version file is always cleared alongside the game files, since it
+    /// only makes sense once they've actually been removed. `on_stage` is
+    /// called with a short, stable identifier (see [`UninstallStage`])
+    /// before each directory is removed, so callers can surface progress
+    /// instead of a bare spinner for what can be a multi-GB, multi-second
+    /// operation.
+    pub async fn uninstall_game(
+        &self,
+        options: UninstallOptions,
+        mut on_stage: impl FnMut(UninstallStage),
+    ) -> Result<(), String> {
         let release_dir = self.base_dir.join("release");
         if fs::metadata(&release_dir).await.is_ok() {
+            on_stage(UninstallStage::Game);
             fs::remove_dir_all(&release_dir)
                 .await
                 .map_err(|e| format!("failed to remove game files: {e}"))?;
-        }
 
-        let jre_dir = env::jre_dir();
-        if fs::metadata(&jre_dir).await.is_ok() {
-            fs::remove_dir_all(&jre_dir)
-                .await
-                .map_err(|e| format!("failed to remove bundled JRE: {e}"))?;
+            let version_file = self.base_dir.join(LOCAL_STATE_FILE);
+            if fs::metadata(&version_file).await.is_ok() {
+                on_stage(UninstallStage::SavedVersion);
+                fs::remove_file(&version_file)
+                    .await
+                    .map_err(|e| format!("failed to clear saved version: {e}"))?;
+            }
         }
 
-        let cache_dir = env::cache_dir();
-        if fs::metadata(&cache_dir).await.is_ok() {
-            fs::remove_dir_all(&cache_dir)
-                .await
-                .map_err(|e| format!("failed to remove cache: {e}"))?;
+        if !options.keep_jre {
+            let jre_dir = env::jre_dir();
+            if fs::metadata(&jre_dir).await.is_ok() {
+                on_stage(UninstallStage::Jre);
+                fs::remove_dir_all(&jre_dir)
+                    .await
+                    .map_err(|e| format!("failed to remove bundled JRE: {e}"))?;
+            }
+
+            let cache_dir = env::cache_dir();
+            if fs::metadata(&cache_dir).await.is_ok() {
+                on_stage(UninstallStage::Cache);
+                fs::remove_dir_all(&cache_dir)
+                    .await
+                    .map_err(|e| format!("failed to remove cache: {e}"))?;
+            }
         }
 
         let butler_dir = env::butler_dir();
         if fs::metadata(&butler_dir).await.is_ok() {
+            on_stage(UninstallStage::Butler);
             fs::remove_dir_all(&butler_dir)
                 .await
                 .map_err(|e| format!("failed to remove butler files: {e}"))?;
         }
 
-        let user_data_dir = self.base_dir.join("UserData");
-        if fs::metadata(&user_data_dir).await.is_ok() {
-            fs::remove_dir_all(&user_data_dir)
+        if !options.keep_user_data {
+            let user_data_dir = self.base_dir.join("UserData");
+            if fs::metadata(&user_data_dir).await.is_ok() {
+                on_stage(UninstallStage::UserData);
+                fs::remove_dir_all(&user_data_dir)
+                    .await
+                    .map_err(|e| format!("failed to remove user data: {e}"))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the installed game files and saved version so a following
+    /// [`download_pwr`](crate::pwr::download_pwr) call is forced to fetch a
+    /// full package instead of an incremental patch. Unlike
+    /// [`uninstall_game`](Self::uninstall_game), leaves the JRE, cache, and
+    /// Butler install in place, since a repair doesn't need to redownload
+    /// those; pass `also_clear_cache` to remove them too.
+    pub async fn remove_game_files(&self, also_clear_cache: bool) -> Result<(), String> {
+        let release_dir = self.base_dir.join("release");
+        if fs::metadata(&release_dir).await.is_ok() {
+            fs::remove_dir_all(&release_dir)
                 .await
-                .map_err(|e| format!("failed to remove user data: {e}"))?;
+                .map_err(|e| format!("failed to remove game files: {e}"))?;
         }
 
         let version_file = self.base_dir.join(LOCAL_STATE_FILE);
@@ -91,6 +182,189 @@ impl StorageManager {
                 .map_err(|e| format!("failed to clear saved version: {e}"))?;
         }
 
+        if also_clear_cache {
+            let jre_dir = env::jre_dir();
+            if fs::metadata(&jre_dir).await.is_ok() {
+                fs::remove_dir_all(&jre_dir)
+                    .await
+                    .map_err(|e| format!("failed to remove bundled JRE: {e}"))?;
+            }
+
+            let cache_dir = env::cache_dir();
+            if fs::metadata(&cache_dir).await.is_ok() {
+                fs::remove_dir_all(&cache_dir)
+                    .await
+                    .map_err(|e| format!("failed to remove cache: {e}"))?;
+            }
+        }
+
         Ok(())
     }
+
+    /// Relocates everything the launcher owns (game, JRE, cache, butler
+    /// state, user data, and the saved version file) to `new_base`, instead
+    /// of re-downloading it at the new location. Intended for an install-dir
+    /// override setting to call when the user picks a new directory.
+    ///
+    /// Each category is moved independently, falling back to a recursive
+    /// copy-then-remove when `new_base` is on a different filesystem. If any
+    /// category fails partway through, the categories already moved are
+    /// moved back to their original location before the error is returned,
+    /// so the install is never left half-migrated.
+    ///
+    /// Not wired up yet: nothing calls this until an install-dir override
+    /// setting exists to trigger it.
+    /// Resolves where a migratable category outside `base_dir` (JRE, cache,
+    /// butler state) currently lives: `default` before any migration has
+    /// happened, or `base_dir.join(name)` once one has, since that's exactly
+    /// where the previous `migrate_to` call put it.
+    fn current_location(&self, name: &str, default: PathBuf) -> PathBuf {
+        if self.base_dir == env::default_app_dir() {
+            default
+        } else {
+            self.base_dir.join(name)
+        }
+    }
+
+    #[allow(dead_code)]
+    pub async fn migrate_to(&mut self, new_base: &Path, mut progress: ProgressCallback<'_>) -> Result<(), String> {
+        if new_base == self.base_dir {
+            return Ok(());
+        }
+
+        // JRE, cache, and butler state normally live outside `base_dir`
+        // (following XDG splits on Linux) until a migration consolidates
+        // them under it, so their source resolves to the OS default only
+        // before the first migration; once `base_dir` has moved away from
+        // the OS default, a prior migration already left them under it, and
+        // that's where a second migration must look instead of at the
+        // now-stale global path.
+        let entries: [(&str, PathBuf, PathBuf); 6] = [
+            ("game", self.base_dir.join("release"), new_base.join("release")),
+            ("JRE", self.current_location("jre", env::jre_dir()), new_base.join("jre")),
+            ("cache", self.current_location("cache", env::cache_dir()), new_base.join("cache")),
+            ("butler state", self.current_location("butler", env::butler_dir()), new_base.join("butler")),
+            ("user data", self.base_dir.join("UserData"), new_base.join("UserData")),
+            ("saved version", self.base_dir.join(LOCAL_STATE_FILE), new_base.join(LOCAL_STATE_FILE)),
+        ];
+        let total = entries.len() as f32;
+        let mut migrated: Vec<(PathBuf, PathBuf)> = Vec::new();
+
+        for (index, (label, src, dest)) in entries.iter().enumerate() {
+            emit_progress(
+                &mut progress,
+                ProgressUpdate {
+                    stage: "migrate",
+                    progress: index as f32 / total * 100.0,
+                    message: format!("Moving {label}..."),
+                    current_file: Some((*label).to_owned()),
+                    speed: None,
+                    eta: None,
+                },
+            );
+
+            match move_path(src, dest).await {
+                Ok(true) => migrated.push((src.clone(), dest.clone())),
+                Ok(false) => {}
+                Err(err) => {
+                    warn!("storage: failed to move {label} during migration: {err}; rolling back");
+                    rollback_moves(&migrated).await;
+                    return Err(err);
+                }
+            }
+        }
+
+        emit_progress(
+            &mut progress,
+            ProgressUpdate {
+                stage: "migrate",
+                progress: 100.0,
+                message: "Migration complete".to_owned(),
+                current_file: None,
+                speed: None,
+                eta: None,
+            },
+        );
+
+        self.base_dir = new_base.to_path_buf();
+        Ok(())
+    }
+}
+
+/// Moves `src` to `dest`, returning `Ok(false)` if `src` doesn't exist.
+/// Falls back to a recursive copy, removing `src` only once the copy has
+/// fully succeeded, so a failed copy never leaves `src` gone.
+async fn move_path(src: &Path, dest: &Path) -> Result<bool, String> {
+    if fs::metadata(src).await.is_err() {
+        return Ok(false);
+    }
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|e| format!("failed to prepare destination {}: {e}", dest.display()))?;
+    }
+    if fs::rename(src, dest).await.is_ok() {
+        return Ok(true);
+    }
+
+    if let Err(err) = copy_path_recursive(src, dest).await {
+        if fs::metadata(dest).await.is_ok() {
+            let _ = remove_path(dest).await;
+        }
+        return Err(err);
+    }
+    remove_path(src)
+        .await
+        .map_err(|e| format!("failed to remove {} after copying it to {}: {e}", src.display(), dest.display()))?;
+    Ok(true)
+}
+
+async fn remove_path(path: &Path) -> std::io::Result<()> {
+    if fs::metadata(path).await?.is_dir() {
+        fs::remove_dir_all(path).await
+    } else {
+        fs::remove_file(path).await
+    }
+}
+
+/// Copies `src` to `dest`, recursing into directories iteratively to avoid
+/// the extra boxing an async fn would need to call itself.
+async fn copy_path_recursive(src: &Path, dest: &Path) -> Result<(), String> {
+    let mut pending = vec![(src.to_path_buf(), dest.to_path_buf())];
+    while let Some((src, dest)) = pending.pop() {
+        let metadata = fs::metadata(&src)
+            .await
+            .map_err(|e| format!("failed to read {}: {e}", src.display()))?;
+        if metadata.is_dir() {
+            fs::create_dir_all(&dest)
+                .await
+                .map_err(|e| format!("failed to create {}: {e}", dest.display()))?;
+            let mut children = fs::read_dir(&src)
+                .await
+                .map_err(|e| format!("failed to read {}: {e}", src.display()))?;
+            while let Some(child) = children
+                .next_entry()
+                .await
+                .map_err(|e| format!("failed to read entry in {}: {e}", src.display()))?
+            {
+                pending.push((child.path(), dest.join(child.file_name())));
+            }
+        } else {
+            fs::copy(&src, &dest)
+                .await
+                .map_err(|e| format!("failed to copy {} to {}: {e}", src.display(), dest.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Moves already-migrated categories back to their original location,
+/// best-effort, so a failure partway through a migration doesn't strand the
+/// install split across both directories.
+async fn rollback_moves(migrated: &[(PathBuf, PathBuf)]) {
+    for (original, moved) in migrated.iter().rev() {
+        if let Err(err) = move_path(moved, original).await {
+            warn!("storage: failed to roll back migration of {}: {err}", moved.display());
+        }
+    }
 }
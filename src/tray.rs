@@ -0,0 +1,107 @@
+//! Optional system tray icon with quick actions (Play / Check for updates /
+//! Open game folder / Quit), for users who keep the launcher running in the
+//! background. Opt-in via a setting, since not everyone wants that.
+//!
+//! Linux's only tray backend (gtk + libappindicator) needs system GTK dev
+//! packages that aren't a reasonable requirement for every Linux build (see
+//! the target-specific dependency in `Cargo.toml`), so the tray is
+//! Windows/macOS only for now; [`Tray::build`] always fails on Linux.
+
+// On Linux the stub `imp::Tray::poll_event` always returns `None`, so these
+// variants are never constructed there even though `ui::LauncherApp` matches
+// on all of them.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayEvent {
+    Play,
+    CheckForUpdates,
+    OpenGameFolder,
+    Quit,
+}
+
+#[cfg(not(target_os = "linux"))]
+mod imp {
+    use tray_icon::menu::{Menu, MenuEvent, MenuItem};
+    use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
+
+    use super::TrayEvent;
+
+    pub struct Tray {
+        _icon: TrayIcon,
+        play_item: MenuItem,
+        update_item: MenuItem,
+        folder_item: MenuItem,
+        quit_item: MenuItem,
+    }
+
+    impl Tray {
+        pub fn build(icon: &eframe::egui::IconData) -> Result<Tray, String> {
+            let icon = Icon::from_rgba(icon.rgba.clone(), icon.width, icon.height)
+                .map_err(|e| format!("failed to build tray icon: {e}"))?;
+
+            let play_item = MenuItem::new("Play", true, None);
+            let update_item = MenuItem::new("Check for updates", true, None);
+            let folder_item = MenuItem::new("Open game folder", true, None);
+            let quit_item = MenuItem::new("Quit", true, None);
+
+            let menu = Menu::new();
+            menu.append_items(&[&play_item, &update_item, &folder_item, &quit_item])
+                .map_err(|e| format!("failed to build tray menu: {e}"))?;
+
+            let tray_icon = TrayIconBuilder::new()
+                .with_menu(Box::new(menu))
+                .with_tooltip("HRS Launcher")
+                .with_icon(icon)
+                .build()
+                .map_err(|e| format!("failed to create tray icon: {e}"))?;
+
+            Ok(Tray {
+                _icon: tray_icon,
+                play_item,
+                update_item,
+                folder_item,
+                quit_item,
+            })
+        }
+
+        pub fn set_play_enabled(&self, enabled: bool) {
+            self.play_item.set_enabled(enabled);
+        }
+
+        pub fn poll_event(&self) -> Option<TrayEvent> {
+            let event = MenuEvent::receiver().try_recv().ok()?;
+            if event.id == *self.play_item.id() {
+                Some(TrayEvent::Play)
+            } else if event.id == *self.update_item.id() {
+                Some(TrayEvent::CheckForUpdates)
+            } else if event.id == *self.folder_item.id() {
+                Some(TrayEvent::OpenGameFolder)
+            } else if event.id == *self.quit_item.id() {
+                Some(TrayEvent::Quit)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod imp {
+    use super::TrayEvent;
+
+    pub struct Tray;
+
+    impl Tray {
+        pub fn build(_icon: &eframe::egui::IconData) -> Result<Tray, String> {
+            Err("system tray is not supported on Linux builds yet".into())
+        }
+
+        pub fn set_play_enabled(&self, _enabled: bool) {}
+
+        pub fn poll_event(&self) -> Option<TrayEvent> {
+            None
+        }
+    }
+}
+
+pub use imp::Tray;
@@ -1,6 +1,12 @@
+use log::warn;
+
 use crate::engine::state::AuthMode;
+use crate::process::GarbageCollector;
 
-use super::{DEFAULT_PLAYER_NAME, ModSort, NEWS_PREVIEW_FALLBACK_EN, Theme};
+use super::{
+    DEFAULT_PLAYER_NAME, InstalledModSort, ModDensity, ModRecency, ModSort,
+    NEWS_PREVIEW_FALLBACK_EN, Theme,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Language {
@@ -15,6 +21,16 @@ pub enum Language {
     Russian,
     Turkish,
     Italian,
+    Japanese,
+    /// Kept for its already-written translations even though it isn't
+    /// offered as a selectable language yet: no bundled font covers hangul,
+    /// so every translated string would render as missing-glyph boxes. See
+    /// `setup_custom_fonts` in `ui/mod.rs`.
+    #[allow(dead_code)]
+    Korean,
+    Arabic,
+    Polish,
+    Vietnamese,
 }
 
 impl Language {
@@ -31,8 +47,18 @@ impl Language {
             Language::Russian => "Russian",
             Language::Turkish => "Turkish",
             Language::Italian => "Italian",
+            Language::Japanese => "Japanese",
+            Language::Korean => "Korean",
+            Language::Arabic => "Arabic",
+            Language::Polish => "Polish",
+            Language::Vietnamese => "Vietnamese",
         }
     }
+
+    /// `true` for languages whose script reads right-to-left.
+    pub const fn is_rtl(self) -> bool {
+        matches!(self, Language::Arabic)
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -46,6 +72,11 @@ impl I18n {
         Self { language }
     }
 
+    #[must_use]
+    pub const fn language(self) -> Language {
+        self.language
+    }
+
     #[allow(clippy::too_many_arguments)]
     // Single helper keeps call sites compact for locale strings.
     fn pick<'a>(
@@ -61,8 +92,13 @@ impl I18n {
         russian: &'a str,
         turkish: &'a str,
         italian: &'a str,
+        japanese: &'a str,
+        korean: &'a str,
+        arabic: &'a str,
+        polish: &'a str,
+        vietnamese: &'a str,
     ) -> &'a str {
-        match self.language {
+        let picked = match self.language {
             Language::English => english,
             Language::Ukrainian => ukrainian,
             Language::Spanish => spanish,
@@ -74,7 +110,23 @@ impl I18n {
             Language::Russian => russian,
             Language::Turkish => turkish,
             Language::Italian => italian,
+            Language::Japanese => japanese,
+            Language::Korean => korean,
+            Language::Arabic => arabic,
+            Language::Polish => polish,
+            Language::Vietnamese => vietnamese,
+        };
+        // Debug-only heuristic: a non-English string that's byte-identical to the
+        // English one is usually a missing translation rather than an
+        // intentional match (proper nouns, shared loanwords). Logged rather than
+        // visually marked so it doesn't need the return type to own a String.
+        if cfg!(debug_assertions) && self.language != Language::English && picked == english {
+            warn!(
+                "i18n: {:?} has no distinct translation for {english:?}, using English fallback",
+                self.language
+            );
         }
+        picked
     }
 
     pub fn theme_label(self, theme: Theme) -> &'static str {
@@ -90,6 +142,11 @@ impl I18n {
             (Theme::Dark, Language::Russian) => "Темная",
             (Theme::Dark, Language::Turkish) => "Koyu",
             (Theme::Dark, Language::Italian) => "Scuro",
+            (Theme::Dark, Language::Japanese) => "ダーク",
+            (Theme::Dark, Language::Korean) => "다크",
+            (Theme::Dark, Language::Arabic) => "داكن",
+            (Theme::Dark, Language::Polish) => "Ciemny",
+            (Theme::Dark, Language::Vietnamese) => "Tối",
             (Theme::Light, Language::English) => "Light",
             (Theme::Light, Language::Ukrainian) => "Світла",
             (Theme::Light, Language::Spanish) => "Claro",
@@ -101,6 +158,11 @@ impl I18n {
             (Theme::Light, Language::Russian) => "Светлая",
             (Theme::Light, Language::Turkish) => "Açık",
             (Theme::Light, Language::Italian) => "Chiaro",
+            (Theme::Light, Language::Japanese) => "ライト",
+            (Theme::Light, Language::Korean) => "라이트",
+            (Theme::Light, Language::Arabic) => "فاتح",
+            (Theme::Light, Language::Polish) => "Jasny",
+            (Theme::Light, Language::Vietnamese) => "Sáng",
         }
     }
 
@@ -117,6 +179,11 @@ impl I18n {
             (ModSort::Downloads, Language::Russian) => "Самые скачиваемые",
             (ModSort::Downloads, Language::Turkish) => "En çok indirilen",
             (ModSort::Downloads, Language::Italian) => "Più scaricati",
+            (ModSort::Downloads, Language::Japanese) => "ダウンロード数順",
+            (ModSort::Downloads, Language::Korean) => "다운로드 많은 순",
+            (ModSort::Downloads, Language::Arabic) => "الأكثر تنزيلًا",
+            (ModSort::Downloads, Language::Polish) => "Najczęściej pobierane",
+            (ModSort::Downloads, Language::Vietnamese) => "Tải nhiều nhất",
             (ModSort::Updated, Language::English) => "Recently updated",
             (ModSort::Updated, Language::Ukrainian) => "Нещодавно оновлені",
             (ModSort::Updated, Language::Spanish) => "Actualizados recientemente",
@@ -128,6 +195,11 @@ impl I18n {
             (ModSort::Updated, Language::Russian) => "Недавно обновленные",
             (ModSort::Updated, Language::Turkish) => "Son güncellenen",
             (ModSort::Updated, Language::Italian) => "Aggiornati di recente",
+            (ModSort::Updated, Language::Japanese) => "最近更新された順",
+            (ModSort::Updated, Language::Korean) => "최근 업데이트 순",
+            (ModSort::Updated, Language::Arabic) => "الأحدث تحديثًا",
+            (ModSort::Updated, Language::Polish) => "Ostatnio zaktualizowane",
+            (ModSort::Updated, Language::Vietnamese) => "Cập nhật gần đây",
             (ModSort::Name, Language::English) => "Name A-Z",
             (ModSort::Name, Language::Ukrainian) => "Назва A-Z",
             (ModSort::Name, Language::Spanish) => "Nombre A-Z",
@@ -139,9 +211,88 @@ impl I18n {
             (ModSort::Name, Language::Russian) => "Имя A-Z",
             (ModSort::Name, Language::Turkish) => "İsim A-Z",
             (ModSort::Name, Language::Italian) => "Nome A-Z",
+            (ModSort::Name, Language::Japanese) => "名前 A-Z",
+            (ModSort::Name, Language::Korean) => "이름 A-Z",
+            (ModSort::Name, Language::Arabic) => "الاسم أ-ي",
+            (ModSort::Name, Language::Polish) => "Nazwa A-Z",
+            (ModSort::Name, Language::Vietnamese) => "Tên A-Z",
+        }
+    }
+
+    pub fn installed_mod_sort_label(self, sort: InstalledModSort) -> &'static str {
+        match (sort, self.language) {
+            (InstalledModSort::Name, Language::English) => "Name A-Z",
+            (InstalledModSort::Name, Language::Ukrainian) => "Назва A-Z",
+            (InstalledModSort::Name, Language::Spanish) => "Nombre A-Z",
+            (InstalledModSort::Name, Language::French) => "Nom A-Z",
+            (InstalledModSort::Name, Language::German) => "Name A-Z",
+            (InstalledModSort::Name, Language::Portuguese) => "Nome A-Z",
+            (InstalledModSort::Name, Language::Chinese) => "名称 A-Z",
+            (InstalledModSort::Name, Language::Hindi) => "नाम A-Z",
+            (InstalledModSort::Name, Language::Russian) => "Имя A-Z",
+            (InstalledModSort::Name, Language::Turkish) => "İsim A-Z",
+            (InstalledModSort::Name, Language::Italian) => "Nome A-Z",
+            (InstalledModSort::Name, Language::Japanese) => "名前 A-Z",
+            (InstalledModSort::Name, Language::Korean) => "이름 A-Z",
+            (InstalledModSort::Name, Language::Arabic) => "الاسم أ-ي",
+            (InstalledModSort::Name, Language::Polish) => "Nazwa A-Z",
+            (InstalledModSort::Name, Language::Vietnamese) => "Tên A-Z",
+            (InstalledModSort::InstallDate, Language::English) => "Recently installed",
+            (InstalledModSort::InstallDate, Language::Ukrainian) => "Нещодавно встановлені",
+            (InstalledModSort::InstallDate, Language::Spanish) => "Instalados recientemente",
+            (InstalledModSort::InstallDate, Language::French) => "Installés récemment",
+            (InstalledModSort::InstallDate, Language::German) => "Kürzlich installiert",
+            (InstalledModSort::InstallDate, Language::Portuguese) => "Instalados recentemente",
+            (InstalledModSort::InstallDate, Language::Chinese) => "最近安装",
+            (InstalledModSort::InstallDate, Language::Hindi) => "हाल ही में इंस्टॉल किए गए",
+            (InstalledModSort::InstallDate, Language::Russian) => "Недавно установленные",
+            (InstalledModSort::InstallDate, Language::Turkish) => "Son yüklenen",
+            (InstalledModSort::InstallDate, Language::Italian) => "Installati di recente",
+            (InstalledModSort::InstallDate, Language::Japanese) => "最近インストールした順",
+            (InstalledModSort::InstallDate, Language::Korean) => "최근 설치 순",
+            (InstalledModSort::InstallDate, Language::Arabic) => "الأحدث تثبيتًا",
+            (InstalledModSort::InstallDate, Language::Polish) => "Ostatnio zainstalowane",
+            (InstalledModSort::InstallDate, Language::Vietnamese) => "Cài đặt gần đây",
+            (InstalledModSort::Size, Language::English) => "Largest first",
+            (InstalledModSort::Size, Language::Ukrainian) => "Спочатку найбільші",
+            (InstalledModSort::Size, Language::Spanish) => "Más grandes primero",
+            (InstalledModSort::Size, Language::French) => "Du plus grand au plus petit",
+            (InstalledModSort::Size, Language::German) => "Größte zuerst",
+            (InstalledModSort::Size, Language::Portuguese) => "Maiores primeiro",
+            (InstalledModSort::Size, Language::Chinese) => "体积从大到小",
+            (InstalledModSort::Size, Language::Hindi) => "सबसे बड़ा पहले",
+            (InstalledModSort::Size, Language::Russian) => "Сначала самые большие",
+            (InstalledModSort::Size, Language::Turkish) => "Önce en büyük",
+            (InstalledModSort::Size, Language::Italian) => "Prima i più grandi",
+            (InstalledModSort::Size, Language::Japanese) => "サイズが大きい順",
+            (InstalledModSort::Size, Language::Korean) => "큰 용량 순",
+            (InstalledModSort::Size, Language::Arabic) => "الأكبر أولاً",
+            (InstalledModSort::Size, Language::Polish) => "Od największych",
+            (InstalledModSort::Size, Language::Vietnamese) => "Lớn nhất trước",
         }
     }
 
+    pub fn installed_mod_filter_hint(self) -> &'static str {
+        self.pick(
+            "Filter installed mods...",
+            "Фільтрувати встановлені моди...",
+            "Filtrar mods instalados...",
+            "Filtrer les mods installés...",
+            "Installierte Mods filtern...",
+            "Filtrar mods instalados...",
+            "筛选已安装的模组...",
+            "इंस्टॉल किए गए मॉड फ़िल्टर करें...",
+            "Фильтр установленных модов...",
+            "Yüklü modları filtrele...",
+            "Filtra le mod installate...",
+            "インストール済みModを絞り込む...",
+            "설치된 모드 필터링...",
+            "تصفية الإضافات المثبتة...",
+            "Filtruj zainstalowane mody...",
+            "Lọc các mod đã cài đặt...",
+        )
+    }
+
     pub fn heading(self) -> &'static str {
         self.pick(
             "HRS Launcher",
@@ -155,6 +306,11 @@ impl I18n {
             "HRS лаунчер",
             "HRS Başlatıcı",
             "HRS Launcher",
+            "HRSランチャー",
+            "HRS 런처",
+            "مُشغّل HRS",
+            "HRS Launcher",
+            "HRS Launcher",
         )
     }
 
@@ -171,6 +327,11 @@ impl I18n {
             "Сообщественный лаунчер для Hytale",
             "Hytale için topluluk başlatıcısı",
             "Launcher comunitario per Hytale",
+            "Hytale向けコミュニティランチャー",
+            "Hytale용 커뮤니티 런처",
+            "مُشغّل مجتمعي لِلعبة Hytale",
+            "Launcher społecznościowy dla Hytale",
+            "Trình khởi chạy cộng đồng cho Hytale",
         )
     }
 
@@ -187,6 +348,11 @@ impl I18n {
             Language::Russian => format!("Лаунчер v{version}"),
             Language::Turkish => format!("Başlatıcı v{version}"),
             Language::Italian => format!("Launcher v{version}"),
+            Language::Japanese => format!("ランチャー v{version}"),
+            Language::Korean => format!("런처 v{version}"),
+            Language::Arabic => format!("المُشغّل v{version}"),
+            Language::Polish => format!("Launcher v{version}"),
+            Language::Vietnamese => format!("Launcher v{version}"),
         }
     }
 
@@ -203,6 +369,158 @@ impl I18n {
             "Присоединиться к нашему серверу Discord",
             "Discord sunucumuza katılın",
             "Unisciti al nostro server Discord",
+            "Discordサーバーに参加する",
+            "Discord 서버에 참여하세요",
+            "انضم إلى خادم Discord الخاص بنا",
+            "Dołącz do naszego serwera Discord",
+            "Tham gia máy chủ Discord của chúng tôi",
+        )
+    }
+
+    pub fn onboarding_heading(self) -> &'static str {
+        self.pick(
+            "Welcome to HRS Launcher",
+            "Ласкаво просимо до HRS Launcher",
+            "Bienvenido a HRS Launcher",
+            "Bienvenue dans HRS Launcher",
+            "Willkommen bei HRS Launcher",
+            "Bem-vindo ao HRS Launcher",
+            "欢迎使用 HRS Launcher",
+            "HRS Launcher में आपका स्वागत है",
+            "Добро пожаловать в HRS Launcher",
+            "HRS Launcher'a hoş geldiniz",
+            "Benvenuto in HRS Launcher",
+            "HRS Launcherへようこそ",
+            "HRS Launcher에 오신 것을 환영합니다",
+            "مرحبًا بك في HRS Launcher",
+            "Witamy w HRS Launcher",
+            "Chào mừng đến với HRS Launcher",
+        )
+    }
+
+    pub fn onboarding_intro(self) -> &'static str {
+        self.pick(
+            "A few quick steps before you jump in:",
+            "Кілька швидких кроків перед початком:",
+            "Unos pasos rápidos antes de empezar:",
+            "Quelques étapes rapides avant de commencer :",
+            "Ein paar kurze Schritte, bevor es losgeht:",
+            "Alguns passos rápidos antes de começar:",
+            "开始之前的几个简单步骤：",
+            "शुरू करने से पहले कुछ त्वरित चरण:",
+            "Несколько быстрых шагов перед началом:",
+            "Başlamadan önce birkaç hızlı adım:",
+            "Alcuni passaggi rapidi prima di iniziare:",
+            "始める前にいくつかの手順があります:",
+            "시작하기 전에 몇 가지 빠른 단계:",
+            "قبل أن تبدأ، إليك بضع خطوات سريعة:",
+            "Kilka szybkich kroków, zanim zaczniesz:",
+            "Một vài bước nhanh trước khi bạn bắt đầu:",
+        )
+    }
+
+    pub fn onboarding_step_player_name(self) -> &'static str {
+        self.pick(
+            "Set your player name",
+            "Вкажіть ім'я гравця",
+            "Configura tu nombre de jugador",
+            "Définissez votre nom de joueur",
+            "Lege deinen Spielernamen fest",
+            "Defina seu nome de jogador",
+            "设置你的玩家名称",
+            "अपना प्लेयर नाम सेट करें",
+            "Укажите имя игрока",
+            "Oyuncu adınızı belirleyin",
+            "Imposta il tuo nome giocatore",
+            "プレイヤー名を設定する",
+            "플레이어 이름을 설정하세요",
+            "قم بتعيين اسم اللاعب الخاص بك",
+            "Ustaw nazwę gracza",
+            "Đặt tên người chơi",
+        )
+    }
+
+    pub fn onboarding_step_version(self) -> &'static str {
+        self.pick(
+            "Pick a game version",
+            "Оберіть версію гри",
+            "Elige una versión del juego",
+            "Choisissez une version du jeu",
+            "Wähle eine Spielversion",
+            "Escolha uma versão do jogo",
+            "选择游戏版本",
+            "गेम संस्करण चुनें",
+            "Выберите версию игры",
+            "Bir oyun sürümü seçin",
+            "Scegli una versione del gioco",
+            "ゲームバージョンを選択する",
+            "게임 버전을 선택하세요",
+            "اختر إصدار اللعبة",
+            "Wybierz wersję gry",
+            "Chọn phiên bản trò chơi",
+        )
+    }
+
+    pub fn onboarding_step_download(self) -> &'static str {
+        self.pick(
+            "Download it",
+            "Завантажте її",
+            "Descárgala",
+            "Téléchargez-la",
+            "Lade sie herunter",
+            "Baixe-o",
+            "下载游戏",
+            "इसे डाउनलोड करें",
+            "Скачайте игру",
+            "İndirin",
+            "Scaricalo",
+            "ダウンロードする",
+            "다운로드하세요",
+            "قم بتنزيلها",
+            "Pobierz",
+            "Tải xuống",
+        )
+    }
+
+    pub fn onboarding_step_play(self) -> &'static str {
+        self.pick(
+            "Hit Play",
+            "Натисніть Грати",
+            "Pulsa Jugar",
+            "Cliquez sur Jouer",
+            "Klicke auf Spielen",
+            "Clique em Jogar",
+            "点击开始游戏",
+            "प्ले पर क्लिक करें",
+            "Нажмите Играть",
+            "Oyna'ya basın",
+            "Premi Gioca",
+            "プレイを押す",
+            "플레이를 누르세요",
+            "اضغط على تشغيل",
+            "Naciśnij Graj",
+            "Nhấn Chơi",
+        )
+    }
+
+    pub fn onboarding_dismiss_button(self) -> &'static str {
+        self.pick(
+            "Got it",
+            "Зрозуміло",
+            "Entendido",
+            "Compris",
+            "Verstanden",
+            "Entendi",
+            "知道了",
+            "समझ गया",
+            "Понятно",
+            "Anladım",
+            "Capito",
+            "了解しました",
+            "알겠습니다",
+            "حسنًا",
+            "Rozumiem",
+            "Đã hiểu",
         )
     }
 
@@ -219,6 +537,11 @@ impl I18n {
             "Статус",
             "Durum",
             "Stato",
+            "状態",
+            "상태",
+            "الحالة",
+            "Status",
+            "Trạng thái",
         )
     }
 
@@ -235,6 +558,11 @@ impl I18n {
             "Готово",
             "Hazır",
             "Pronto",
+            "準備完了",
+            "준비됨",
+            "جاهز",
+            "Gotowe",
+            "Sẵn sàng",
         )
     }
 
@@ -251,6 +579,11 @@ impl I18n {
             "Выполняется",
             "Çalışıyor",
             "In esecuzione",
+            "実行中",
+            "실행 중",
+            "قيد التشغيل",
+            "Uruchomione",
+            "Đang chạy",
         )
     }
 
@@ -267,6 +600,11 @@ impl I18n {
             "Внимание",
             "Dikkat",
             "Attenzione",
+            "注意",
+            "주의",
+            "تنبيه",
+            "Uwaga",
+            "Chú ý",
         )
     }
 
@@ -283,6 +621,32 @@ impl I18n {
             "Загрузка",
             "İndiriliyor",
             "Download in corso",
+            "ダウンロード中",
+            "다운로드 중",
+            "جارٍ التنزيل",
+            "Pobieranie",
+            "Đang tải xuống",
+        )
+    }
+
+    pub fn status_preparing_runtime(self) -> &'static str {
+        self.pick(
+            "Preparing runtime",
+            "Підготовка середовища виконання",
+            "Preparando el entorno de ejecución",
+            "Préparation du runtime",
+            "Runtime wird vorbereitet",
+            "Preparando o runtime",
+            "正在准备运行时",
+            "रनटाइम तैयार किया जा रहा है",
+            "Подготовка среды выполнения",
+            "Çalışma zamanı hazırlanıyor",
+            "Preparazione del runtime in corso",
+            "ランタイムを準備中",
+            "런타임 준비 중",
+            "جارٍ تجهيز بيئة التشغيل",
+            "Przygotowywanie środowiska uruchomieniowego",
+            "Đang chuẩn bị runtime",
         )
     }
 
@@ -299,6 +663,11 @@ impl I18n {
             "Удаление",
             "Kaldırılıyor",
             "Disinstallazione in corso",
+            "アンインストール中",
+            "제거 중",
+            "جارٍ إلغاء التثبيت",
+            "Odinstalowywanie",
+            "Đang gỡ cài đặt",
         )
     }
 
@@ -315,6 +684,53 @@ impl I18n {
             "Диагностика",
             "Tanılama",
             "Diagnostica",
+            "診断",
+            "진단",
+            "التشخيص",
+            "Diagnostyka",
+            "Chẩn đoán",
+        )
+    }
+
+    pub fn status_testing_java(self) -> &'static str {
+        self.pick(
+            "Testing Java",
+            "Перевірка Java",
+            "Probando Java",
+            "Test de Java",
+            "Java-Test",
+            "Testando Java",
+            "测试 Java",
+            "जावा परीक्षण",
+            "Проверка Java",
+            "Java Testi",
+            "Test di Java",
+            "Javaテスト",
+            "Java 테스트",
+            "اختبار Java",
+            "Testowanie Javy",
+            "Đang kiểm tra Java",
+        )
+    }
+
+    pub fn status_creating_crash_report(self) -> &'static str {
+        self.pick(
+            "Creating crash report",
+            "Створення звіту про збій",
+            "Creando informe de fallo",
+            "Création du rapport de plantage",
+            "Absturzbericht wird erstellt",
+            "Criando relatório de falha",
+            "正在创建崩溃报告",
+            "क्रैश रिपोर्ट बनाई जा रही है",
+            "Создание отчета о сбое",
+            "Çökme raporu oluşturuluyor",
+            "Creazione report crash in corso",
+            "クラッシュレポートを作成中",
+            "충돌 보고서 생성 중",
+            "جارٍ إنشاء تقرير الأعطال",
+            "Tworzenie raportu awarii",
+            "Đang tạo báo cáo lỗi",
         )
     }
 
@@ -331,6 +747,11 @@ impl I18n {
             "В работе",
             "İşleniyor",
             "In lavorazione",
+            "処理中",
+            "작업 중",
+            "جارٍ العمل",
+            "Pracuje",
+            "Đang xử lý",
         )
     }
 
@@ -347,6 +768,11 @@ impl I18n {
             "Обновить",
             "Yenile",
             "Aggiorna",
+            "更新",
+            "새로고침",
+            "تحديث",
+            "Odśwież",
+            "Làm mới",
         )
     }
 
@@ -363,6 +789,11 @@ impl I18n {
             "Выполняется диагностика...",
             "Tanılama çalışıyor...",
             "Diagnostica in esecuzione...",
+            "診断を実行中...",
+            "진단 실행 중...",
+            "جارٍ تشغيل التشخيص...",
+            "Uruchamianie diagnostyki...",
+            "Đang chạy chẩn đoán...",
         )
     }
 
@@ -379,6 +810,11 @@ impl I18n {
             "Диагностика завершена.",
             "Tanılama tamamlandı.",
             "Diagnostica completata.",
+            "診断が完了しました。",
+            "진단이 완료되었습니다.",
+            "اكتمل التشخيص.",
+            "Diagnostyka zakończona.",
+            "Đã hoàn tất chẩn đoán.",
         )
     }
 
@@ -395,6 +831,11 @@ impl I18n {
             "Отчет диагностики пока недоступен.",
             "Henüz bir tanılama raporu yok.",
             "Nessun report di diagnostica disponibile.",
+            "診断レポートはまだありません。",
+            "아직 진단 보고서가 없습니다.",
+            "لا يوجد تقرير تشخيص متاح حتى الآن.",
+            "Brak jeszcze dostępnego raportu diagnostycznego.",
+            "Chưa có báo cáo chẩn đoán nào.",
         )
     }
 
@@ -411,9 +852,35 @@ impl I18n {
             "Закрыть",
             "Kapat",
             "Chiudi",
+            "閉じる",
+            "닫기",
+            "إغلاق",
+            "Zamknij",
+            "Đóng",
         )
     }
 
+    pub fn news_unread_badge(self, count: usize) -> String {
+        match self.language {
+            Language::English => format!("{count} new"),
+            Language::Ukrainian => format!("{count} нових"),
+            Language::Spanish => format!("{count} nuevas"),
+            Language::French => format!("{count} nouvelles"),
+            Language::German => format!("{count} neu"),
+            Language::Portuguese => format!("{count} novas"),
+            Language::Chinese => format!("{count} 条新消息"),
+            Language::Hindi => format!("{count} नई"),
+            Language::Russian => format!("{count} новых"),
+            Language::Turkish => format!("{count} yeni"),
+            Language::Italian => format!("{count} nuove"),
+            Language::Japanese => format!("新着 {count} 件"),
+            Language::Korean => format!("신규 {count}개"),
+            Language::Arabic => format!("{count} جديد"),
+            Language::Polish => format!("{count} nowych"),
+            Language::Vietnamese => format!("{count} mới"),
+        }
+    }
+
     pub fn news_subheading(self) -> &'static str {
         self.pick(
             "What's happening in Hytale",
@@ -427,6 +894,11 @@ impl I18n {
             "Что происходит в Hytale",
             "Hytale'da neler oluyor",
             "Cosa sta succedendo in Hytale",
+            "Hytaleの最新情報",
+            "Hytale 소식",
+            "ما الجديد في Hytale",
+            "Co się dzieje w Hytale",
+            "Chuyện gì đang diễn ra trong Hytale",
         )
     }
 
@@ -443,6 +915,11 @@ impl I18n {
             "Обновление...",
             "Güncelleniyor...",
             "Aggiornamento in corso...",
+            "更新中...",
+            "업데이트 중...",
+            "جارٍ التحديث...",
+            "Aktualizowanie...",
+            "Đang cập nhật...",
         )
     }
 
@@ -459,6 +936,11 @@ impl I18n {
             Language::Russian => format!("Не удалось получить новости: {err}"),
             Language::Turkish => format!("Haberler alınamadı: {err}"),
             Language::Italian => format!("Impossibile caricare le notizie: {err}"),
+            Language::Japanese => format!("ニュースの取得に失敗しました: {err}"),
+            Language::Korean => format!("뉴스를 가져오지 못했습니다: {err}"),
+            Language::Arabic => format!("فشل جلب الأخبار: {err}"),
+            Language::Polish => format!("Nie udało się pobrać aktualności: {err}"),
+            Language::Vietnamese => format!("Không thể tải tin tức: {err}"),
         }
     }
 
@@ -475,6 +957,95 @@ impl I18n {
             "Подробнее на hytale.com.",
             "Daha fazlası için hytale.com.",
             "Детальніше на hytale.com.",
+            "hytale.comで詳細を見る。",
+            "hytale.com에서 자세히 보기.",
+            "اقرأ المزيد على hytale.com.",
+            "Więcej informacji na hytale.com.",
+            "Xem thêm tại hytale.com.",
+        )
+    }
+
+    pub fn news_read_more_button(self) -> &'static str {
+        self.pick(
+            "Read more",
+            "Читати далі",
+            "Leer más",
+            "Lire la suite",
+            "Weiterlesen",
+            "Leia mais",
+            "阅读更多",
+            "और पढ़ें",
+            "Читать далее",
+            "Devamını oku",
+            "Leggi di più",
+            "続きを読む",
+            "더 읽기",
+            "اقرأ المزيد",
+            "Czytaj więcej",
+            "Đọc thêm",
+        )
+    }
+
+    pub fn news_article_loading(self) -> &'static str {
+        self.pick(
+            "Loading article...",
+            "Завантаження статті...",
+            "Cargando artículo...",
+            "Chargement de l'article...",
+            "Artikel wird geladen...",
+            "Carregando artigo...",
+            "正在加载文章...",
+            "लेख लोड हो रहा है...",
+            "Загрузка статьи...",
+            "Makale yükleniyor...",
+            "Caricamento articolo...",
+            "記事を読み込み中...",
+            "기사를 불러오는 중...",
+            "جارٍ تحميل المقال...",
+            "Wczytywanie artykułu...",
+            "Đang tải bài viết...",
+        )
+    }
+
+    pub fn news_article_unavailable(self) -> &'static str {
+        self.pick(
+            "Couldn't load this article inline. Try opening it in your browser instead.",
+            "Не вдалося завантажити статтю. Спробуйте відкрити її в браузері.",
+            "No se pudo cargar este artículo. Prueba a abrirlo en tu navegador.",
+            "Impossible de charger cet article. Essayez de l'ouvrir dans votre navigateur.",
+            "Dieser Artikel konnte nicht geladen werden. Öffne ihn stattdessen im Browser.",
+            "Não foi possível carregar este artigo. Tente abri-lo no navegador.",
+            "无法加载此文章,请尝试在浏览器中打开。",
+            "यह लेख लोड नहीं हो सका। इसके बजाय इसे अपने ब्राउज़र में खोलने का प्रयास करें।",
+            "Не удалось загрузить статью. Попробуйте открыть её в браузере.",
+            "Bu makale yüklenemedi. Bunun yerine tarayıcınızda açmayı deneyin.",
+            "Impossibile caricare questo articolo. Prova ad aprirlo nel browser.",
+            "この記事を読み込めませんでした。代わりにブラウザで開いてみてください。",
+            "이 기사를 불러올 수 없습니다. 대신 브라우저에서 열어보세요.",
+            "تعذّر تحميل هذا المقال. حاول فتحه في متصفحك بدلاً من ذلك.",
+            "Nie udało się wczytać tego artykułu w aplikacji. Spróbuj otworzyć go w przeglądarce.",
+            "Không thể tải bài viết này trực tiếp. Hãy thử mở nó trong trình duyệt.",
+        )
+    }
+
+    pub fn news_open_in_browser_button(self) -> &'static str {
+        self.pick(
+            "Open in browser",
+            "Відкрити в браузері",
+            "Abrir en el navegador",
+            "Ouvrir dans le navigateur",
+            "Im Browser öffnen",
+            "Abrir no navegador",
+            "在浏览器中打开",
+            "ब्राउज़र में खोलें",
+            "Открыть в браузере",
+            "Tarayıcıda aç",
+            "Apri nel browser",
+            "ブラウザで開く",
+            "브라우저에서 열기",
+            "افتح في المتصفح",
+            "Otwórz w przeglądarce",
+            "Mở trong trình duyệt",
         )
     }
 
@@ -491,6 +1062,11 @@ impl I18n {
             "Моды",
             "Modlar",
             "Mod",
+            "モッド",
+            "모드",
+            "التعديلات",
+            "Mody",
+            "Mod",
         )
     }
 
@@ -507,6 +1083,11 @@ impl I18n {
             "Поиск...",
             "Aranıyor...",
             "Ricerca in corso...",
+            "検索中...",
+            "검색 중...",
+            "جارٍ البحث...",
+            "Szukanie...",
+            "Đang tìm kiếm...",
         )
     }
 
@@ -523,6 +1104,11 @@ impl I18n {
             Language::Russian => format!("{count} результатов"),
             Language::Turkish => format!("{count} sonuç"),
             Language::Italian => format!("{count} risultati"),
+            Language::Japanese => format!("{count} 件の結果"),
+            Language::Korean => format!("결과 {count}개"),
+            Language::Arabic => format!("{count} نتيجة"),
+            Language::Polish => format!("{count} wyników"),
+            Language::Vietnamese => format!("{count} kết quả"),
         }
     }
 
@@ -539,6 +1125,11 @@ impl I18n {
             "Поиск по названию или ключевому слову...",
             "Ada veya anahtar kelimeye göre arayın...",
             "Cerca per nome o parola chiave...",
+            "名前またはキーワードで検索...",
+            "이름이나 키워드로 검색...",
+            "البحث بالاسم أو الكلمة المفتاحية...",
+            "Szukaj po nazwie lub słowie kluczowym...",
+            "Tìm kiếm theo tên hoặc từ khóa...",
         )
     }
 
@@ -555,6 +1146,11 @@ impl I18n {
             "Поиск",
             "Ara",
             "Cerca",
+            "検索",
+            "검색",
+            "بحث",
+            "Szukaj",
+            "Tìm kiếm",
         )
     }
 
@@ -571,9 +1167,51 @@ impl I18n {
             "Очистить",
             "Temizle",
             "Cancella",
+            "クリア",
+            "지우기",
+            "مسح",
+            "Wyczyść",
+            "Xóa",
         )
     }
 
+    pub fn mod_density_label(self, density: ModDensity) -> &'static str {
+        match (density, self.language) {
+            (ModDensity::Comfortable, Language::English) => "Comfortable",
+            (ModDensity::Comfortable, Language::Ukrainian) => "Просторий",
+            (ModDensity::Comfortable, Language::Spanish) => "Cómodo",
+            (ModDensity::Comfortable, Language::French) => "Confortable",
+            (ModDensity::Comfortable, Language::German) => "Komfortabel",
+            (ModDensity::Comfortable, Language::Portuguese) => "Confortável",
+            (ModDensity::Comfortable, Language::Chinese) => "舒适",
+            (ModDensity::Comfortable, Language::Hindi) => "आरामदायक",
+            (ModDensity::Comfortable, Language::Russian) => "Просторный",
+            (ModDensity::Comfortable, Language::Turkish) => "Rahat",
+            (ModDensity::Comfortable, Language::Italian) => "Comodo",
+            (ModDensity::Comfortable, Language::Japanese) => "ゆったり",
+            (ModDensity::Comfortable, Language::Korean) => "여유롭게",
+            (ModDensity::Comfortable, Language::Arabic) => "مريح",
+            (ModDensity::Comfortable, Language::Polish) => "Komfortowy",
+            (ModDensity::Comfortable, Language::Vietnamese) => "Thoải mái",
+            (ModDensity::Compact, Language::English) => "Compact",
+            (ModDensity::Compact, Language::Ukrainian) => "Компактний",
+            (ModDensity::Compact, Language::Spanish) => "Compacto",
+            (ModDensity::Compact, Language::French) => "Compact",
+            (ModDensity::Compact, Language::German) => "Kompakt",
+            (ModDensity::Compact, Language::Portuguese) => "Compacto",
+            (ModDensity::Compact, Language::Chinese) => "紧凑",
+            (ModDensity::Compact, Language::Hindi) => "संक्षिप्त",
+            (ModDensity::Compact, Language::Russian) => "Компактный",
+            (ModDensity::Compact, Language::Turkish) => "Kompakt",
+            (ModDensity::Compact, Language::Italian) => "Compatto",
+            (ModDensity::Compact, Language::Japanese) => "コンパクト",
+            (ModDensity::Compact, Language::Korean) => "간결하게",
+            (ModDensity::Compact, Language::Arabic) => "مضغوط",
+            (ModDensity::Compact, Language::Polish) => "Kompaktowy",
+            (ModDensity::Compact, Language::Vietnamese) => "Gọn",
+        }
+    }
+
     pub fn mods_sort_label(self) -> &'static str {
         self.pick(
             "Sort by",
@@ -587,6 +1225,11 @@ impl I18n {
             "Сортировать по",
             "Sırala",
             "Ordina per",
+            "並び替え",
+            "정렬 기준",
+            "الترتيب حسب",
+            "Sortuj według",
+            "Sắp xếp theo",
         )
     }
 
@@ -603,6 +1246,11 @@ impl I18n {
             "Категория",
             "Kategori",
             "Categoria",
+            "カテゴリー",
+            "카테고리",
+            "الفئة",
+            "Kategoria",
+            "Danh mục",
         )
     }
 
@@ -619,9 +1267,162 @@ impl I18n {
             "Все категории",
             "Tüm kategoriler",
             "Tutte le categorie",
+            "すべてのカテゴリー",
+            "모든 카테고리",
+            "جميع الفئات",
+            "Wszystkie kategorie",
+            "Tất cả danh mục",
+        )
+    }
+
+    pub fn mods_min_downloads_label(self) -> &'static str {
+        self.pick(
+            "Min. downloads",
+            "Мін. завантажень",
+            "Descargas mín.",
+            "Téléch. min.",
+            "Min. Downloads",
+            "Mín. de downloads",
+            "最低下载量",
+            "न्यूनतम डाउनलोड",
+            "Мин. загрузок",
+            "Min. indirme",
+            "Download min.",
+            "最小ダウンロード数",
+            "최소 다운로드 수",
+            "الحد الأدنى للتنزيلات",
+            "Min. liczba pobrań",
+            "Số lượt tải tối thiểu",
+        )
+    }
+
+    pub fn mods_min_downloads_placeholder(self) -> &'static str {
+        self.pick(
+            "e.g. 1000",
+            "напр. 1000",
+            "p. ej. 1000",
+            "p. ex. 1000",
+            "z. B. 1000",
+            "ex. 1000",
+            "例如 1000",
+            "उदा. 1000",
+            "напр. 1000",
+            "örn. 1000",
+            "es. 1000",
+            "例: 1000",
+            "예: 1000",
+            "مثال: 1000",
+            "np. 1000",
+            "vd. 1000",
+        )
+    }
+
+    pub fn mods_recency_label(self) -> &'static str {
+        self.pick(
+            "Updated within",
+            "Оновлено за",
+            "Actualizado en",
+            "Mis à jour depuis",
+            "Aktualisiert innerhalb",
+            "Atualizado em",
+            "更新时间",
+            "अद्यतन अवधि",
+            "Обновлено за",
+            "Güncelleme süresi",
+            "Aggiornato entro",
+            "更新期間",
+            "업데이트 기간",
+            "تم التحديث خلال",
+            "Zaktualizowano w ciągu",
+            "Đã cập nhật trong vòng",
         )
     }
 
+    pub fn mod_recency_label(self, recency: ModRecency) -> &'static str {
+        match (recency, self.language) {
+            (ModRecency::Any, Language::English) => "Any time",
+            (ModRecency::Any, Language::Ukrainian) => "Будь-коли",
+            (ModRecency::Any, Language::Spanish) => "Cualquier momento",
+            (ModRecency::Any, Language::French) => "N'importe quand",
+            (ModRecency::Any, Language::German) => "Jederzeit",
+            (ModRecency::Any, Language::Portuguese) => "Qualquer período",
+            (ModRecency::Any, Language::Chinese) => "任何时间",
+            (ModRecency::Any, Language::Hindi) => "किसी भी समय",
+            (ModRecency::Any, Language::Russian) => "В любое время",
+            (ModRecency::Any, Language::Turkish) => "Her zaman",
+            (ModRecency::Any, Language::Italian) => "Qualsiasi periodo",
+            (ModRecency::Any, Language::Japanese) => "いつでも",
+            (ModRecency::Any, Language::Korean) => "전체 기간",
+            (ModRecency::Any, Language::Arabic) => "أي وقت",
+            (ModRecency::Any, Language::Polish) => "Dowolny czas",
+            (ModRecency::Any, Language::Vietnamese) => "Bất kỳ lúc nào",
+            (ModRecency::LastMonth, Language::English) => "Last month",
+            (ModRecency::LastMonth, Language::Ukrainian) => "Останній місяць",
+            (ModRecency::LastMonth, Language::Spanish) => "Último mes",
+            (ModRecency::LastMonth, Language::French) => "Dernier mois",
+            (ModRecency::LastMonth, Language::German) => "Letzter Monat",
+            (ModRecency::LastMonth, Language::Portuguese) => "Último mês",
+            (ModRecency::LastMonth, Language::Chinese) => "最近一个月",
+            (ModRecency::LastMonth, Language::Hindi) => "पिछला महीना",
+            (ModRecency::LastMonth, Language::Russian) => "Последний месяц",
+            (ModRecency::LastMonth, Language::Turkish) => "Son bir ay",
+            (ModRecency::LastMonth, Language::Italian) => "Ultimo mese",
+            (ModRecency::LastMonth, Language::Japanese) => "過去1ヶ月",
+            (ModRecency::LastMonth, Language::Korean) => "지난 1개월",
+            (ModRecency::LastMonth, Language::Arabic) => "الشهر الماضي",
+            (ModRecency::LastMonth, Language::Polish) => "Ostatni miesiąc",
+            (ModRecency::LastMonth, Language::Vietnamese) => "Tháng trước",
+            (ModRecency::Last3Months, Language::English) => "Last 3 months",
+            (ModRecency::Last3Months, Language::Ukrainian) => "Останні 3 місяці",
+            (ModRecency::Last3Months, Language::Spanish) => "Últimos 3 meses",
+            (ModRecency::Last3Months, Language::French) => "3 derniers mois",
+            (ModRecency::Last3Months, Language::German) => "Letzte 3 Monate",
+            (ModRecency::Last3Months, Language::Portuguese) => "Últimos 3 meses",
+            (ModRecency::Last3Months, Language::Chinese) => "最近3个月",
+            (ModRecency::Last3Months, Language::Hindi) => "पिछले 3 महीने",
+            (ModRecency::Last3Months, Language::Russian) => "Последние 3 месяца",
+            (ModRecency::Last3Months, Language::Turkish) => "Son 3 ay",
+            (ModRecency::Last3Months, Language::Italian) => "Ultimi 3 mesi",
+            (ModRecency::Last3Months, Language::Japanese) => "過去3ヶ月",
+            (ModRecency::Last3Months, Language::Korean) => "지난 3개월",
+            (ModRecency::Last3Months, Language::Arabic) => "آخر 3 أشهر",
+            (ModRecency::Last3Months, Language::Polish) => "Ostatnie 3 miesiące",
+            (ModRecency::Last3Months, Language::Vietnamese) => "3 tháng qua",
+            (ModRecency::Last6Months, Language::English) => "Last 6 months",
+            (ModRecency::Last6Months, Language::Ukrainian) => "Останні 6 місяців",
+            (ModRecency::Last6Months, Language::Spanish) => "Últimos 6 meses",
+            (ModRecency::Last6Months, Language::French) => "6 derniers mois",
+            (ModRecency::Last6Months, Language::German) => "Letzte 6 Monate",
+            (ModRecency::Last6Months, Language::Portuguese) => "Últimos 6 meses",
+            (ModRecency::Last6Months, Language::Chinese) => "最近6个月",
+            (ModRecency::Last6Months, Language::Hindi) => "पिछले 6 महीने",
+            (ModRecency::Last6Months, Language::Russian) => "Последние 6 месяцев",
+            (ModRecency::Last6Months, Language::Turkish) => "Son 6 ay",
+            (ModRecency::Last6Months, Language::Italian) => "Ultimi 6 mesi",
+            (ModRecency::Last6Months, Language::Japanese) => "過去6ヶ月",
+            (ModRecency::Last6Months, Language::Korean) => "지난 6개월",
+            (ModRecency::Last6Months, Language::Arabic) => "آخر 6 أشهر",
+            (ModRecency::Last6Months, Language::Polish) => "Ostatnie 6 miesięcy",
+            (ModRecency::Last6Months, Language::Vietnamese) => "6 tháng qua",
+            (ModRecency::LastYear, Language::English) => "Last 12 months",
+            (ModRecency::LastYear, Language::Ukrainian) => "Останні 12 місяців",
+            (ModRecency::LastYear, Language::Spanish) => "Últimos 12 meses",
+            (ModRecency::LastYear, Language::French) => "12 derniers mois",
+            (ModRecency::LastYear, Language::German) => "Letzte 12 Monate",
+            (ModRecency::LastYear, Language::Portuguese) => "Últimos 12 meses",
+            (ModRecency::LastYear, Language::Chinese) => "最近12个月",
+            (ModRecency::LastYear, Language::Hindi) => "पिछले 12 महीने",
+            (ModRecency::LastYear, Language::Russian) => "Последние 12 месяцев",
+            (ModRecency::LastYear, Language::Turkish) => "Son 12 ay",
+            (ModRecency::LastYear, Language::Italian) => "Ultimi 12 mesi",
+            (ModRecency::LastYear, Language::Japanese) => "過去12ヶ月",
+            (ModRecency::LastYear, Language::Korean) => "지난 12개월",
+            (ModRecency::LastYear, Language::Arabic) => "آخر 12 شهرًا",
+            (ModRecency::LastYear, Language::Polish) => "Ostatnie 12 miesięcy",
+            (ModRecency::LastYear, Language::Vietnamese) => "12 tháng qua",
+        }
+    }
+
     pub fn mods_showing(self, visible: usize, total: usize) -> String {
         match self.language {
             Language::English => format!("Showing {visible} of {total} mods"),
@@ -635,6 +1436,11 @@ impl I18n {
             Language::Russian => format!("Показано {visible} из {total} модов"),
             Language::Turkish => format!("{total} modun {visible} tanesi gösteriliyor"),
             Language::Italian => format!("Mostrate {visible} di {total} mod"),
+            Language::Japanese => format!("{total}個のモッドのうち{visible}個を表示"),
+            Language::Korean => format!("{total}개 모드 중 {visible}개 표시 중"),
+            Language::Arabic => format!("يتم عرض {visible} من {total} تعديل"),
+            Language::Polish => format!("Wyświetlanie {visible} z {total} modów"),
+            Language::Vietnamese => format!("Hiển thị {visible} trong số {total} mod"),
         }
     }
 
@@ -651,6 +1457,11 @@ impl I18n {
             Language::Russian => format!("Ошибка поиска: {err}"),
             Language::Turkish => format!("Arama başarısız: {err}"),
             Language::Italian => format!("Ricerca fallita: {err}"),
+            Language::Japanese => format!("検索に失敗しました: {err}"),
+            Language::Korean => format!("검색 실패: {err}"),
+            Language::Arabic => format!("فشل البحث: {err}"),
+            Language::Polish => format!("Wyszukiwanie nie powiodło się: {err}"),
+            Language::Vietnamese => format!("Tìm kiếm thất bại: {err}"),
         }
     }
 
@@ -667,6 +1478,11 @@ impl I18n {
             "Моды не загружены. Попробуйте поиск по названию.",
             "Mod yüklenmedi. İsimle aramayı deneyin.",
             "Nessuna mod caricata. Prova a cercare per nome.",
+            "モッドが読み込まれていません。名前で検索してみてください。",
+            "로드된 모드가 없습니다. 이름으로 검색해 보세요.",
+            "لم يتم تحميل أي تعديلات. حاول البحث بالاسم.",
+            "Nie wczytano żadnych modów. Spróbuj wyszukać po nazwie.",
+            "Không có mod nào được tải. Hãy thử tìm kiếm theo tên.",
         )
     }
 
@@ -683,6 +1499,11 @@ impl I18n {
             "Нет модов, соответствующих текущим фильтрам.",
             "Mevcut filtrelere uyan mod yok.",
             "Nessuna mod corrisponde ai filtri attuali.",
+            "現在のフィルターに一致するモッドはありません。",
+            "현재 필터와 일치하는 모드가 없습니다.",
+            "لا توجد تعديلات تطابق عوامل التصفية الحالية.",
+            "Żaden mod nie pasuje do bieżących filtrów.",
+            "Không có mod nào khớp với bộ lọc hiện tại.",
         )
     }
 
@@ -699,6 +1520,11 @@ impl I18n {
             "Установленные моды",
             "Yüklü modlar",
             "Mod installate",
+            "インストール済みのモッド",
+            "설치된 모드",
+            "التعديلات المثبتة",
+            "Zainstalowane mody",
+            "Mod đã cài đặt",
         )
     }
 
@@ -715,6 +1541,32 @@ impl I18n {
             "Моды еще не установлены.",
             "Henüz mod kurulmadı.",
             "Nessuna mod installata.",
+            "まだモッドがインストールされていません。",
+            "아직 설치된 모드가 없습니다.",
+            "لا توجد تعديلات مثبتة حتى الآن.",
+            "Nie zainstalowano jeszcze żadnych modów.",
+            "Chưa cài đặt mod nào.",
+        )
+    }
+
+    pub fn mods_installed_no_matches(self) -> &'static str {
+        self.pick(
+            "No installed mods match that filter.",
+            "Жоден встановлений мод не відповідає фільтру.",
+            "Ningún mod instalado coincide con ese filtro.",
+            "Aucun mod installé ne correspond à ce filtre.",
+            "Keine installierten Mods entsprechen diesem Filter.",
+            "Nenhum mod instalado corresponde a esse filtro.",
+            "没有已安装的模组匹配该筛选条件。",
+            "कोई इंस्टॉल किया गया मॉड इस फ़िल्टर से मेल नहीं खाता।",
+            "Ни один установленный мод не соответствует фильтру.",
+            "Hiçbir yüklü mod bu filtreyle eşleşmiyor.",
+            "Nessuna mod installata corrisponde a questo filtro.",
+            "このフィルターに一致するインストール済みModはありません。",
+            "이 필터와 일치하는 설치된 모드가 없습니다.",
+            "لا توجد إضافة مثبتة تطابق هذا الفلتر.",
+            "Żaden zainstalowany mod nie pasuje do tego filtra.",
+            "Không có mod nào đã cài đặt khớp với bộ lọc đó.",
         )
     }
 
@@ -731,6 +1583,221 @@ impl I18n {
             Language::Russian => format!("Не удалось получить установленные моды: {err}"),
             Language::Turkish => format!("Yüklü modlar alınamadı: {err}"),
             Language::Italian => format!("Impossibile caricare le mod installate: {err}"),
+            Language::Japanese => format!("インストール済みモッドの取得に失敗しました: {err}"),
+            Language::Korean => format!("설치된 모드를 가져오지 못했습니다: {err}"),
+            Language::Arabic => format!("فشل جلب التعديلات المثبتة: {err}"),
+            Language::Polish => format!("Błąd zainstalowanych modów: {err}"),
+            Language::Vietnamese => format!("Lỗi mod đã cài đặt: {err}"),
+        }
+    }
+
+    pub fn mods_download_failed(self, err: &str) -> String {
+        match self.language {
+            Language::English => format!("Mod install failed: {err}"),
+            Language::Ukrainian => format!("Не вдалося встановити мод: {err}"),
+            Language::Spanish => format!("Error al instalar el mod: {err}"),
+            Language::French => format!("Échec de l'installation du mod : {err}"),
+            Language::German => format!("Mod-Installation fehlgeschlagen: {err}"),
+            Language::Portuguese => format!("Falha ao instalar o mod: {err}"),
+            Language::Chinese => format!("模组安装失败: {err}"),
+            Language::Hindi => format!("मॉड इंस्टॉल करने में विफल: {err}"),
+            Language::Russian => format!("Не удалось установить мод: {err}"),
+            Language::Turkish => format!("Mod kurulamadı: {err}"),
+            Language::Italian => format!("Installazione della mod non riuscita: {err}"),
+            Language::Japanese => format!("モッドのインストールに失敗しました: {err}"),
+            Language::Korean => format!("모드 설치에 실패했습니다: {err}"),
+            Language::Arabic => format!("فشل تثبيت التعديل: {err}"),
+            Language::Polish => format!("Instalacja moda nie powiodła się: {err}"),
+            Language::Vietnamese => format!("Cài đặt mod thất bại: {err}"),
+        }
+    }
+
+    pub fn mods_show_file_error(self, err: &str) -> String {
+        match self.language {
+            Language::English => format!("Couldn't show file: {err}"),
+            Language::Ukrainian => format!("Не вдалося показати файл: {err}"),
+            Language::Spanish => format!("No se pudo mostrar el archivo: {err}"),
+            Language::French => format!("Impossible d'afficher le fichier : {err}"),
+            Language::German => format!("Datei konnte nicht angezeigt werden: {err}"),
+            Language::Portuguese => format!("Não foi possível mostrar o arquivo: {err}"),
+            Language::Chinese => format!("无法显示文件: {err}"),
+            Language::Hindi => format!("फ़ाइल दिखाई नहीं जा सकी: {err}"),
+            Language::Russian => format!("Не удалось показать файл: {err}"),
+            Language::Turkish => format!("Dosya gösterilemedi: {err}"),
+            Language::Italian => format!("Impossibile mostrare il file: {err}"),
+            Language::Japanese => format!("ファイルを表示できませんでした: {err}"),
+            Language::Korean => format!("파일을 표시할 수 없습니다: {err}"),
+            Language::Arabic => format!("تعذر عرض الملف: {err}"),
+            Language::Polish => format!("Nie udało się pokazać pliku: {err}"),
+            Language::Vietnamese => format!("Không thể hiển thị tệp: {err}"),
+        }
+    }
+
+    pub fn mods_whats_new_header(self) -> &'static str {
+        self.pick(
+            "What's new",
+            "Що нового",
+            "Novedades",
+            "Nouveautés",
+            "Was ist neu",
+            "Novidades",
+            "更新内容",
+            "नया क्या है",
+            "Что нового",
+            "Yenilikler",
+            "Novità",
+            "新着情報",
+            "새로운 소식",
+            "الجديد",
+            "Co nowego",
+            "Có gì mới",
+        )
+    }
+
+    pub fn mods_changelog_empty(self) -> &'static str {
+        self.pick(
+            "No changelog available for this version.",
+            "Для цієї версії немає списку змін.",
+            "No hay notas de la versión para esta versión.",
+            "Aucune note de version disponible pour cette version.",
+            "Für diese Version ist kein Änderungsprotokoll verfügbar.",
+            "Não há notas de versão disponíveis para esta versão.",
+            "此版本没有可用的更新日志。",
+            "इस संस्करण के लिए कोई चेंजलॉग उपलब्ध नहीं है।",
+            "Список изменений для этой версии недоступен.",
+            "Bu sürüm için değişiklik günlüğü yok.",
+            "Nessun changelog disponibile per questa versione.",
+            "このバージョンの変更履歴はありません。",
+            "이 버전에 대한 변경 로그가 없습니다.",
+            "لا يوجد سجل تغييرات متاح لهذا الإصدار.",
+            "Brak listy zmian dla tej wersji.",
+            "Không có nhật ký thay đổi cho phiên bản này.",
+        )
+    }
+
+    pub fn mods_changelog_failed(self, err: &str) -> String {
+        match self.language {
+            Language::English => format!("Couldn't load changelog: {err}"),
+            Language::Ukrainian => format!("Не вдалося завантажити список змін: {err}"),
+            Language::Spanish => format!("No se pudieron cargar las notas de la versión: {err}"),
+            Language::French => format!("Impossible de charger les notes de version : {err}"),
+            Language::German => format!("Änderungsprotokoll konnte nicht geladen werden: {err}"),
+            Language::Portuguese => format!("Não foi possível carregar as notas de versão: {err}"),
+            Language::Chinese => format!("无法加载更新日志: {err}"),
+            Language::Hindi => format!("चेंजलॉग लोड नहीं हो सका: {err}"),
+            Language::Russian => format!("Не удалось загрузить список изменений: {err}"),
+            Language::Turkish => format!("Değişiklik günlüğü yüklenemedi: {err}"),
+            Language::Italian => format!("Impossibile caricare il changelog: {err}"),
+            Language::Japanese => format!("変更履歴を読み込めませんでした: {err}"),
+            Language::Korean => format!("변경 로그를 불러올 수 없습니다: {err}"),
+            Language::Arabic => format!("تعذر تحميل سجل التغييرات: {err}"),
+            Language::Polish => format!("Nie udało się wczytać listy zmian: {err}"),
+            Language::Vietnamese => format!("Không thể tải nhật ký thay đổi: {err}"),
+        }
+    }
+
+    pub fn mods_missing_dependencies(self, ids: &str) -> String {
+        match self.language {
+            Language::English => format!("This mod needs these CurseForge mods installed too: {ids}"),
+            Language::Ukrainian => format!("Цьому моду також потрібні ці моди CurseForge: {ids}"),
+            Language::Spanish => format!("Este mod también necesita estos mods de CurseForge: {ids}"),
+            Language::French => format!("Ce mod nécessite aussi ces mods CurseForge : {ids}"),
+            Language::German => format!("Dieser Mod benötigt außerdem diese CurseForge-Mods: {ids}"),
+            Language::Portuguese => format!("Este mod também precisa destes mods do CurseForge: {ids}"),
+            Language::Chinese => format!("该模组还需要这些 CurseForge 模组：{ids}"),
+            Language::Hindi => format!("इस मॉड को इन CurseForge मॉड्स की भी आवश्यकता है: {ids}"),
+            Language::Russian => format!("Этому моду также нужны следующие моды CurseForge: {ids}"),
+            Language::Turkish => format!("Bu mod ayrıca şu CurseForge modlarına ihtiyaç duyuyor: {ids}"),
+            Language::Italian => format!("Questa mod richiede anche queste mod CurseForge: {ids}"),
+            Language::Japanese => format!("このModには以下のCurseForge Modも必要です: {ids}"),
+            Language::Korean => format!("이 모드에는 다음 CurseForge 모드도 필요합니다: {ids}"),
+            Language::Arabic => format!("تحتاج هذه الإضافة أيضًا إلى إضافات CurseForge التالية: {ids}"),
+            Language::Polish => format!("Ten mod wymaga zainstalowania również tych modów CurseForge: {ids}"),
+            Language::Vietnamese => format!("Mod này cần cài đặt thêm các mod CurseForge sau: {ids}"),
+        }
+    }
+
+    pub fn mods_installing_progress(self, completed: u32, total: u32) -> String {
+        match self.language {
+            Language::English => format!("Installing mod {} of {}...", completed + 1, total),
+            Language::Ukrainian => format!("Встановлення мода {} з {}...", completed + 1, total),
+            Language::Spanish => format!("Instalando mod {} de {}...", completed + 1, total),
+            Language::French => format!("Installation du mod {} sur {}...", completed + 1, total),
+            Language::German => format!("Installiere Mod {} von {}...", completed + 1, total),
+            Language::Portuguese => format!("Instalando mod {} de {}...", completed + 1, total),
+            Language::Chinese => format!("正在安装第 {} / {} 个模组...", completed + 1, total),
+            Language::Hindi => format!("मॉड {} / {} इंस्टॉल हो रहा है...", completed + 1, total),
+            Language::Russian => format!("Установка мода {} из {}...", completed + 1, total),
+            Language::Turkish => format!("Mod {} / {} kuruluyor...", completed + 1, total),
+            Language::Italian => format!("Installazione mod {} di {}...", completed + 1, total),
+            Language::Japanese => format!("モッド {} / {} をインストール中...", completed + 1, total),
+            Language::Korean => format!("모드 {} / {} 설치 중...", completed + 1, total),
+            Language::Arabic => format!("تثبيت التعديل {} من {}...", completed + 1, total),
+            Language::Polish => format!("Instalowanie moda {} z {}...", completed + 1, total),
+            Language::Vietnamese => format!("Đang cài đặt mod {} trong {}...", completed + 1, total),
+        }
+    }
+
+    pub fn mods_cancel_installs(self) -> &'static str {
+        self.pick(
+            "Cancel installs",
+            "Скасувати встановлення",
+            "Cancelar instalación",
+            "Annuler les installations",
+            "Installationen abbrechen",
+            "Cancelar instalações",
+            "取消安装",
+            "इंस्टॉल रद्द करें",
+            "Отменить установку",
+            "Kurulumları iptal et",
+            "Annulla installazioni",
+            "インストールをキャンセル",
+            "설치 취소",
+            "إلغاء عمليات التثبيت",
+            "Anuluj instalacje",
+            "Hủy cài đặt",
+        )
+    }
+
+    pub fn mods_size(self, size: &str) -> String {
+        match self.language {
+            Language::English => format!("Size {size}"),
+            Language::Ukrainian => format!("Розмір {size}"),
+            Language::Spanish => format!("Tamaño {size}"),
+            Language::French => format!("Taille {size}"),
+            Language::German => format!("Größe {size}"),
+            Language::Portuguese => format!("Tamanho {size}"),
+            Language::Chinese => format!("大小 {size}"),
+            Language::Hindi => format!("आकार {size}"),
+            Language::Russian => format!("Размер {size}"),
+            Language::Turkish => format!("Boyut {size}"),
+            Language::Italian => format!("Dimensione {size}"),
+            Language::Japanese => format!("サイズ {size}"),
+            Language::Korean => format!("크기 {size}"),
+            Language::Arabic => format!("الحجم {size}"),
+            Language::Polish => format!("Rozmiar {size}"),
+            Language::Vietnamese => format!("Kích thước {size}"),
+        }
+    }
+
+    pub fn mods_total_size(self, size: &str) -> String {
+        match self.language {
+            Language::English => format!("Mods using {size}"),
+            Language::Ukrainian => format!("Моди займають {size}"),
+            Language::Spanish => format!("Mods ocupan {size}"),
+            Language::French => format!("Mods utilisant {size}"),
+            Language::German => format!("Mods belegen {size}"),
+            Language::Portuguese => format!("Mods ocupam {size}"),
+            Language::Chinese => format!("模组占用 {size}"),
+            Language::Hindi => format!("मॉड्स {size} उपयोग कर रहे हैं"),
+            Language::Russian => format!("Моды занимают {size}"),
+            Language::Turkish => format!("Modlar {size} kullanıyor"),
+            Language::Italian => format!("Le mod occupano {size}"),
+            Language::Japanese => format!("Modの使用量 {size}"),
+            Language::Korean => format!("모드 사용량 {size}"),
+            Language::Arabic => format!("الإضافات تستخدم {size}"),
+            Language::Polish => format!("Mody zajmują {size}"),
+            Language::Vietnamese => format!("Mod sử dụng {size}"),
         }
     }
 
@@ -747,6 +1814,11 @@ impl I18n {
             "Обновить список",
             "Yüklüleri yenile",
             "Aggiorna installate",
+            "インストール済みを更新",
+            "설치 목록 새로고침",
+            "تحديث القائمة المثبتة",
+            "Odśwież zainstalowane",
+            "Làm mới đã cài đặt",
         )
     }
 
@@ -763,6 +1835,32 @@ impl I18n {
             "Удалить",
             "Kaldır",
             "Rimuovi",
+            "削除",
+            "제거",
+            "إزالة",
+            "Usuń",
+            "Xóa",
+        )
+    }
+
+    pub fn mods_show_file_button(self) -> &'static str {
+        self.pick(
+            "Show file",
+            "Показати файл",
+            "Mostrar archivo",
+            "Afficher le fichier",
+            "Datei anzeigen",
+            "Mostrar arquivo",
+            "显示文件",
+            "फ़ाइल दिखाएं",
+            "Показать файл",
+            "Dosyayı göster",
+            "Mostra file",
+            "ファイルを表示",
+            "파일 표시",
+            "إظهار الملف",
+            "Pokaż plik",
+            "Hiển thị tệp",
         )
     }
 
@@ -779,6 +1877,11 @@ impl I18n {
             "Установите игру, чтобы включить установку модов.",
             "Mod kurulumu için önce oyunu yükleyin.",
             "Installa il gioco per abilitare l'installazione delle mod.",
+            "モッドのインストールを有効にするには、ゲームをインストールしてください。",
+            "모드 설치를 사용하려면 게임을 설치하세요.",
+            "قم بتثبيت اللعبة لتمكين تثبيت التعديلات.",
+            "Zainstaluj grę, aby włączyć instalację modów.",
+            "Cài đặt trò chơi để bật cài đặt mod.",
         )
     }
 
@@ -795,6 +1898,11 @@ impl I18n {
             "Выбрать файлы модов",
             "Mod dosyalarını seç",
             "Seleziona file mod",
+            "モッドファイルを選択",
+            "모드 파일 선택",
+            "اختر ملفات التعديل",
+            "Wybierz pliki modów",
+            "Chọn tệp mod",
         )
     }
 
@@ -811,6 +1919,74 @@ impl I18n {
             "Установка модов временно недоступна.",
             "Mod kurulumu geçici olarak devre dışı.",
             "Installazione mod temporaneamente disabilitata.",
+            "モッドのインストールは一時的に無効になっています。",
+            "모드 설치가 일시적으로 비활성화되었습니다.",
+            "تثبيت التعديلات معطل مؤقتًا.",
+            "Instalowanie modów jest tymczasowo wyłączone.",
+            "Cài đặt mod tạm thời bị vô hiệu hóa.",
+        )
+    }
+
+    pub fn mods_live_search_toggle(self) -> &'static str {
+        self.pick(
+            "Search as I type",
+            "Шукати під час введення",
+            "Buscar mientras escribo",
+            "Rechercher en tapant",
+            "Während der Eingabe suchen",
+            "Pesquisar ao digitar",
+            "输入时实时搜索",
+            "टाइप करते समय खोजें",
+            "Искать во время ввода",
+            "Yazarken ara",
+            "Cerca mentre scrivo",
+            "入力中に検索",
+            "입력하는 동안 검색",
+            "البحث أثناء الكتابة",
+            "Szukaj podczas pisania",
+            "Tìm kiếm khi gõ",
+        )
+    }
+
+    pub fn mods_drop_hint(self) -> &'static str {
+        self.pick(
+            "Drop .zip or .jar mod archives to install",
+            "Перетягніть архіви .zip або .jar для встановлення",
+            "Suelta archivos .zip o .jar para instalarlos",
+            "Déposez des archives .zip ou .jar pour les installer",
+            "Lege .zip- oder .jar-Mod-Archive zum Installieren ab",
+            "Solte arquivos .zip ou .jar para instalar",
+            "拖放 .zip 或 .jar 模组档案以安装",
+            "इंस्टॉल करने के लिए .zip या .jar मॉड फ़ाइलें छोड़ें",
+            "Перетащите архивы .zip или .jar для установки",
+            "Kurmak için .zip veya .jar mod arşivlerini bırakın",
+            "Rilascia archivi mod .zip o .jar per installarli",
+            ".zipまたは.jarのモッドアーカイブをドロップしてインストール",
+            "설치할 .zip 또는 .jar 모드 파일을 놓으세요",
+            "أفلت أرشيفات التعديلات .zip أو .jar للتثبيت",
+            "Upuść archiwa modów .zip lub .jar, aby zainstalować",
+            "Thả tệp mod .zip hoặc .jar vào đây để cài đặt",
+        )
+    }
+
+    pub fn mods_drop_rejected(self) -> &'static str {
+        self.pick(
+            "Only .zip or .jar mod archives can be installed this way.",
+            "Цим способом можна встановити лише архіви .zip або .jar.",
+            "Solo se pueden instalar archivos .zip o .jar de esta manera.",
+            "Seules les archives .zip ou .jar peuvent être installées ainsi.",
+            "Nur .zip- oder .jar-Mod-Archive können so installiert werden.",
+            "Apenas arquivos .zip ou .jar podem ser instalados desta forma.",
+            "只能通过此方式安装 .zip 或 .jar 模组档案。",
+            "इस तरह केवल .zip या .jar मॉड फ़ाइलें इंस्टॉल की जा सकती हैं।",
+            "Таким способом можно установить только архивы .zip или .jar.",
+            "Bu şekilde yalnızca .zip veya .jar mod arşivleri kurulabilir.",
+            "In questo modo si possono installare solo archivi mod .zip o .jar.",
+            "この方法でインストールできるのは.zipまたは.jarのモッドアーカイブのみです。",
+            "이 방법으로는 .zip 또는 .jar 모드 파일만 설치할 수 있습니다.",
+            "يمكن تثبيت أرشيفات .zip أو .jar فقط بهذه الطريقة.",
+            "W ten sposób można instalować tylko archiwa modów .zip lub .jar.",
+            "Chỉ có thể cài đặt tệp mod .zip hoặc .jar theo cách này.",
         )
     }
 
@@ -827,6 +2003,74 @@ impl I18n {
             "Установить",
             "Yükle",
             "Installa",
+            "インストール",
+            "설치",
+            "تثبيت",
+            "Zainstaluj",
+            "Cài đặt",
+        )
+    }
+
+    pub fn mods_copy_link_button(self) -> &'static str {
+        self.pick(
+            "Copy link",
+            "Копіювати посилання",
+            "Copiar enlace",
+            "Copier le lien",
+            "Link kopieren",
+            "Copiar link",
+            "复制链接",
+            "लिंक कॉपी करें",
+            "Копировать ссылку",
+            "Bağlantıyı kopyala",
+            "Copia link",
+            "リンクをコピー",
+            "링크 복사",
+            "نسخ الرابط",
+            "Kopiuj link",
+            "Sao chép liên kết",
+        )
+    }
+
+    pub fn mods_copy_link_hint(self) -> &'static str {
+        self.pick(
+            "Copy this mod's CurseForge page URL to the clipboard.",
+            "Копіювати URL-адресу сторінки мода на CurseForge в буфер обміну.",
+            "Copia la URL de la página de CurseForge de este mod al portapapeles.",
+            "Copie l'URL de la page CurseForge de ce mod dans le presse-papiers.",
+            "Kopiert die CurseForge-Seiten-URL dieses Mods in die Zwischenablage.",
+            "Copia a URL da página do CurseForge deste mod para a área de transferência.",
+            "将该模组的 CurseForge 页面链接复制到剪贴板。",
+            "इस मॉड के CurseForge पेज का URL क्लिपबोर्ड पर कॉपी करें।",
+            "Копировать URL страницы мода на CurseForge в буфер обмена.",
+            "Bu modun CurseForge sayfası URL'sini panoya kopyalar.",
+            "Copia l'URL della pagina CurseForge di questa mod negli appunti.",
+            "このMODのCurseForgeページURLをクリップボードにコピーします。",
+            "이 모드의 CurseForge 페이지 URL을 클립보드에 복사합니다.",
+            "نسخ رابط صفحة CurseForge لهذا التعديل إلى الحافظة.",
+            "Skopiuj adres URL strony CurseForge tego moda do schowka.",
+            "Sao chép URL trang CurseForge của mod này vào bộ nhớ tạm.",
+        )
+    }
+
+    pub fn mods_link_copied(self) -> &'static str {
+        self.pick(
+            "Copied!",
+            "Скопійовано!",
+            "¡Copiado!",
+            "Copié !",
+            "Kopiert!",
+            "Copiado!",
+            "已复制!",
+            "कॉपी हो गया!",
+            "Скопировано!",
+            "Kopyalandı!",
+            "Copiato!",
+            "コピーしました!",
+            "복사됨!",
+            "تم النسخ!",
+            "Skopiowano!",
+            "Đã sao chép!",
         )
     }
 
@@ -843,6 +2087,11 @@ impl I18n {
             Language::Russian => format!("Загрузки {downloads}"),
             Language::Turkish => format!("İndirme {downloads}"),
             Language::Italian => format!("Download {downloads}"),
+            Language::Japanese => format!("ダウンロード数 {downloads}"),
+            Language::Korean => format!("다운로드 {downloads}"),
+            Language::Arabic => format!("التنزيلات {downloads}"),
+            Language::Polish => format!("Pobrania {downloads}"),
+            Language::Vietnamese => format!("Lượt tải {downloads}"),
         }
     }
 
@@ -859,6 +2108,11 @@ impl I18n {
             Language::Russian => format!("Обновлено {updated}"),
             Language::Turkish => format!("{updated} güncellendi"),
             Language::Italian => format!("Aggiornata {updated}"),
+            Language::Japanese => format!("更新日 {updated}"),
+            Language::Korean => format!("업데이트 {updated}"),
+            Language::Arabic => format!("تم التحديث {updated}"),
+            Language::Polish => format!("Zaktualizowano {updated}"),
+            Language::Vietnamese => format!("Cập nhật {updated}"),
         }
     }
 
@@ -875,6 +2129,11 @@ impl I18n {
             Language::Russian => format!("От {authors}"),
             Language::Turkish => format!("{authors} tarafından"),
             Language::Italian => format!("Di {authors}"),
+            Language::Japanese => format!("作者: {authors}"),
+            Language::Korean => format!("{authors} 제작"),
+            Language::Arabic => format!("بواسطة {authors}"),
+            Language::Polish => format!("Autor: {authors}"),
+            Language::Vietnamese => format!("Bởi {authors}"),
         }
     }
 
@@ -891,6 +2150,11 @@ impl I18n {
             "Управление лаунчером",
             "Başlatıcı kontrolleri",
             "Controlli launcher",
+            "ランチャーの操作",
+            "런처 제어",
+            "أدوات التحكم بالمُشغّل",
+            "Sterowanie launcherem",
+            "Điều khiển trình khởi chạy",
         )
     }
 
@@ -907,6 +2171,74 @@ impl I18n {
             "Управляйте обновлениями и играйте",
             "Güncellemeleri yönetin ve oynayın",
             "Gestisci aggiornamenti e gioca",
+            "更新の管理とプレイ",
+            "업데이트 관리 및 플레이",
+            "إدارة التحديثات واللعب",
+            "Zarządzaj aktualizacjami i graj",
+            "Quản lý cập nhật và chơi",
+        )
+    }
+
+    pub fn last_played(self, relative: &str) -> String {
+        match self.language {
+            Language::English => format!("Last played: {relative}"),
+            Language::Ukrainian => format!("Останній запуск: {relative}"),
+            Language::Spanish => format!("Última partida: {relative}"),
+            Language::French => format!("Dernière partie : {relative}"),
+            Language::German => format!("Zuletzt gespielt: {relative}"),
+            Language::Portuguese => format!("Última partida: {relative}"),
+            Language::Chinese => format!("上次游玩：{relative}"),
+            Language::Hindi => format!("अंतिम बार खेला: {relative}"),
+            Language::Russian => format!("Последний запуск: {relative}"),
+            Language::Turkish => format!("Son oynama: {relative}"),
+            Language::Italian => format!("Ultima partita: {relative}"),
+            Language::Japanese => format!("最終プレイ: {relative}"),
+            Language::Korean => format!("마지막 플레이: {relative}"),
+            Language::Arabic => format!("آخر لعبة: {relative}"),
+            Language::Polish => format!("Ostatnio grano: {relative}"),
+            Language::Vietnamese => format!("Chơi lần cuối: {relative}"),
+        }
+    }
+
+    pub fn total_play_time(self, formatted: &str) -> String {
+        match self.language {
+            Language::English => format!("Total play time: {formatted}"),
+            Language::Ukrainian => format!("Загальний час гри: {formatted}"),
+            Language::Spanish => format!("Tiempo total de juego: {formatted}"),
+            Language::French => format!("Temps de jeu total : {formatted}"),
+            Language::German => format!("Gesamtspielzeit: {formatted}"),
+            Language::Portuguese => format!("Tempo total de jogo: {formatted}"),
+            Language::Chinese => format!("总游玩时间：{formatted}"),
+            Language::Hindi => format!("कुल खेल समय: {formatted}"),
+            Language::Russian => format!("Общее время игры: {formatted}"),
+            Language::Turkish => format!("Toplam oynama süresi: {formatted}"),
+            Language::Italian => format!("Tempo di gioco totale: {formatted}"),
+            Language::Japanese => format!("合計プレイ時間: {formatted}"),
+            Language::Korean => format!("총 플레이 시간: {formatted}"),
+            Language::Arabic => format!("إجمالي وقت اللعب: {formatted}"),
+            Language::Polish => format!("Łączny czas gry: {formatted}"),
+            Language::Vietnamese => format!("Tổng thời gian chơi: {formatted}"),
+        }
+    }
+
+    pub fn last_played_empty(self) -> &'static str {
+        self.pick(
+            "Never played yet",
+            "Ще не запускали гру",
+            "Aún no has jugado",
+            "Jamais joué pour l'instant",
+            "Noch nie gespielt",
+            "Ainda não jogou",
+            "尚未游玩",
+            "अभी तक नहीं खेला",
+            "Ещё не играли",
+            "Henüz oynanmadı",
+            "Non ancora giocato",
+            "まだプレイしていません",
+            "아직 플레이하지 않음",
+            "لم يتم اللعب بعد",
+            "Jeszcze nigdy nie grano",
+            "Chưa từng chơi",
         )
     }
 
@@ -923,6 +2255,11 @@ impl I18n {
             "Имя игрока",
             "Oyuncu adı",
             "Nome giocatore",
+            "プレイヤー名",
+            "플레이어 이름",
+            "اسم اللاعب",
+            "Nazwa gracza",
+            "Tên người chơi",
         )
     }
 
@@ -939,6 +2276,11 @@ impl I18n {
             "Игрок",
             "Oyuncu",
             "Giocatore",
+            "プレイヤー",
+            "플레이어",
+            "لاعب",
+            "Gracz",
+            "Người chơi",
         )
     }
 
@@ -955,6 +2297,53 @@ impl I18n {
             "Сохранить",
             "Kaydet",
             "Salva",
+            "保存",
+            "저장",
+            "حفظ",
+            "Zapisz",
+            "Lưu",
+        )
+    }
+
+    pub fn player_name_too_long(self, max_len: usize) -> String {
+        match self.language {
+            Language::English => format!("Name must be {max_len} characters or fewer."),
+            Language::Ukrainian => format!("Ім'я має бути не довше {max_len} символів."),
+            Language::Spanish => format!("El nombre debe tener como máximo {max_len} caracteres."),
+            Language::French => format!("Le nom doit contenir au plus {max_len} caractères."),
+            Language::German => format!("Der Name darf höchstens {max_len} Zeichen lang sein."),
+            Language::Portuguese => format!("O nome deve ter no máximo {max_len} caracteres."),
+            Language::Chinese => format!("名称不能超过 {max_len} 个字符。"),
+            Language::Hindi => format!("नाम {max_len} वर्णों या उससे कम का होना चाहिए।"),
+            Language::Russian => format!("Имя должно быть не длиннее {max_len} символов."),
+            Language::Turkish => format!("Ad en fazla {max_len} karakter olabilir."),
+            Language::Italian => format!("Il nome deve contenere al massimo {max_len} caratteri."),
+            Language::Japanese => format!("名前は{max_len}文字以内にしてください。"),
+            Language::Korean => format!("이름은 {max_len}자 이하여야 합니다."),
+            Language::Arabic => format!("يجب أن يتكون الاسم من {max_len} حرفًا أو أقل."),
+            Language::Polish => format!("Nazwa musi mieć maksymalnie {max_len} znaków."),
+            Language::Vietnamese => format!("Tên phải có tối đa {max_len} ký tự."),
+        }
+    }
+
+    pub fn player_name_invalid_chars(self) -> &'static str {
+        self.pick(
+            "Name can only contain letters, numbers, underscores, and hyphens.",
+            "Ім'я може містити лише літери, цифри, підкреслення та дефіси.",
+            "El nombre solo puede contener letras, números, guiones bajos y guiones.",
+            "Le nom ne peut contenir que des lettres, chiffres, tirets bas et tirets.",
+            "Der Name darf nur Buchstaben, Zahlen, Unterstriche und Bindestriche enthalten.",
+            "O nome só pode conter letras, números, sublinhados e hífens.",
+            "名称只能包含字母、数字、下划线和连字符。",
+            "नाम में केवल अक्षर, संख्याएं, अंडरस्कोर और हाइफ़न हो सकते हैं।",
+            "Имя может содержать только буквы, цифры, подчёркивания и дефисы.",
+            "Ad yalnızca harf, sayı, alt çizgi ve tire içerebilir.",
+            "Il nome può contenere solo lettere, numeri, underscore e trattini.",
+            "名前に使用できるのは英数字、アンダースコア、ハイフンのみです。",
+            "이름에는 문자, 숫자, 밑줄, 하이픈만 사용할 수 있습니다.",
+            "يمكن أن يحتوي الاسم فقط على أحرف وأرقام وشرطات سفلية وشرطات.",
+            "Nazwa może zawierać tylko litery, cyfry, podkreślenia i myślniki.",
+            "Tên chỉ được chứa chữ cái, số, dấu gạch dưới và dấu gạch ngang.",
         )
     }
 
@@ -971,9 +2360,56 @@ impl I18n {
             Language::Russian => format!("Имя игрока: {err}"),
             Language::Turkish => format!("Oyuncu adı: {err}"),
             Language::Italian => format!("Nome giocatore: {err}"),
+            Language::Japanese => format!("プレイヤー名: {err}"),
+            Language::Korean => format!("플레이어 이름: {err}"),
+            Language::Arabic => format!("اسم اللاعب: {err}"),
+            Language::Polish => format!("Nazwa gracza: {err}"),
+            Language::Vietnamese => format!("Tên người chơi: {err}"),
         }
     }
 
+    pub fn profile_label(self) -> &'static str {
+        self.pick(
+            "Profile",
+            "Профіль",
+            "Perfil",
+            "Profil",
+            "Profil",
+            "Perfil",
+            "档案",
+            "प्रोफ़ाइल",
+            "Профиль",
+            "Profil",
+            "Profilo",
+            "プロファイル",
+            "프로필",
+            "الملف الشخصي",
+            "Profil",
+            "Hồ sơ",
+        )
+    }
+
+    pub fn new_profile_button(self) -> &'static str {
+        self.pick(
+            "+ New profile",
+            "+ Новий профіль",
+            "+ Nuevo perfil",
+            "+ Nouveau profil",
+            "+ Neues Profil",
+            "+ Novo perfil",
+            "+ 新建档案",
+            "+ नई प्रोफ़ाइल",
+            "+ Новый профиль",
+            "+ Yeni profil",
+            "+ Nuovo profilo",
+            "+ 新しいプロファイル",
+            "+ 새 프로필",
+            "+ ملف شخصي جديد",
+            "+ Nowy profil",
+            "+ Hồ sơ mới",
+        )
+    }
+
     pub fn auth_mode_label(self) -> &'static str {
         self.pick(
             "Auth mode",
@@ -987,6 +2423,11 @@ impl I18n {
             "Режим аутентификации",
             "Kimlik doğrulama modu",
             "Modalità autenticazione",
+            "認証モード",
+            "인증 모드",
+            "وضع المصادقة",
+            "Tryb uwierzytelniania",
+            "Chế độ xác thực",
         )
     }
 
@@ -1003,6 +2444,11 @@ impl I18n {
             (AuthMode::Offline, Language::Russian) => "Офлайн",
             (AuthMode::Offline, Language::Turkish) => "Çevrimdışı",
             (AuthMode::Offline, Language::Italian) => "Offline",
+            (AuthMode::Offline, Language::Japanese) => "オフライン",
+            (AuthMode::Offline, Language::Korean) => "오프라인",
+            (AuthMode::Offline, Language::Arabic) => "غير متصل",
+            (AuthMode::Offline, Language::Polish) => "Offline",
+            (AuthMode::Offline, Language::Vietnamese) => "Ngoại tuyến",
             (AuthMode::Online, Language::English) => "Online",
             (AuthMode::Online, Language::Ukrainian) => "Онлайн",
             (AuthMode::Online, Language::Spanish) => "En línea",
@@ -1014,73 +2460,542 @@ impl I18n {
             (AuthMode::Online, Language::Russian) => "Онлайн",
             (AuthMode::Online, Language::Turkish) => "Çevrimiçi",
             (AuthMode::Online, Language::Italian) => "Online",
+            (AuthMode::Online, Language::Japanese) => "オンライン",
+            (AuthMode::Online, Language::Korean) => "온라인",
+            (AuthMode::Online, Language::Arabic) => "متصل",
+            (AuthMode::Online, Language::Polish) => "Online",
+            (AuthMode::Online, Language::Vietnamese) => "Trực tuyến",
         }
     }
 
-    pub fn version_label(self) -> &'static str {
+    pub fn auth_mode_offline_hint(self) -> &'static str {
         self.pick(
-            "Game version",
-            "Версія гри",
-            "Versión del juego",
-            "Version du jeu",
-            "Spielversion",
-            "Versão do jogo",
-            "游戏版本",
-            "गेम संस्करण",
-            "Версия игры",
-            "Oyun sürümü",
-            "Versione gioco",
+            "Play without signing in. No Hytale account is needed.",
+            "Грати без входу в систему. Облікового запису Hytale не потрібно.",
+            "Juega sin iniciar sesión. No se necesita una cuenta de Hytale.",
+            "Jouez sans vous connecter. Aucun compte Hytale n'est requis.",
+            "Spiele ohne Anmeldung. Es wird kein Hytale-Konto benötigt.",
+            "Jogue sem entrar na conta. Nenhuma conta Hytale é necessária.",
+            "无需登录即可游玩。不需要 Hytale 账户。",
+            "साइन इन किए बिना खेलें। किसी Hytale खाते की आवश्यकता नहीं है।",
+            "Играйте без входа в систему. Учетная запись Hytale не требуется.",
+            "Oturum açmadan oyna. Hytale hesabına gerek yoktur.",
+            "Gioca senza accedere. Non è necessario un account Hytale.",
+            "サインインせずにプレイ。Hytaleアカウントは不要です。",
+            "로그인 없이 플레이합니다. Hytale 계정이 필요하지 않습니다.",
+            "العب بدون تسجيل الدخول. لا حاجة لحساب Hytale.",
+            "Graj bez logowania. Konto Hytale nie jest wymagane.",
+            "Chơi mà không cần đăng nhập. Không cần tài khoản Hytale.",
         )
     }
 
-    pub fn version_latest(self, latest: Option<u32>) -> String {
-        match (latest, self.language) {
-            (Some(v), Language::English) => format!("Latest (v{v})"),
-            (Some(v), Language::Ukrainian) => format!("Остання (v{v})"),
-            (Some(v), Language::Spanish) => format!("Última (v{v})"),
-            (Some(v), Language::French) => format!("Dernière (v{v})"),
-            (Some(v), Language::German) => format!("Neueste (v{v})"),
-            (Some(v), Language::Portuguese) => format!("Mais recente (v{v})"),
-            (Some(v), Language::Chinese) => format!("最新 (v{v})"),
-            (Some(v), Language::Hindi) => format!("नवीनतम (v{v})"),
-            (Some(v), Language::Russian) => format!("Последняя (v{v})"),
-            (Some(v), Language::Turkish) => format!("En son (v{v})"),
-            (Some(v), Language::Italian) => format!("Ultima (v{v})"),
-            (None, Language::English) => "Latest".into(),
-            (None, Language::Ukrainian) => "Остання".into(),
-            (None, Language::Spanish) => "Última".into(),
-            (None, Language::French) => "Dernière".into(),
-            (None, Language::German) => "Neueste".into(),
-            (None, Language::Portuguese) => "Mais recente".into(),
-            (None, Language::Chinese) => "最新".into(),
-            (None, Language::Hindi) => "नवीनतम".into(),
-            (None, Language::Russian) => "Последняя".into(),
-            (None, Language::Turkish) => "En son".into(),
-            (None, Language::Italian) => "Ultima".into(),
-        }
+    pub fn auth_mode_online_hint(self) -> &'static str {
+        self.pick(
+            "Sign in with your Hytale account to play online.",
+            "Увійдіть до облікового запису Hytale, щоб грати онлайн.",
+            "Inicia sesión con tu cuenta de Hytale para jugar en línea.",
+            "Connectez-vous avec votre compte Hytale pour jouer en ligne.",
+            "Melde dich mit deinem Hytale-Konto an, um online zu spielen.",
+            "Entre com sua conta Hytale para jogar online.",
+            "使用您的 Hytale 账户登录以进行联机游戏。",
+            "ऑनलाइन खेलने के लिए अपने Hytale खाते से साइन इन करें।",
+            "Войдите в учетную запись Hytale, чтобы играть онлайн.",
+            "Çevrimiçi oynamak için Hytale hesabınla oturum aç.",
+            "Accedi con il tuo account Hytale per giocare online.",
+            "オンラインでプレイするにはHytaleアカウントでサインインしてください。",
+            "온라인으로 플레이하려면 Hytale 계정으로 로그인하세요.",
+            "سجّل الدخول بحساب Hytale للعب عبر الإنترنت.",
+            "Zaloguj się na konto Hytale, aby grać online.",
+            "Đăng nhập bằng tài khoản Hytale để chơi trực tuyến.",
+        )
     }
 
-    pub fn version_value(self, version: u32) -> String {
-        format!("v{version}")
+    pub fn auth_mode_hint(self, mode: AuthMode) -> &'static str {
+        match mode {
+            AuthMode::Offline => self.auth_mode_offline_hint(),
+            AuthMode::Online => self.auth_mode_online_hint(),
+        }
     }
 
-    pub fn version_refresh_button(self) -> &'static str {
+    pub fn extra_launch_args_label(self) -> &'static str {
         self.pick(
-            "Refresh list",
-            "Оновити список",
-            "Actualizar lista",
-            "Rafraîchir la liste",
-            "Liste aktualisieren",
-            "Atualizar lista",
-            "刷新列表",
-            "सूची रिफ्रेश करें",
-            "Обновить список",
-            "Listeyi yenile",
-            "Aggiorna lista",
+            "Extra launch arguments",
+            "Додаткові аргументи запуску",
+            "Argumentos de lanzamiento adicionales",
+            "Arguments de lancement supplémentaires",
+            "Zusätzliche Startargumente",
+            "Argumentos de inicialização extras",
+            "额外的启动参数",
+            "अतिरिक्त लॉन्च आर्गुमेंट",
+            "Дополнительные аргументы запуска",
+            "Ek başlatma bağımsız değişkenleri",
+            "Argomenti di avvio aggiuntivi",
+            "追加の起動引数",
+            "추가 실행 인자",
+            "وسائط تشغيل إضافية",
+            "Dodatkowe argumenty uruchamiania",
+            "Tham số khởi chạy bổ sung",
         )
     }
 
-    pub fn version_custom_label(self) -> &'static str {
+    pub fn extra_launch_args_placeholder(self) -> &'static str {
+        self.pick(
+            "e.g. --fullscreen \"--custom flag\"",
+            "напр. --fullscreen \"--custom flag\"",
+            "ej. --fullscreen \"--custom flag\"",
+            "ex. --fullscreen \"--custom flag\"",
+            "z. B. --fullscreen \"--custom flag\"",
+            "ex. --fullscreen \"--custom flag\"",
+            "例如 --fullscreen \"--custom flag\"",
+            "उदा. --fullscreen \"--custom flag\"",
+            "напр. --fullscreen \"--custom flag\"",
+            "örn. --fullscreen \"--custom flag\"",
+            "es. --fullscreen \"--custom flag\"",
+            "例: --fullscreen \"--custom flag\"",
+            "예: --fullscreen \"--custom flag\"",
+            "مثال: --fullscreen \"--custom flag\"",
+            "np. --fullscreen \"--custom flag\"",
+            "vd. --fullscreen \"--custom flag\"",
+        )
+    }
+
+    pub fn extra_launch_args_error(self, err: &str) -> String {
+        match self.language {
+            Language::English => format!("Extra launch arguments: {err}"),
+            Language::Ukrainian => format!("Додаткові аргументи запуску: {err}"),
+            Language::Spanish => format!("Argumentos de lanzamiento adicionales: {err}"),
+            Language::French => format!("Arguments de lancement supplémentaires : {err}"),
+            Language::German => format!("Zusätzliche Startargumente: {err}"),
+            Language::Portuguese => format!("Argumentos de inicialização extras: {err}"),
+            Language::Chinese => format!("额外的启动参数: {err}"),
+            Language::Hindi => format!("अतिरिक्त लॉन्च आर्गुमेंट: {err}"),
+            Language::Russian => format!("Дополнительные аргументы запуска: {err}"),
+            Language::Turkish => format!("Ek başlatma bağımsız değişkenleri: {err}"),
+            Language::Italian => format!("Argomenti di avvio aggiuntivi: {err}"),
+            Language::Japanese => format!("追加の起動引数: {err}"),
+            Language::Korean => format!("추가 실행 인자: {err}"),
+            Language::Arabic => format!("وسائط تشغيل إضافية: {err}"),
+            Language::Polish => format!("Dodatkowe argumenty uruchamiania: {err}"),
+            Language::Vietnamese => format!("Tham số khởi chạy bổ sung: {err}"),
+        }
+    }
+
+    pub fn extra_launch_args_preview(self, args: &[String]) -> String {
+        if args.is_empty() {
+            return self.extra_launch_args_preview_empty().to_owned();
+        }
+        let joined = args
+            .iter()
+            .map(|arg| format!("[{arg}]"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        match self.language {
+            Language::English => format!("Final arguments: {joined}"),
+            Language::Ukrainian => format!("Підсумкові аргументи: {joined}"),
+            Language::Spanish => format!("Argumentos finales: {joined}"),
+            Language::French => format!("Arguments finaux : {joined}"),
+            Language::German => format!("Endgültige Argumente: {joined}"),
+            Language::Portuguese => format!("Argumentos finais: {joined}"),
+            Language::Chinese => format!("最终参数: {joined}"),
+            Language::Hindi => format!("अंतिम आर्गुमेंट: {joined}"),
+            Language::Russian => format!("Итоговые аргументы: {joined}"),
+            Language::Turkish => format!("Son bağımsız değişkenler: {joined}"),
+            Language::Italian => format!("Argomenti finali: {joined}"),
+            Language::Japanese => format!("最終的な引数: {joined}"),
+            Language::Korean => format!("최종 인자: {joined}"),
+            Language::Arabic => format!("الوسائط النهائية: {joined}"),
+            Language::Polish => format!("Ostateczne argumenty: {joined}"),
+            Language::Vietnamese => format!("Tham số cuối cùng: {joined}"),
+        }
+    }
+
+    fn extra_launch_args_preview_empty(self) -> &'static str {
+        self.pick(
+            "Final arguments: (none)",
+            "Підсумкові аргументи: (немає)",
+            "Argumentos finales: (ninguno)",
+            "Arguments finaux : (aucun)",
+            "Endgültige Argumente: (keine)",
+            "Argumentos finais: (nenhum)",
+            "最终参数：（无）",
+            "अंतिम आर्गुमेंट: (कोई नहीं)",
+            "Итоговые аргументы: (нет)",
+            "Son bağımsız değişkenler: (yok)",
+            "Argomenti finali: (nessuno)",
+            "最終的な引数: (なし)",
+            "최종 인자: (없음)",
+            "الوسائط النهائية: (لا يوجد)",
+            "Ostateczne argumenty: (brak)",
+            "Tham số cuối cùng: (không có)",
+        )
+    }
+
+    pub fn memory_settings_label(self) -> &'static str {
+        self.pick(
+            "Memory (GB)",
+            "Пам'ять (ГБ)",
+            "Memoria (GB)",
+            "Mémoire (Go)",
+            "Arbeitsspeicher (GB)",
+            "Memória (GB)",
+            "内存 (GB)",
+            "मेमोरी (GB)",
+            "Память (ГБ)",
+            "Bellek (GB)",
+            "Memoria (GB)",
+            "メモリ (GB)",
+            "메모리 (GB)",
+            "الذاكرة (GB)",
+            "Pamięć (GB)",
+            "Bộ nhớ (GB)",
+        )
+    }
+
+    pub fn min_memory_placeholder(self) -> &'static str {
+        self.pick(
+            "Min",
+            "Мін",
+            "Mín",
+            "Min",
+            "Min",
+            "Mín",
+            "最小",
+            "न्यूनतम",
+            "Мин",
+            "Min",
+            "Min",
+            "最小",
+            "최소",
+            "الحد الأدنى",
+            "Min.",
+            "Tối thiểu",
+        )
+    }
+
+    pub fn max_memory_placeholder(self) -> &'static str {
+        self.pick(
+            "Max",
+            "Макс",
+            "Máx",
+            "Max",
+            "Max",
+            "Máx",
+            "最大",
+            "अधिकतम",
+            "Макс",
+            "Maks",
+            "Max",
+            "最大",
+            "최대",
+            "الحد الأقصى",
+            "Maks.",
+            "Tối đa",
+        )
+    }
+
+    pub fn memory_settings_apply_button(self) -> &'static str {
+        self.pick(
+            "Apply",
+            "Застосувати",
+            "Aplicar",
+            "Appliquer",
+            "Anwenden",
+            "Aplicar",
+            "应用",
+            "लागू करें",
+            "Применить",
+            "Uygula",
+            "Applica",
+            "適用",
+            "적용",
+            "تطبيق",
+            "Zastosuj",
+            "Áp dụng",
+        )
+    }
+
+    pub fn memory_settings_error_not_positive(self) -> &'static str {
+        self.pick(
+            "Memory values must be greater than zero.",
+            "Значення пам'яті мають бути більшими за нуль.",
+            "Los valores de memoria deben ser mayores que cero.",
+            "Les valeurs de mémoire doivent être supérieures à zéro.",
+            "Speicherwerte müssen größer als null sein.",
+            "Os valores de memória devem ser maiores que zero.",
+            "内存值必须大于零。",
+            "मेमोरी मान शून्य से अधिक होना चाहिए।",
+            "Значения памяти должны быть больше нуля.",
+            "Bellek değerleri sıfırdan büyük olmalıdır.",
+            "I valori di memoria devono essere maggiori di zero.",
+            "メモリの値は0より大きくしてください。",
+            "메모리 값은 0보다 커야 합니다.",
+            "يجب أن تكون قيم الذاكرة أكبر من الصفر.",
+            "Wartości pamięci muszą być większe od zera.",
+            "Giá trị bộ nhớ phải lớn hơn 0.",
+        )
+    }
+
+    pub fn memory_settings_error_min_exceeds_max(self) -> &'static str {
+        self.pick(
+            "Min memory cannot exceed max memory.",
+            "Мінімальна пам'ять не може перевищувати максимальну.",
+            "La memoria mínima no puede superar la máxima.",
+            "La mémoire minimale ne peut pas dépasser la maximale.",
+            "Der Mindestspeicher darf den Höchstspeicher nicht überschreiten.",
+            "A memória mínima não pode exceder a máxima.",
+            "最小内存不能超过最大内存。",
+            "न्यूनतम मेमोरी अधिकतम मेमोरी से अधिक नहीं हो सकती।",
+            "Минимальная память не может превышать максимальную.",
+            "Minimum bellek, maksimum belleği aşamaz.",
+            "La memoria minima non può superare quella massima.",
+            "最小メモリは最大メモリを超えられません。",
+            "최소 메모리는 최대 메모리를 초과할 수 없습니다.",
+            "لا يمكن أن تتجاوز الذاكرة الدنيا الذاكرة القصوى.",
+            "Pamięć minimalna nie może przekraczać pamięci maksymalnej.",
+            "Bộ nhớ tối thiểu không được vượt quá bộ nhớ tối đa.",
+        )
+    }
+
+    pub fn memory_settings_error_exceeds_system(self, system_gb: u32) -> String {
+        match self.language {
+            Language::English => format!("Value exceeds system memory ({system_gb} GB)."),
+            Language::Ukrainian => format!("Значення перевищує обсяг пам'яті системи ({system_gb} ГБ)."),
+            Language::Spanish => format!("El valor supera la memoria del sistema ({system_gb} GB)."),
+            Language::French => format!("La valeur dépasse la mémoire du système ({system_gb} Go)."),
+            Language::German => format!("Der Wert übersteigt den Systemspeicher ({system_gb} GB)."),
+            Language::Portuguese => format!("O valor excede a memória do sistema ({system_gb} GB)."),
+            Language::Chinese => format!("该值超过系统内存（{system_gb} GB）。"),
+            Language::Hindi => format!("मान सिस्टम मेमोरी ({system_gb} GB) से अधिक है।"),
+            Language::Russian => format!("Значение превышает объём памяти системы ({system_gb} ГБ)."),
+            Language::Turkish => format!("Değer sistem belleğini aşıyor ({system_gb} GB)."),
+            Language::Italian => format!("Il valore supera la memoria di sistema ({system_gb} GB)."),
+            Language::Japanese => format!("値がシステムメモリ ({system_gb} GB) を超えています。"),
+            Language::Korean => format!("값이 시스템 메모리({system_gb}GB)를 초과합니다."),
+            Language::Arabic => format!("القيمة تتجاوز ذاكرة النظام ({system_gb} GB)."),
+            Language::Polish => format!("Wartość przekracza pamięć systemową ({system_gb} GB)."),
+            Language::Vietnamese => format!("Giá trị vượt quá bộ nhớ hệ thống ({system_gb} GB)."),
+        }
+    }
+
+    pub fn gc_label(self) -> &'static str {
+        self.pick(
+            "Garbage collector",
+            "Збирач сміття",
+            "Recolector de basura",
+            "Ramasse-miettes",
+            "Garbage Collector",
+            "Coletor de lixo",
+            "垃圾回收器",
+            "गार्बेज कलेक्टर",
+            "Сборщик мусора",
+            "Çöp toplayıcı",
+            "Garbage collector",
+            "ガベージコレクタ",
+            "가비지 컬렉터",
+            "جامع القمامة",
+            "Garbage collector",
+            "Bộ thu gom rác",
+        )
+    }
+
+    pub fn gc_value(self, gc: GarbageCollector) -> &'static str {
+        match gc {
+            GarbageCollector::Default => self.gc_value_default(),
+            GarbageCollector::G1 => "G1",
+            GarbageCollector::Zgc => "ZGC",
+            GarbageCollector::Shenandoah => "Shenandoah",
+        }
+    }
+
+    fn gc_value_default(self) -> &'static str {
+        self.pick(
+            "Default",
+            "За замовчуванням",
+            "Predeterminado",
+            "Par défaut",
+            "Standard",
+            "Padrão",
+            "默认",
+            "डिफ़ॉल्ट",
+            "По умолчанию",
+            "Varsayılan",
+            "Predefinito",
+            "デフォルト",
+            "기본값",
+            "الافتراضي",
+            "Domyślny",
+            "Mặc định",
+        )
+    }
+
+    pub fn gc_newer_java_note(self) -> &'static str {
+        self.pick(
+            "ZGC and Shenandoah require a newer JDK than the bundled runtime may provide.",
+            "ZGC і Shenandoah потребують новішого JDK, ніж може надати вбудоване середовище.",
+            "ZGC y Shenandoah requieren un JDK más reciente del que podría incluir el entorno empaquetado.",
+            "ZGC et Shenandoah nécessitent un JDK plus récent que celui fourni avec le runtime intégré.",
+            "ZGC und Shenandoah erfordern ein neueres JDK, als die gebündelte Laufzeit möglicherweise bietet.",
+            "ZGC e Shenandoah requerem um JDK mais recente do que o runtime incluído pode fornecer.",
+            "ZGC 和 Shenandoah 需要比内置运行时更新的 JDK。",
+            "ZGC और Shenandoah को बंडल किए गए रनटाइम से नए JDK की आवश्यकता हो सकती है।",
+            "ZGC и Shenandoah требуют более новый JDK, чем может предоставить встроенная среда выполнения.",
+            "ZGC ve Shenandoah, birlikte gelen çalışma zamanından daha yeni bir JDK gerektirebilir.",
+            "ZGC e Shenandoah richiedono un JDK più recente di quello fornito dal runtime integrato.",
+            "ZGCとShenandoahは、バンドルされているランタイムより新しいJDKを必要とする場合があります。",
+            "ZGC와 Shenandoah는 번들된 런타임보다 더 새로운 JDK가 필요할 수 있습니다.",
+            "يتطلب ZGC وShenandoah إصدار JDK أحدث مما قد توفره بيئة التشغيل المضمنة.",
+            "ZGC i Shenandoah wymagają nowszego JDK niż może zapewnić dołączone środowisko uruchomieniowe.",
+            "ZGC và Shenandoah yêu cầu JDK mới hơn so với runtime đi kèm có thể cung cấp.",
+        )
+    }
+
+    pub fn version_label(self) -> &'static str {
+        self.pick(
+            "Game version",
+            "Версія гри",
+            "Versión del juego",
+            "Version du jeu",
+            "Spielversion",
+            "Versão do jogo",
+            "游戏版本",
+            "गेम संस्करण",
+            "Версия игры",
+            "Oyun sürümü",
+            "Versione gioco",
+            "ゲームバージョン",
+            "게임 버전",
+            "إصدار اللعبة",
+            "Wersja gry",
+            "Phiên bản trò chơi",
+        )
+    }
+
+    pub fn installed_version_label(self, version: u32) -> String {
+        match self.language {
+            Language::English => format!("Installed: v{version}"),
+            Language::Ukrainian => format!("Встановлено: v{version}"),
+            Language::Spanish => format!("Instalado: v{version}"),
+            Language::French => format!("Installée : v{version}"),
+            Language::German => format!("Installiert: v{version}"),
+            Language::Portuguese => format!("Instalado: v{version}"),
+            Language::Chinese => format!("已安装：v{version}"),
+            Language::Hindi => format!("इंस्टॉल्ड: v{version}"),
+            Language::Russian => format!("Установлено: v{version}"),
+            Language::Turkish => format!("Yüklü: v{version}"),
+            Language::Italian => format!("Installata: v{version}"),
+            Language::Japanese => format!("インストール済み: v{version}"),
+            Language::Korean => format!("설치됨: v{version}"),
+            Language::Arabic => format!("مثبت: v{version}"),
+            Language::Polish => format!("Zainstalowano: v{version}"),
+            Language::Vietnamese => format!("Đã cài đặt: v{version}"),
+        }
+    }
+
+    pub fn installed_version_pending_change(self) -> &'static str {
+        self.pick(
+            "(selection differs - will change on next download)",
+            "(вибір відрізняється - зміниться після наступного завантаження)",
+            "(la selección es distinta - cambiará en la próxima descarga)",
+            "(la sélection diffère - changera au prochain téléchargement)",
+            "(Auswahl weicht ab - ändert sich beim nächsten Download)",
+            "(a seleção é diferente - mudará no próximo download)",
+            "（所选版本不同，将在下次下载时更改）",
+            "(चुनाव अलग है - अगली डाउनलोड पर बदल जाएगा)",
+            "(выбор отличается - изменится при следующей загрузке)",
+            "(seçim farklı - sonraki indirmede değişecek)",
+            "(la selezione è diversa - cambierà al prossimo download)",
+            "（選択が異なります - 次回のダウンロードで変更されます）",
+            "(선택한 버전이 다릅니다 - 다음 다운로드 시 변경됩니다)",
+            "(الاختيار مختلف - سيتغير عند التنزيل التالي)",
+            "(wybór różni się - zmieni się przy następnym pobraniu)",
+            "(lựa chọn khác - sẽ thay đổi ở lần tải tiếp theo)",
+        )
+    }
+
+    pub fn installed_version_none(self) -> &'static str {
+        self.pick(
+            "Installed: none yet",
+            "Встановлено: ще немає",
+            "Instalado: todavía ninguno",
+            "Installée : aucune pour le moment",
+            "Installiert: noch keine",
+            "Instalado: nenhum ainda",
+            "已安装：暂无",
+            "इंस्टॉल्ड: अभी तक कोई नहीं",
+            "Установлено: пока нет",
+            "Yüklü: henüz yok",
+            "Installata: nessuna ancora",
+            "インストール済み: まだありません",
+            "설치됨: 아직 없음",
+            "مثبت: لا يوجد حتى الآن",
+            "Zainstalowano: jeszcze żadnego",
+            "Đã cài đặt: chưa có",
+        )
+    }
+
+    pub fn version_latest(self, latest: Option<u32>) -> String {
+        match (latest, self.language) {
+            (Some(v), Language::English) => format!("Latest (v{v})"),
+            (Some(v), Language::Ukrainian) => format!("Остання (v{v})"),
+            (Some(v), Language::Spanish) => format!("Última (v{v})"),
+            (Some(v), Language::French) => format!("Dernière (v{v})"),
+            (Some(v), Language::German) => format!("Neueste (v{v})"),
+            (Some(v), Language::Portuguese) => format!("Mais recente (v{v})"),
+            (Some(v), Language::Chinese) => format!("最新 (v{v})"),
+            (Some(v), Language::Hindi) => format!("नवीनतम (v{v})"),
+            (Some(v), Language::Russian) => format!("Последняя (v{v})"),
+            (Some(v), Language::Turkish) => format!("En son (v{v})"),
+            (Some(v), Language::Italian) => format!("Ultima (v{v})"),
+            (Some(v), Language::Japanese) => format!("最新 (v{v})"),
+            (Some(v), Language::Korean) => format!("최신 (v{v})"),
+            (Some(v), Language::Arabic) => format!("الأحدث (v{v})"),
+            (Some(v), Language::Polish) => format!("Najnowsza (v{v})"),
+            (Some(v), Language::Vietnamese) => format!("Mới nhất (v{v})"),
+            (None, Language::English) => "Latest".into(),
+            (None, Language::Ukrainian) => "Остання".into(),
+            (None, Language::Spanish) => "Última".into(),
+            (None, Language::French) => "Dernière".into(),
+            (None, Language::German) => "Neueste".into(),
+            (None, Language::Portuguese) => "Mais recente".into(),
+            (None, Language::Chinese) => "最新".into(),
+            (None, Language::Hindi) => "नवीनतम".into(),
+            (None, Language::Russian) => "Последняя".into(),
+            (None, Language::Turkish) => "En son".into(),
+            (None, Language::Italian) => "Ultima".into(),
+            (None, Language::Japanese) => "最新".into(),
+            (None, Language::Korean) => "최신".into(),
+            (None, Language::Arabic) => "الأحدث".into(),
+            (None, Language::Polish) => "Najnowsza".into(),
+            (None, Language::Vietnamese) => "Mới nhất".into(),
+        }
+    }
+
+    pub fn version_value(self, version: u32) -> String {
+        format!("v{version}")
+    }
+
+    pub fn version_refresh_button(self) -> &'static str {
+        self.pick(
+            "Refresh list",
+            "Оновити список",
+            "Actualizar lista",
+            "Rafraîchir la liste",
+            "Liste aktualisieren",
+            "Atualizar lista",
+            "刷新列表",
+            "सूची रिफ्रेश करें",
+            "Обновить список",
+            "Listeyi yenile",
+            "Aggiorna lista",
+            "リストを更新",
+            "목록 새로고침",
+            "تحديث القائمة",
+            "Odśwież listę",
+            "Làm mới danh sách",
+        )
+    }
+
+    pub fn version_custom_label(self) -> &'static str {
         self.pick(
             "Custom version",
             "Своя версія",
@@ -1093,6 +3008,11 @@ impl I18n {
             "Пользовательская версия",
             "Özel sürüm",
             "Versione personalizzata",
+            "カスタムバージョン",
+            "사용자 지정 버전",
+            "إصدار مخصص",
+            "Niestandardowa wersja",
+            "Phiên bản tùy chỉnh",
         )
     }
 
@@ -1109,6 +3029,11 @@ impl I18n {
             "например, 3",
             "örn. 3",
             "es. 3",
+            "例: 3",
+            "예: 3",
+            "مثال: 3",
+            "np. 3",
+            "vd. 3",
         )
     }
 
@@ -1125,6 +3050,11 @@ impl I18n {
             "Установить версию",
             "Sürümü ayarla",
             "Imposta versione",
+            "バージョンを設定",
+            "버전 설정",
+            "تعيين الإصدار",
+            "Ustaw wersję",
+            "Đặt phiên bản",
         )
     }
 
@@ -1141,9 +3071,130 @@ impl I18n {
             Language::Russian => format!("Не удалось получить список версий: {err}"),
             Language::Turkish => format!("Sürüm listesi alınamadı: {err}"),
             Language::Italian => format!("Impossibile caricare la lista delle versioni: {err}"),
+            Language::Japanese => format!("バージョン一覧の取得に失敗しました: {err}"),
+            Language::Korean => format!("버전 목록을 가져오지 못했습니다: {err}"),
+            Language::Arabic => format!("فشل جلب قائمة الإصدارات: {err}"),
+            Language::Polish => format!("Nie udało się pobrać listy wersji: {err}"),
+            Language::Vietnamese => format!("Không thể lấy danh sách phiên bản: {err}"),
         }
     }
 
+    pub fn no_versions_found_message(self) -> &'static str {
+        self.pick(
+            "No versions found for your platform — check connectivity / run diagnostics",
+            "Версій для вашої платформи не знайдено — перевірте підключення / запустіть діагностику",
+            "No se encontraron versiones para tu plataforma — comprueba la conexión o ejecuta diagnósticos",
+            "Aucune version trouvée pour votre plateforme — vérifiez la connexion / lancez les diagnostics",
+            "Keine Versionen für deine Plattform gefunden — Verbindung prüfen / Diagnose ausführen",
+            "Nenhuma versão encontrada para sua plataforma — verifique a conexão / execute diagnósticos",
+            "未找到适用于您平台的版本 — 请检查网络连接或运行诊断",
+            "आपके प्लेटफ़ॉर्म के लिए कोई संस्करण नहीं मिला — कनेक्टिविटी जाँचें / डायग्नॉस्टिक्स चलाएँ",
+            "Версии для вашей платформы не найдены — проверьте подключение / запустите диагностику",
+            "Platformunuz için sürüm bulunamadı — bağlantıyı kontrol edin / tanılamayı çalıştırın",
+            "Nessuna versione trovata per la tua piattaforma — controlla la connessione / esegui la diagnostica",
+            "お使いのプラットフォーム向けのバージョンが見つかりません — 接続を確認するか診断を実行してください",
+            "사용 중인 플랫폼에 맞는 버전을 찾을 수 없습니다 — 연결 상태를 확인하거나 진단을 실행하세요",
+            "لم يتم العثور على إصدارات لمنصتك — تحقق من الاتصال / شغّل التشخيص",
+            "Nie znaleziono wersji dla Twojej platformy — sprawdź połączenie / uruchom diagnostykę",
+            "Không tìm thấy phiên bản nào cho nền tảng của bạn — kiểm tra kết nối / chạy chẩn đoán",
+        )
+    }
+
+    pub fn unsupported_platform_message(self, os: &str, arch: &str) -> String {
+        match self.language {
+            Language::English => format!(
+                "Your platform ({os}/{arch}) isn't supported by the patch server yet. Please file an issue so we can add it."
+            ),
+            Language::Ukrainian => format!(
+                "Ваша платформа ({os}/{arch}) поки не підтримується сервером оновлень. Будь ласка, створіть issue, щоб ми могли її додати."
+            ),
+            Language::Spanish => format!(
+                "Tu plataforma ({os}/{arch}) aún no es compatible con el servidor de parches. Abre un issue para que podamos añadirla."
+            ),
+            Language::French => format!(
+                "Votre plateforme ({os}/{arch}) n'est pas encore prise en charge par le serveur de correctifs. Merci d'ouvrir un ticket pour que nous puissions l'ajouter."
+            ),
+            Language::German => format!(
+                "Deine Plattform ({os}/{arch}) wird vom Patch-Server noch nicht unterstützt. Bitte erstelle ein Issue, damit wir sie hinzufügen können."
+            ),
+            Language::Portuguese => format!(
+                "Sua plataforma ({os}/{arch}) ainda não é suportada pelo servidor de patches. Abra uma issue para que possamos adicioná-la."
+            ),
+            Language::Chinese => format!(
+                "补丁服务器尚不支持您的平台（{os}/{arch}）。请提交 issue，以便我们添加支持。"
+            ),
+            Language::Hindi => format!(
+                "आपका प्लेटफ़ॉर्म ({os}/{arch}) अभी पैच सर्वर द्वारा समर्थित नहीं है। कृपया एक इश्यू दर्ज करें ताकि हम इसे जोड़ सकें।"
+            ),
+            Language::Russian => format!(
+                "Ваша платформа ({os}/{arch}) пока не поддерживается сервером обновлений. Пожалуйста, создайте issue, чтобы мы могли её добавить."
+            ),
+            Language::Turkish => format!(
+                "Platformunuz ({os}/{arch}) henüz yama sunucusu tarafından desteklenmiyor. Eklememiz için lütfen bir issue açın."
+            ),
+            Language::Italian => format!(
+                "La tua piattaforma ({os}/{arch}) non è ancora supportata dal server delle patch. Apri una issue così possiamo aggiungerla."
+            ),
+            Language::Japanese => format!(
+                "お使いのプラットフォーム（{os}/{arch}）はまだパッチサーバーでサポートされていません。追加できるよう issue を作成してください。"
+            ),
+            Language::Korean => format!(
+                "사용 중인 플랫폼({os}/{arch})은 아직 패치 서버에서 지원하지 않습니다. 추가할 수 있도록 issue를 등록해 주세요."
+            ),
+            Language::Arabic => format!(
+                "منصتك ({os}/{arch}) غير مدعومة بعد من خادم التحديثات. يرجى فتح تذكرة (issue) لنتمكن من إضافتها."
+            ),
+            Language::Polish => format!(
+                "Twoja platforma ({os}/{arch}) nie jest jeszcze obsługiwana przez serwer aktualizacji. Zgłoś issue, abyśmy mogli ją dodać."
+            ),
+            Language::Vietnamese => format!(
+                "Nền tảng của bạn ({os}/{arch}) chưa được máy chủ bản vá hỗ trợ. Vui lòng tạo issue để chúng tôi có thể bổ sung."
+            ),
+        }
+    }
+
+    pub fn file_issue_button(self) -> &'static str {
+        self.pick(
+            "File an issue",
+            "Створити issue",
+            "Crear un issue",
+            "Ouvrir un ticket",
+            "Issue erstellen",
+            "Abrir uma issue",
+            "提交 issue",
+            "इश्यू दर्ज करें",
+            "Создать issue",
+            "Issue aç",
+            "Apri una issue",
+            "issue を作成",
+            "issue 등록",
+            "فتح تذكرة",
+            "Zgłoś issue",
+            "Tạo issue",
+        )
+    }
+
+    pub fn version_unavailable_offline_hint(self) -> &'static str {
+        self.pick(
+            "Unavailable offline",
+            "Недоступно офлайн",
+            "No disponible sin conexión",
+            "Indisponible hors ligne",
+            "Offline nicht verfügbar",
+            "Indisponível offline",
+            "离线时不可用",
+            "ऑफ़लाइन उपलब्ध नहीं है",
+            "Недоступно в офлайн-режиме",
+            "Çevrimdışıyken kullanılamaz",
+            "Non disponibile offline",
+            "オフラインでは利用できません",
+            "오프라인에서는 사용할 수 없음",
+            "غير متاح بدون اتصال",
+            "Niedostępne offline",
+            "Không khả dụng khi ngoại tuyến",
+        )
+    }
+
     pub fn version_input_error(self) -> &'static str {
         self.pick(
             "Enter a valid version number.",
@@ -1157,6 +3208,137 @@ impl I18n {
             "Введите корректный номер версии.",
             "Geçerli bir sürüm numarası girin.",
             "Inserisci un numero di versione valido.",
+            "有効なバージョン番号を入力してください。",
+            "올바른 버전 번호를 입력하세요.",
+            "أدخل رقم إصدار صالحًا.",
+            "Wprowadź prawidłowy numer wersji.",
+            "Nhập số phiên bản hợp lệ.",
+        )
+    }
+
+    pub fn tray_enabled_toggle(self) -> &'static str {
+        self.pick(
+            "Show system tray icon",
+            "Показувати значок у системному лотку",
+            "Mostrar icono en la bandeja del sistema",
+            "Afficher l'icône dans la zone de notification",
+            "Symbol im Infobereich anzeigen",
+            "Mostrar ícone na bandeja do sistema",
+            "显示系统托盘图标",
+            "सिस्टम ट्रे आइकन दिखाएं",
+            "Показывать значок в системном трее",
+            "Sistem tepsisi simgesini göster",
+            "Mostra icona nella system tray",
+            "システムトレイアイコンを表示",
+            "시스템 트레이 아이콘 표시",
+            "إظهار أيقونة في شريط النظام",
+            "Pokaż ikonę w zasobniku systemowym",
+            "Hiện biểu tượng khay hệ thống",
+        )
+    }
+
+    pub fn tray_enabled_hint(self) -> &'static str {
+        self.pick(
+            "Keep a tray icon running with quick actions for Play, checking for updates, and opening the game folder.",
+            "Залишати значок у треї з швидкими діями: грати, перевірити оновлення та відкрити теку гри.",
+            "Mantiene un icono en la bandeja con accesos directos para jugar, buscar actualizaciones y abrir la carpeta del juego.",
+            "Conserve une icône dans la zone de notification avec des actions rapides pour jouer, vérifier les mises à jour et ouvrir le dossier du jeu.",
+            "Hält ein Symbol im Infobereich mit Schnellzugriffen zum Spielen, Nach Updates suchen und Spielordner öffnen.",
+            "Mantém um ícone na bandeja com ações rápidas para jogar, verificar atualizações e abrir a pasta do jogo.",
+            "在系统托盘保留图标,提供开始游戏、检查更新和打开游戏文件夹的快捷操作。",
+            "प्ले करने, अपडेट जांचने और गेम फ़ोल्डर खोलने जैसी त्वरित कार्रवाइयों के लिए ट्रे आइकन चालू रखें।",
+            "Оставлять значок в трее с быстрыми действиями: играть, проверить обновления и открыть папку игры.",
+            "Oyna, güncellemeleri denetle ve oyun klasörünü aç için hızlı eylemler içeren bir tepsi simgesini etkin tutar.",
+            "Mantiene un'icona nella system tray con azioni rapide per giocare, controllare gli aggiornamenti e aprire la cartella di gioco.",
+            "プレイ、アップデートの確認、ゲームフォルダを開くためのクイック操作を備えたトレイアイコンを常駐させます。",
+            "플레이, 업데이트 확인, 게임 폴더 열기를 위한 빠른 작업이 포함된 트레이 아이콘을 유지합니다.",
+            "إبقاء أيقونة في شريط النظام مع إجراءات سريعة للعب والتحقق من التحديثات وفتح مجلد اللعبة.",
+            "Utrzymuj ikonę w zasobniku systemowym z szybkimi akcjami do gry, sprawdzania aktualizacji i otwierania folderu gry.",
+            "Giữ biểu tượng khay hệ thống chạy với các thao tác nhanh để Chơi, kiểm tra cập nhật và mở thư mục trò chơi.",
+        )
+    }
+
+    pub fn minimize_to_tray_toggle(self) -> &'static str {
+        self.pick(
+            "Minimize to tray when playing",
+            "Згортати в трей під час гри",
+            "Minimizar a la bandeja al jugar",
+            "Réduire dans la zone de notification en jouant",
+            "Beim Spielen in den Infobereich minimieren",
+            "Minimizar para a bandeja ao jogar",
+            "游戏时最小化到托盘",
+            "खेलते समय ट्रे में छोटा करें",
+            "Сворачивать в трей во время игры",
+            "Oyun oynarken tepsiye küçült",
+            "Riduci a icona nella tray durante il gioco",
+            "プレイ中はトレイに最小化",
+            "플레이 중에는 트레이로 최소화",
+            "التصغير إلى الشريط أثناء اللعب",
+            "Minimalizuj do zasobnika podczas gry",
+            "Thu nhỏ vào khay khi đang chơi",
+        )
+    }
+
+    pub fn minimize_to_tray_hint(self) -> &'static str {
+        self.pick(
+            "Hides the launcher window while the game is starting, restoring it once the launcher is ready again.",
+            "Приховує вікно лаунчера під час запуску гри та відновлює його, коли лаунчер знову готовий.",
+            "Oculta la ventana del launcher mientras el juego se inicia y la restaura cuando el launcher vuelve a estar listo.",
+            "Masque la fenêtre du launcher pendant le démarrage du jeu et la restaure une fois que le launcher est de nouveau prêt.",
+            "Blendet das Launcher-Fenster beim Starten des Spiels aus und stellt es wieder her, sobald der Launcher wieder bereit ist.",
+            "Oculta a janela do launcher enquanto o jogo inicia, restaurando-a assim que o launcher estiver pronto novamente.",
+            "在游戏启动时隐藏启动器窗口,待启动器再次就绪后恢复显示。",
+            "गेम शुरू होने के दौरान लॉन्चर विंडो छिपाता है और लॉन्चर फिर से तैयार होने पर उसे बहाल करता है।",
+            "Скрывает окно лаунчера во время запуска игры и восстанавливает его, когда лаунчер снова готов.",
+            "Oyun başlarken başlatıcı penceresini gizler ve başlatıcı yeniden hazır olduğunda geri getirir.",
+            "Nasconde la finestra del launcher durante l'avvio del gioco e la ripristina quando il launcher è di nuovo pronto.",
+            "ゲームの起動中はランチャーウィンドウを非表示にし、ランチャーが再び準備できたら復元します。",
+            "게임이 시작되는 동안 런처 창을 숨기고 런처가 다시 준비되면 복원합니다.",
+            "يخفي نافذة المشغل أثناء بدء تشغيل اللعبة، ويستعيدها بمجرد أن يصبح المشغل جاهزًا مرة أخرى.",
+            "Ukrywa okno launchera podczas uruchamiania gry, przywracając je, gdy launcher jest ponownie gotowy.",
+            "Ẩn cửa sổ trình khởi chạy trong khi trò chơi đang khởi động, khôi phục lại khi trình khởi chạy sẵn sàng trở lại.",
+        )
+    }
+
+    pub fn ui_scale_label(self) -> &'static str {
+        self.pick(
+            "UI scale",
+            "Масштаб інтерфейсу",
+            "Escala de la interfaz",
+            "Échelle de l'interface",
+            "Skalierung der Oberfläche",
+            "Escala da interface",
+            "界面缩放",
+            "यूआई स्केल",
+            "Масштаб интерфейса",
+            "Arayüz ölçeği",
+            "Scala dell'interfaccia",
+            "UIスケール",
+            "UI 배율",
+            "حجم الواجهة",
+            "Skala interfejsu",
+            "Tỷ lệ giao diện",
+        )
+    }
+
+    pub fn ui_scale_hint(self) -> &'static str {
+        self.pick(
+            "Adjusts text and layout size for HiDPI displays or easier reading.",
+            "Регулює розмір тексту та елементів для HiDPI-дисплеїв або зручнішого читання.",
+            "Ajusta el tamaño del texto y del diseño para pantallas HiDPI o una lectura más cómoda.",
+            "Ajuste la taille du texte et de la mise en page pour les écrans HiDPI ou une lecture plus facile.",
+            "Passt die Text- und Layoutgröße für HiDPI-Displays oder leichteres Lesen an.",
+            "Ajusta o tamanho do texto e do layout para telas HiDPI ou leitura mais fácil.",
+            "调整文字和布局大小,适配 HiDPI 显示器或便于阅读。",
+            "HiDPI डिस्प्ले या आसान पठन के लिए टेक्स्ट और लेआउट का आकार समायोजित करता है।",
+            "Регулирует размер текста и макета для HiDPI-дисплеев или более удобного чтения.",
+            "HiDPI ekranlar veya daha kolay okuma için metin ve düzen boyutunu ayarlar.",
+            "Regola la dimensione del testo e del layout per display HiDPI o una lettura più agevole.",
+            "HiDPIディスプレイや読みやすさのためにテキストとレイアウトのサイズを調整します。",
+            "HiDPI 디스플레이 또는 더 쉬운 가독성을 위해 텍스트와 레이아웃 크기를 조정합니다.",
+            "يضبط حجم النص والتخطيط لشاشات HiDPI أو لتسهيل القراءة.",
+            "Dostosowuje rozmiar tekstu i układu dla wyświetlaczy HiDPI lub łatwiejszego czytania.",
+            "Điều chỉnh kích thước văn bản và bố cục cho màn hình HiDPI hoặc để đọc dễ hơn.",
         )
     }
 
@@ -1173,6 +3355,11 @@ impl I18n {
             "Запустить диагностику",
             "Tanılama çalıştır",
             "Esegui diagnostica",
+            "診断を実行",
+            "진단 실행",
+            "تشغيل التشخيص",
+            "Uruchom diagnostykę",
+            "Chạy chẩn đoán",
         )
     }
 
@@ -1189,9 +3376,161 @@ impl I18n {
             "Открыть папку игры",
             "Oyun klasörünü aç",
             "Apri cartella gioco",
+            "ゲームフォルダを開く",
+            "게임 폴더 열기",
+            "فتح مجلد اللعبة",
+            "Otwórz folder gry",
+            "Mở thư mục trò chơi",
+        )
+    }
+
+    pub fn open_mods_folder_button(self) -> &'static str {
+        self.pick(
+            "Open mods folder",
+            "Відкрити теку модів",
+            "Abrir carpeta de mods",
+            "Ouvrir le dossier des mods",
+            "Mods-Ordner öffnen",
+            "Abrir pasta de mods",
+            "打开模组文件夹",
+            "मॉड फ़ोल्डर खोलें",
+            "Открыть папку модов",
+            "Mod klasörünü aç",
+            "Apri cartella mod",
+            "MODフォルダを開く",
+            "모드 폴더 열기",
+            "فتح مجلد التعديلات",
+            "Otwórz folder modów",
+            "Mở thư mục mod",
+        )
+    }
+
+    pub fn open_crashes_folder_button(self) -> &'static str {
+        self.pick(
+            "Open crashes folder",
+            "Відкрити теку збоїв",
+            "Abrir carpeta de fallos",
+            "Ouvrir le dossier des plantages",
+            "Absturzordner öffnen",
+            "Abrir pasta de falhas",
+            "打开崩溃文件夹",
+            "क्रैश फ़ोल्डर खोलें",
+            "Открыть папку сбоев",
+            "Çökme klasörünü aç",
+            "Apri cartella crash",
+            "クラッシュフォルダを開く",
+            "충돌 폴더 열기",
+            "فتح مجلد الأعطال",
+            "Otwórz folder awarii",
+            "Mở thư mục báo lỗi",
+        )
+    }
+
+    pub fn open_cache_folder_button(self) -> &'static str {
+        self.pick(
+            "Open download cache",
+            "Відкрити кеш завантажень",
+            "Abrir caché de descargas",
+            "Ouvrir le cache de téléchargement",
+            "Download-Cache öffnen",
+            "Abrir cache de downloads",
+            "打开下载缓存",
+            "डाउनलोड कैश खोलें",
+            "Открыть кэш загрузок",
+            "İndirme önbelleğini aç",
+            "Apri cache dei download",
+            "ダウンロードキャッシュを開く",
+            "다운로드 캐시 열기",
+            "فتح ذاكرة التخزين المؤقت للتنزيلات",
+            "Otwórz pamięć podręczną pobierania",
+            "Mở bộ nhớ đệm tải xuống",
         )
     }
 
+    pub fn force_continuous_repaint_toggle(self) -> &'static str {
+        self.pick(
+            "Force continuous repaint (debug)",
+            "Примусовий безперервний перемальовок (налагодження)",
+            "Forzar repintado continuo (depuración)",
+            "Forcer le réaffichage continu (débogage)",
+            "Kontinuierliches Neuzeichnen erzwingen (Debug)",
+            "Forçar repintura contínua (depuração)",
+            "强制持续重绘(调试)",
+            "निरंतर रीपेंट बाध्य करें (डीबग)",
+            "Принудительная непрерывная перерисовка (отладка)",
+            "Sürekli yeniden boyamayı zorla (hata ayıklama)",
+            "Forza ridisegno continuo (debug)",
+            "連続再描画を強制(デバッグ)",
+            "연속 리페인트 강제 (디버그)",
+            "فرض إعادة الرسم المستمر (تصحيح الأخطاء)",
+            "Wymuś ciągłe odświeżanie (debug)",
+            "Buộc vẽ lại liên tục (gỡ lỗi)",
+        )
+    }
+
+    pub fn force_continuous_repaint_hint(self) -> &'static str {
+        self.pick(
+            "Repaint every frame even while idle, instead of backing off to a short poll. Useful when diagnosing UI responsiveness, but wastes CPU otherwise.",
+            "Перемальовувати кожен кадр навіть у режимі очікування, замість переходу на короткий опитувальний інтервал. Корисно для діагностики чутливості інтерфейсу, але інакше витрачає ресурси ЦП.",
+            "Repinta cada fotograma incluso en reposo, en vez de pasar a un sondeo corto. Útil para diagnosticar la capacidad de respuesta de la interfaz, pero desperdicia CPU en otros casos.",
+            "Réaffiche chaque image même au repos, au lieu de passer à un court intervalle d'interrogation. Utile pour diagnostiquer la réactivité de l'interface, mais gaspille du CPU sinon.",
+            "Zeichnet jeden Frame neu, auch im Leerlauf, statt auf ein kurzes Poll-Intervall umzuschalten. Nützlich zur Diagnose der UI-Reaktionsfähigkeit, verschwendet sonst CPU-Zeit.",
+            "Repinta cada quadro mesmo ocioso, em vez de recuar para um intervalo de verificação curto. Útil para diagnosticar a responsividade da interface, mas desperdiça CPU.",
+            "即使空闲也每帧重绘,而不是退回到短轮询间隔。用于诊断界面响应性很有用,但平时会浪费 CPU。",
+            "निष्क्रिय रहते हुए भी हर फ़्रेम को रीपेंट करें, छोटे पोल अंतराल पर वापस जाने के बजाय। UI प्रतिक्रियाशीलता का निदान करने में उपयोगी, पर अन्यथा CPU बर्बाद करता है।",
+            "Перерисовывать каждый кадр даже в режиме ожидания, вместо перехода на короткий интервал опроса. Полезно для диагностики отзывчивости интерфейса, но иначе расходует ресурсы ЦП.",
+            "Boşta bile her kareyi yeniden boyar, kısa bir yoklama aralığına geçmek yerine. Arayüz tepkiselliğini teşhis ederken kullanışlıdır, ancak aksi halde CPU'yu boşa harcar.",
+            "Ridisegna ogni fotogramma anche da inattivo, invece di passare a un breve intervallo di polling. Utile per diagnosticare la reattività dell'interfaccia, ma altrimenti spreca CPU.",
+            "アイドル中でも短いポーリング間隔に切り替えず、毎フレーム再描画します。UIの応答性の診断に便利ですが、それ以外ではCPUを浪費します。",
+            "유휴 상태에서도 짧은 폴링 간격으로 전환하지 않고 매 프레임을 다시 그립니다. UI 응답성을 진단할 때 유용하지만 그 외에는 CPU를 낭비합니다.",
+            "إعادة الرسم في كل إطار حتى أثناء الخمول، بدلاً من التراجع إلى فاصل استطلاع قصير. مفيد عند تشخيص استجابة الواجهة، لكنه يهدر المعالج في غير ذلك.",
+            "Odświeżaj każdą klatkę nawet w stanie bezczynności, zamiast przechodzić na krótkie odpytywanie. Przydatne przy diagnozowaniu responsywności interfejsu, ale w innym wypadku marnuje CPU.",
+            "Vẽ lại mọi khung hình ngay cả khi nhàn rỗi, thay vì chuyển sang thăm dò ngắn. Hữu ích khi chẩn đoán độ phản hồi của giao diện, nhưng nếu không sẽ lãng phí CPU.",
+        )
+    }
+
+    pub fn use_system_java_toggle(self) -> &'static str {
+        self.pick(
+            "Use Java found on PATH instead of downloading one",
+            "Використовувати Java, знайдену в PATH, замість завантаження",
+            "Usar el Java encontrado en PATH en lugar de descargar uno",
+            "Utiliser le Java trouvé dans le PATH au lieu d'en télécharger un",
+            "Auf PATH gefundenes Java verwenden, statt eines herunterzuladen",
+            "Usar o Java encontrado no PATH em vez de baixar um",
+            "使用 PATH 中找到的 Java,而不是下载一个",
+            "डाउनलोड करने के बजाय PATH में मिले Java का उपयोग करें",
+            "Использовать Java, найденную в PATH, вместо загрузки",
+            "İndirmek yerine PATH üzerinde bulunan Java'yı kullan",
+            "Usa il Java trovato nel PATH invece di scaricarne uno",
+            "ダウンロードする代わりにPATH上に見つかったJavaを使用する",
+            "다운로드하는 대신 PATH에서 찾은 Java 사용",
+            "استخدام جافا الموجودة في PATH بدلاً من تنزيل واحدة",
+            "Użyj Javy znalezionej w PATH zamiast jej pobierania",
+            "Sử dụng Java tìm thấy trong PATH thay vì tải xuống",
+        )
+    }
+
+    pub fn use_system_java_hint(self, detected_path: &str) -> String {
+        match self.language {
+            Language::English => format!("A compatible Java was found on PATH at {detected_path}. Enable this to skip the bundled runtime download and use it instead."),
+            Language::Ukrainian => format!("Сумісну Java знайдено в PATH за адресою {detected_path}. Увімкніть, щоб пропустити завантаження вбудованого середовища та використовувати цю Java."),
+            Language::Spanish => format!("Se encontró un Java compatible en PATH en {detected_path}. Actívalo para omitir la descarga del entorno incluido y usar este en su lugar."),
+            Language::French => format!("Un Java compatible a été trouvé dans le PATH à {detected_path}. Activez ceci pour ignorer le téléchargement de l'environnement fourni et l'utiliser à la place."),
+            Language::German => format!("Ein kompatibles Java wurde im PATH unter {detected_path} gefunden. Aktivieren, um den Download der mitgelieferten Laufzeitumgebung zu überspringen und dieses stattdessen zu verwenden."),
+            Language::Portuguese => format!("Um Java compatível foi encontrado no PATH em {detected_path}. Ative para ignorar o download do runtime incluso e usar este em vez disso."),
+            Language::Chinese => format!("在 PATH 中的 {detected_path} 找到了兼容的 Java。启用此项可跳过内置运行时的下载,改用它。"),
+            Language::Hindi => format!("PATH में {detected_path} पर एक संगत Java मिला। बंडल किए गए रनटाइम डाउनलोड को छोड़ने और इसके बजाय इसका उपयोग करने के लिए इसे सक्षम करें।"),
+            Language::Russian => format!("Совместимая Java найдена в PATH по пути {detected_path}. Включите, чтобы пропустить загрузку встроенной среды выполнения и использовать эту."),
+            Language::Turkish => format!("PATH üzerinde {detected_path} konumunda uyumlu bir Java bulundu. Paketlenmiş çalışma zamanının indirilmesini atlayıp bunun yerine bunu kullanmak için etkinleştirin."),
+            Language::Italian => format!("È stato trovato un Java compatibile nel PATH in {detected_path}. Attiva questa opzione per saltare il download del runtime incluso e usare questo al suo posto."),
+            Language::Japanese => format!("PATH 上の {detected_path} に互換性のある Java が見つかりました。これを有効にすると、同梱ランタイムのダウンロードをスキップして代わりにこれを使用します。"),
+            Language::Korean => format!("PATH의 {detected_path}에서 호환되는 Java를 찾았습니다. 이를 활성화하면 번들된 런타임 다운로드를 건너뛰고 대신 이것을 사용합니다."),
+            Language::Arabic => format!("تم العثور على جافا متوافقة في PATH عند {detected_path}. فعّل هذا الخيار لتخطي تنزيل بيئة التشغيل المجمّعة واستخدام هذه بدلاً منها."),
+            Language::Polish => format!("Znaleziono zgodną Javę w PATH pod adresem {detected_path}. Włącz, aby pominąć pobieranie dołączonego środowiska uruchomieniowego i użyć tej zamiast niego."),
+            Language::Vietnamese => format!("Đã tìm thấy Java tương thích trong PATH tại {detected_path}. Bật tùy chọn này để bỏ qua việc tải xuống runtime đi kèm và sử dụng Java này thay thế."),
+        }
+    }
+
     pub fn diagnostics_heading(self) -> &'static str {
         self.pick(
             "Diagnostics",
@@ -1205,6 +3544,11 @@ impl I18n {
             "Диагностика",
             "Tanılama",
             "Diagnostica",
+            "診断",
+            "진단",
+            "التشخيص",
+            "Diagnostyka",
+            "Chẩn đoán",
         )
     }
 
@@ -1221,9 +3565,161 @@ impl I18n {
             "Просмотреть отчет",
             "Raporu görüntüle",
             "Visualizza report",
+            "レポートを表示",
+            "보고서 보기",
+            "عرض التقرير",
+            "Wyświetl raport",
+            "Xem báo cáo",
+        )
+    }
+
+    pub fn test_java_button(self) -> &'static str {
+        self.pick(
+            "Test Java",
+            "Перевірити Java",
+            "Probar Java",
+            "Tester Java",
+            "Java testen",
+            "Testar Java",
+            "测试 Java",
+            "जावा टेस्ट करें",
+            "Проверить Java",
+            "Java'yı test et",
+            "Testa Java",
+            "Javaをテスト",
+            "Java 테스트",
+            "اختبار Java",
+            "Testuj Javę",
+            "Kiểm tra Java",
+        )
+    }
+
+    pub fn testing_java(self) -> &'static str {
+        self.pick(
+            "Testing Java...",
+            "Перевірка Java...",
+            "Probando Java...",
+            "Test de Java en cours...",
+            "Java wird getestet...",
+            "Testando Java...",
+            "正在测试 Java...",
+            "जावा का परीक्षण हो रहा है...",
+            "Проверка Java...",
+            "Java test ediliyor...",
+            "Test di Java in corso...",
+            "Javaをテスト中...",
+            "Java 테스트 중...",
+            "جارٍ اختبار Java...",
+            "Testowanie Javy...",
+            "Đang kiểm tra Java...",
+        )
+    }
+
+    pub fn java_test_empty(self) -> &'static str {
+        self.pick(
+            "Java hasn't been tested yet.",
+            "Java ще не перевірялася.",
+            "Java aún no se ha probado.",
+            "Java n'a pas encore été testé.",
+            "Java wurde noch nicht getestet.",
+            "Java ainda não foi testado.",
+            "尚未测试 Java。",
+            "जावा का अभी तक परीक्षण नहीं हुआ है।",
+            "Java еще не проверялась.",
+            "Java henüz test edilmedi.",
+            "Java non è ancora stato testato.",
+            "Javaはまだテストされていません。",
+            "Java가 아직 테스트되지 않았습니다.",
+            "لم يتم اختبار Java بعد.",
+            "Java nie została jeszcze przetestowana.",
+            "Java chưa được kiểm tra.",
+        )
+    }
+
+    pub fn java_test_result(self, output: &str) -> String {
+        match self.language {
+            Language::English => format!("Java is working: {output}"),
+            Language::Ukrainian => format!("Java працює: {output}"),
+            Language::Spanish => format!("Java funciona: {output}"),
+            Language::French => format!("Java fonctionne : {output}"),
+            Language::German => format!("Java funktioniert: {output}"),
+            Language::Portuguese => format!("Java está funcionando: {output}"),
+            Language::Chinese => format!("Java 可正常运行：{output}"),
+            Language::Hindi => format!("जावा काम कर रहा है: {output}"),
+            Language::Russian => format!("Java работает: {output}"),
+            Language::Turkish => format!("Java çalışıyor: {output}"),
+            Language::Italian => format!("Java funziona: {output}"),
+            Language::Japanese => format!("Javaは動作しています: {output}"),
+            Language::Korean => format!("Java가 작동 중입니다: {output}"),
+            Language::Arabic => format!("Java يعمل: {output}"),
+            Language::Polish => format!("Java działa: {output}"),
+            Language::Vietnamese => format!("Java hoạt động: {output}"),
+        }
+    }
+
+    pub fn create_crash_report_button(self) -> &'static str {
+        self.pick(
+            "Create crash report",
+            "Створити звіт про збій",
+            "Crear informe de fallo",
+            "Créer un rapport de plantage",
+            "Absturzbericht erstellen",
+            "Criar relatório de falha",
+            "创建崩溃报告",
+            "क्रैश रिपोर्ट बनाएं",
+            "Создать отчет о сбое",
+            "Çökme raporu oluştur",
+            "Crea report crash",
+            "クラッシュレポートを作成",
+            "충돌 보고서 생성",
+            "إنشاء تقرير الأعطال",
+            "Utwórz raport awarii",
+            "Tạo báo cáo lỗi",
+        )
+    }
+
+    pub fn creating_crash_report(self) -> &'static str {
+        self.pick(
+            "Creating crash report...",
+            "Створення звіту про збій...",
+            "Creando informe de fallo...",
+            "Création du rapport de plantage...",
+            "Absturzbericht wird erstellt...",
+            "Criando relatório de falha...",
+            "正在创建崩溃报告...",
+            "क्रैश रिपोर्ट बनाई जा रही है...",
+            "Создание отчета о сбое...",
+            "Çökme raporu oluşturuluyor...",
+            "Creazione report crash in corso...",
+            "クラッシュレポートを作成中...",
+            "충돌 보고서 생성 중...",
+            "جارٍ إنشاء تقرير الأعطال...",
+            "Tworzenie raportu awarii...",
+            "Đang tạo báo cáo lỗi...",
         )
     }
 
+    pub fn crash_report_ready(self, path: &str) -> String {
+        match self.language {
+            Language::English => format!("Crash report ready: {path}"),
+            Language::Ukrainian => format!("Звіт про збій готовий: {path}"),
+            Language::Spanish => format!("Informe de fallo listo: {path}"),
+            Language::French => format!("Rapport de plantage prêt : {path}"),
+            Language::German => format!("Absturzbericht bereit: {path}"),
+            Language::Portuguese => format!("Relatório de falha pronto: {path}"),
+            Language::Chinese => format!("崩溃报告已就绪：{path}"),
+            Language::Hindi => format!("क्रैश रिपोर्ट तैयार है: {path}"),
+            Language::Russian => format!("Отчет о сбое готов: {path}"),
+            Language::Turkish => format!("Çökme raporu hazır: {path}"),
+            Language::Italian => format!("Report crash pronto: {path}"),
+            Language::Japanese => format!("クラッシュレポートの準備ができました: {path}"),
+            Language::Korean => format!("충돌 보고서가 준비되었습니다: {path}"),
+            Language::Arabic => format!("تقرير الأعطال جاهز: {path}"),
+            Language::Polish => format!("Raport awarii gotowy: {path}"),
+            Language::Vietnamese => format!("Báo cáo lỗi đã sẵn sàng: {path}"),
+        }
+    }
+
     pub fn checking(self) -> &'static str {
         self.pick(
             "Checking for updates...",
@@ -1237,6 +3733,11 @@ impl I18n {
             "Проверка обновлений...",
             "Güncellemeler kontrol ediliyor...",
             "Controllo aggiornamenti in corso...",
+            "更新を確認中...",
+            "업데이트 확인 중...",
+            "جارٍ التحقق من التحديثات...",
+            "Sprawdzanie aktualizacji...",
+            "Đang kiểm tra cập nhật...",
         )
     }
 
@@ -1253,27 +3754,173 @@ impl I18n {
             Language::Russian => format!("Загрузка {file}"),
             Language::Turkish => format!("{file} indiriliyor"),
             Language::Italian => format!("Download di {file} in corso"),
+            Language::Japanese => format!("{file} をダウンロード中"),
+            Language::Korean => format!("{file} 다운로드 중"),
+            Language::Arabic => format!("جارٍ تنزيل {file}"),
+            Language::Polish => format!("Pobieranie {file}"),
+            Language::Vietnamese => format!("Đang tải xuống {file}"),
         }
     }
 
-    pub fn uninstalling(self) -> &'static str {
-        self.pick(
-            "Removing game files...",
-            "Видаляємо файли гри...",
-            "Eliminando archivos del juego...",
-            "Suppression des fichiers du jeu...",
-            "Spieldateien werden entfernt...",
-            "Removendo arquivos do jogo...",
-            "正在删除游戏文件...",
-            "गेम फ़ाइलें हटाई जा रही हैं...",
-            "Удаляем файлы игры...",
-            "Oyun dosyaları kaldırılıyor...",
-            "Rimozione file di gioco in corso...",
-        )
+    pub fn uninstalling(self, stage: &str) -> &'static str {
+        match stage {
+            "jre" => self.pick(
+                "Removing Java Runtime...",
+                "Видаляємо Java Runtime...",
+                "Eliminando el entorno de ejecución de Java...",
+                "Suppression du runtime Java...",
+                "Java-Runtime wird entfernt...",
+                "Removendo o runtime Java...",
+                "正在删除 Java 运行时...",
+                "जावा रनटाइम हटाया जा रहा है...",
+                "Удаляем Java Runtime...",
+                "Java Runtime kaldırılıyor...",
+                "Rimozione del runtime Java in corso...",
+                "Javaランタイムを削除中...",
+                "Java 런타임 제거 중...",
+                "جارٍ إزالة بيئة تشغيل Java...",
+                "Usuwanie środowiska uruchomieniowego Java...",
+                "Đang xóa Java Runtime...",
+            ),
+            "cache" => self.pick(
+                "Removing cache...",
+                "Видаляємо кеш...",
+                "Eliminando la caché...",
+                "Suppression du cache...",
+                "Cache wird entfernt...",
+                "Removendo o cache...",
+                "正在删除缓存...",
+                "कैश हटाया जा रहा है...",
+                "Удаляем кэш...",
+                "Önbellek kaldırılıyor...",
+                "Rimozione della cache in corso...",
+                "キャッシュを削除中...",
+                "캐시 제거 중...",
+                "جارٍ إزالة ذاكرة التخزين المؤقت...",
+                "Usuwanie pamięci podręcznej...",
+                "Đang xóa bộ nhớ đệm...",
+            ),
+            "butler" => self.pick(
+                "Removing Butler files...",
+                "Видаляємо файли Butler...",
+                "Eliminando archivos de Butler...",
+                "Suppression des fichiers Butler...",
+                "Butler-Dateien werden entfernt...",
+                "Removendo arquivos do Butler...",
+                "正在删除 Butler 文件...",
+                "बटलर फ़ाइलें हटाई जा रही हैं...",
+                "Удаляем файлы Butler...",
+                "Butler dosyaları kaldırılıyor...",
+                "Rimozione dei file di Butler in corso...",
+                "Butlerファイルを削除中...",
+                "Butler 파일 제거 중...",
+                "جارٍ إزالة ملفات Butler...",
+                "Usuwanie plików Butler...",
+                "Đang xóa tệp Butler...",
+            ),
+            "user_data" => self.pick(
+                "Removing user data...",
+                "Видаляємо дані користувача...",
+                "Eliminando los datos del usuario...",
+                "Suppression des données utilisateur...",
+                "Benutzerdaten werden entfernt...",
+                "Removendo dados do usuário...",
+                "正在删除用户数据...",
+                "उपयोगकर्ता डेटा हटाया जा रहा है...",
+                "Удаляем данные пользователя...",
+                "Kullanıcı verileri kaldırılıyor...",
+                "Rimozione dei dati utente in corso...",
+                "ユーザーデータを削除中...",
+                "사용자 데이터 제거 중...",
+                "جارٍ إزالة بيانات المستخدم...",
+                "Usuwanie danych użytkownika...",
+                "Đang xóa dữ liệu người dùng...",
+            ),
+            "saved_version" => self.pick(
+                "Clearing saved version...",
+                "Очищаємо збережену версію...",
+                "Borrando la versión guardada...",
+                "Suppression de la version enregistrée...",
+                "Gespeicherte Version wird gelöscht...",
+                "Limpando a versão salva...",
+                "正在清除已保存的版本...",
+                "सहेजा गया संस्करण साफ़ किया जा रहा है...",
+                "Очищаем сохранённую версию...",
+                "Kaydedilen sürüm temizleniyor...",
+                "Cancellazione della versione salvata in corso...",
+                "保存済みバージョンを削除中...",
+                "저장된 버전 지우는 중...",
+                "جارٍ مسح الإصدار المحفوظ...",
+                "Czyszczenie zapisanej wersji...",
+                "Đang xóa phiên bản đã lưu...",
+            ),
+            _ => self.pick(
+                "Removing game files...",
+                "Видаляємо файли гри...",
+                "Eliminando archivos del juego...",
+                "Suppression des fichiers du jeu...",
+                "Spieldateien werden entfernt...",
+                "Removendo arquivos do jogo...",
+                "正在删除游戏文件...",
+                "गेम फ़ाइलें हटाई जा रही हैं...",
+                "Удаляем файлы игры...",
+                "Oyun dosyaları kaldırılıyor...",
+                "Rimozione file di gioco in corso...",
+                "ゲームファイルを削除中...",
+                "게임 파일 제거 중...",
+                "جارٍ إزالة ملفات اللعبة...",
+                "Usuwanie plików gry...",
+                "Đang xóa tệp trò chơi...",
+            ),
+        }
     }
 
-    pub fn progress(self, progress: f32, speed: &str) -> String {
-        format!("{progress:.0}% ({speed})")
+    pub fn preparing_runtime(self, stage: &str) -> &'static str {
+        match stage {
+            "extracting" => self.pick(
+                "Extracting Java Runtime...",
+                "Розпаковуємо Java Runtime...",
+                "Extrayendo el entorno de ejecución de Java...",
+                "Extraction du runtime Java...",
+                "Java-Runtime wird entpackt...",
+                "Extraindo o runtime Java...",
+                "正在解压 Java 运行时...",
+                "जावा रनटाइम निकाला जा रहा है...",
+                "Распаковываем Java Runtime...",
+                "Java Runtime ayıklanıyor...",
+                "Estrazione del runtime Java in corso...",
+                "Javaランタイムを展開中...",
+                "Java 런타임 압축 해제 중...",
+                "جارٍ استخراج بيئة تشغيل Java...",
+                "Rozpakowywanie środowiska uruchomieniowego Java...",
+                "Đang giải nén Java Runtime...",
+            ),
+            _ => self.pick(
+                "Downloading Java Runtime...",
+                "Завантажуємо Java Runtime...",
+                "Descargando el entorno de ejecución de Java...",
+                "Téléchargement du runtime Java...",
+                "Java-Runtime wird heruntergeladen...",
+                "Baixando o runtime Java...",
+                "正在下载 Java 运行时...",
+                "जावा रनटाइम डाउनलोड किया जा रहा है...",
+                "Загружаем Java Runtime...",
+                "Java Runtime indiriliyor...",
+                "Download del runtime Java in corso...",
+                "Javaランタイムをダウンロード中...",
+                "Java 런타임 다운로드 중...",
+                "جارٍ تنزيل بيئة تشغيل Java...",
+                "Pobieranie środowiska uruchomieniowego Java...",
+                "Đang tải xuống Java Runtime...",
+            ),
+        }
+    }
+
+    pub fn progress(self, progress: f32, speed: &str, eta: Option<&str>) -> String {
+        match eta {
+            Some(eta) => format!("{progress:.0}% ({speed}, {eta})"),
+            None => format!("{progress:.0}% ({speed})"),
+        }
     }
 
     pub fn ready(self, version: &str) -> String {
@@ -1289,6 +3936,11 @@ impl I18n {
             Language::Russian => format!("Готово к игре версии {version}"),
             Language::Turkish => format!("{version} sürümünü oynamaya hazır"),
             Language::Italian => format!("Pronto per giocare alla versione {version}"),
+            Language::Japanese => format!("バージョン {version} をプレイする準備ができました"),
+            Language::Korean => format!("버전 {version} 플레이 준비 완료"),
+            Language::Arabic => format!("جاهز للعب الإصدار {version}"),
+            Language::Polish => format!("Gotowe do gry w wersji {version}"),
+            Language::Vietnamese => format!("Sẵn sàng chơi phiên bản {version}"),
         }
     }
 
@@ -1305,6 +3957,11 @@ impl I18n {
             "Запуск Hytale...",
             "Hytale başlatılıyor...",
             "Avvio di Hytale in corso...",
+            "Hytaleを起動中...",
+            "Hytale 실행 중...",
+            "جارٍ تشغيل Hytale...",
+            "Uruchamianie Hytale...",
+            "Đang khởi chạy Hytale...",
         )
     }
 
@@ -1321,6 +3978,32 @@ impl I18n {
             Language::Russian => format!("Ошибка: {msg}"),
             Language::Turkish => format!("Hata: {msg}"),
             Language::Italian => format!("Errore: {msg}"),
+            Language::Japanese => format!("エラー: {msg}"),
+            Language::Korean => format!("오류: {msg}"),
+            Language::Arabic => format!("خطأ: {msg}"),
+            Language::Polish => format!("Błąd: {msg}"),
+            Language::Vietnamese => format!("Lỗi: {msg}"),
+        }
+    }
+
+    pub fn jre_integrity_failed(self, msg: &str) -> String {
+        match self.language {
+            Language::English => format!("JRE integrity check failed: {msg}"),
+            Language::Ukrainian => format!("Перевірка цілісності JRE не пройшла: {msg}"),
+            Language::Spanish => format!("Falló la verificación de integridad del JRE: {msg}"),
+            Language::French => format!("Échec de la vérification d'intégrité du JRE : {msg}"),
+            Language::German => format!("JRE-Integritätsprüfung fehlgeschlagen: {msg}"),
+            Language::Portuguese => format!("Falha na verificação de integridade do JRE: {msg}"),
+            Language::Chinese => format!("JRE 完整性校验失败: {msg}"),
+            Language::Hindi => format!("JRE अखंडता जाँच विफल रही: {msg}"),
+            Language::Russian => format!("Проверка целостности JRE не пройдена: {msg}"),
+            Language::Turkish => format!("JRE bütünlük denetimi başarısız oldu: {msg}"),
+            Language::Italian => format!("Controllo di integrità del JRE non riuscito: {msg}"),
+            Language::Japanese => format!("JREの整合性チェックに失敗しました: {msg}"),
+            Language::Korean => format!("JRE 무결성 검사에 실패했습니다: {msg}"),
+            Language::Arabic => format!("فشل فحص سلامة JRE: {msg}"),
+            Language::Polish => format!("Weryfikacja integralności JRE nie powiodła się: {msg}"),
+            Language::Vietnamese => format!("Kiểm tra tính toàn vẹn JRE thất bại: {msg}"),
         }
     }
 
@@ -1337,6 +4020,11 @@ impl I18n {
             "Инициализация лаунчера...",
             "Başlatıcı başlatılıyor...",
             "Inizializzazione launcher in corso...",
+            "ランチャーを初期化中...",
+            "런처 초기화 중...",
+            "جارٍ تهيئة المُشغّل...",
+            "Inicjalizowanie launchera...",
+            "Đang khởi tạo trình khởi chạy...",
         )
     }
 
@@ -1353,6 +4041,11 @@ impl I18n {
             "Ожидание. Нажмите \"Скачать игру\", чтобы установить или обновить.",
             "Boşta. Yüklemek veya güncellemek için Oyunu İndir'e tıklayın.",
             "Inattivo. Clicca Scarica Gioco per installare o aggiornare.",
+            "待機中。インストールまたは更新するには「ゲームをダウンロード」をクリックしてください。",
+            "대기 중입니다. 설치 또는 업데이트하려면 게임 다운로드를 클릭하세요.",
+            "خامل. انقر فوق تنزيل اللعبة للتثبيت أو التحديث.",
+            "Bezczynny. Kliknij Pobierz grę, aby zainstalować lub zaktualizować.",
+            "Nhàn rỗi. Nhấp vào Tải trò chơi để cài đặt hoặc cập nhật.",
         )
     }
 
@@ -1369,6 +4062,53 @@ impl I18n {
             "Играть",
             "Oyna",
             "Gioca",
+            "プレイ",
+            "플레이",
+            "لعب",
+            "Graj",
+            "Chơi",
+        )
+    }
+
+    pub fn game_running_status(self) -> &'static str {
+        self.pick(
+            "Game is running",
+            "Гра запущена",
+            "El juego está en ejecución",
+            "Le jeu est en cours d'exécution",
+            "Spiel läuft bereits",
+            "O jogo está em execução",
+            "游戏正在运行",
+            "गेम चल रहा है",
+            "Игра уже запущена",
+            "Oyun çalışıyor",
+            "Il gioco è in esecuzione",
+            "ゲームは実行中です",
+            "게임이 실행 중입니다",
+            "اللعبة قيد التشغيل",
+            "Gra jest uruchomiona",
+            "Trò chơi đang chạy",
+        )
+    }
+
+    pub fn force_launch_button(self) -> &'static str {
+        self.pick(
+            "Force launch another instance",
+            "Примусово запустити ще один екземпляр",
+            "Forzar otra instancia",
+            "Forcer une autre instance",
+            "Weitere Instanz erzwingen",
+            "Forçar outra instância",
+            "强制启动另一个实例",
+            "दूसरा इंस्टेंस ज़बरदस्ती लॉन्च करें",
+            "Принудительно запустить ещё один экземпляр",
+            "Başka bir örneği zorla başlat",
+            "Forza avvio di un'altra istanza",
+            "別のインスタンスを強制的に起動",
+            "다른 인스턴스 강제 실행",
+            "فرض تشغيل نسخة أخرى",
+            "Wymuś uruchomienie kolejnej instancji",
+            "Buộc khởi chạy một phiên bản khác",
         )
     }
 
@@ -1385,6 +4125,11 @@ impl I18n {
             "Скачать игру",
             "Oyunu indir",
             "Scarica Gioco",
+            "ゲームをダウンロード",
+            "게임 다운로드",
+            "تنزيل اللعبة",
+            "Pobierz grę",
+            "Tải trò chơi",
         )
     }
 
@@ -1401,6 +4146,11 @@ impl I18n {
             "Проверить обновления",
             "Güncellemeleri kontrol et",
             "Check for updates",
+            "更新を確認",
+            "업데이트 확인",
+            "التحقق من التحديثات",
+            "Sprawdź aktualizacje",
+            "Kiểm tra cập nhật",
         )
     }
 
@@ -1417,6 +4167,200 @@ impl I18n {
             "Отмена",
             "İptal",
             "Annulla",
+            "キャンセル",
+            "취소",
+            "إلغاء",
+            "Anuluj",
+            "Hủy",
+        )
+    }
+
+    pub fn reinstall_button(self) -> &'static str {
+        self.pick(
+            "Repair / reinstall",
+            "Відновити / перевстановити",
+            "Reparar / reinstalar",
+            "Réparer / réinstaller",
+            "Reparieren / neu installieren",
+            "Reparar / reinstalar",
+            "修复/重新安装",
+            "मरम्मत करें / पुनः स्थापित करें",
+            "Восстановить / переустановить",
+            "Onar / yeniden yükle",
+            "Ripara / reinstalla",
+            "修復・再インストール",
+            "복구 / 재설치",
+            "إصلاح / إعادة التثبيت",
+            "Napraw / zainstaluj ponownie",
+            "Sửa chữa / cài đặt lại",
+        )
+    }
+
+    pub fn reinstall_confirm_title(self) -> &'static str {
+        self.pick(
+            "Confirm repair/reinstall",
+            "Підтвердьте відновлення",
+            "Confirmar reparación",
+            "Confirmer la réparation",
+            "Reparatur bestätigen",
+            "Confirmar reparo",
+            "确认修复",
+            "मरम्मत की पुष्टि करें",
+            "Подтверждение восстановления",
+            "Onarımı onayla",
+            "Confirma riparazione",
+            "修復の確認",
+            "복구 확인",
+            "تأكيد الإصلاح",
+            "Potwierdź naprawę/ponowną instalację",
+            "Xác nhận sửa chữa/cài đặt lại",
+        )
+    }
+
+    pub fn reinstall_confirm_body(self) -> &'static str {
+        self.pick(
+            "This will delete the installed game files and download a full, fresh copy. Your JRE and cache are kept unless you also clear them below. Are you sure?",
+            "Це видалить встановлені файли гри та завантажить повну нову копію. JRE та кеш збережуться, якщо ви не очистите їх нижче. Ви впевнені?",
+            "Esto eliminará los archivos del juego instalados y descargará una copia completa. La JRE y la caché se conservan salvo que también las borres abajo. ¿Seguro?",
+            "Cela supprimera les fichiers du jeu installés et téléchargera une copie complète. Le JRE et le cache sont conservés sauf si vous les effacez aussi ci-dessous. Êtes-vous sûr ?",
+            "Dies löscht die installierten Spieldateien und lädt eine vollständige, frische Kopie herunter. JRE und Cache bleiben erhalten, sofern du sie unten nicht ebenfalls löschst. Bist du sicher?",
+            "Isso excluirá os arquivos do jogo instalados e baixará uma cópia completa e nova. Seu JRE e cache são mantidos, a menos que você também os limpe abaixo. Tem certeza?",
+            "这将删除已安装的游戏文件并下载全新的完整副本。除非您在下方也清除 JRE 和缓存，否则它们会保留。确定吗？",
+            "यह इंस्टॉल की गई गेम फ़ाइलें हटा देगा और एक पूरी नई प्रति डाउनलोड करेगा। आपका JRE और कैश तब तक बना रहेगा जब तक आप नीचे उन्हें भी साफ़ न करें। क्या आप सुनिश्चित हैं?",
+            "Будут удалены установленные файлы игры и загружена полная новая копия. JRE и кэш сохранятся, если вы также не очистите их ниже. Вы уверены?",
+            "Bu, yüklü oyun dosyalarını silecek ve tam, yeni bir kopya indirecek. Aşağıda ayrıca temizlemediğiniz sürece JRE ve önbelleğiniz korunur. Emin misiniz?",
+            "Questo eliminerà i file di gioco installati e scaricherà una copia completa e nuova. JRE e cache vengono mantenuti a meno che tu non li cancelli anche qui sotto. Sei sicuro?",
+            "インストール済みのゲームファイルを削除し、完全な新しいコピーをダウンロードします。以下でクリアしない限りJREとキャッシュは保持されます。本当に実行しますか？",
+            "설치된 게임 파일을 삭제하고 완전히 새로운 사본을 다운로드합니다. 아래에서 함께 지우지 않는 한 JRE와 캐시는 유지됩니다. 계속하시겠습니까?",
+            "سيؤدي هذا إلى حذف ملفات اللعبة المثبتة وتنزيل نسخة كاملة وجديدة. سيتم الاحتفاظ بـ JRE وذاكرة التخزين المؤقت ما لم تقم بمسحهما أيضًا أدناه. هل أنت متأكد؟",
+            "To usunie zainstalowane pliki gry i pobierze pełną, nową kopię. Twoje JRE i pamięć podręczna zostaną zachowane, chyba że wyczyścisz je poniżej. Czy na pewno?",
+            "Thao tác này sẽ xóa các tệp trò chơi đã cài đặt và tải xuống một bản sao đầy đủ, mới. JRE và bộ nhớ đệm của bạn sẽ được giữ lại trừ khi bạn cũng xóa chúng bên dưới. Bạn có chắc không?",
+        )
+    }
+
+    pub fn reinstall_clear_cache_checkbox(self) -> &'static str {
+        self.pick(
+            "Also clear JRE and download cache",
+            "Також очистити JRE та кеш завантажень",
+            "También borrar JRE y caché de descargas",
+            "Effacer aussi le JRE et le cache de téléchargement",
+            "JRE und Download-Cache ebenfalls löschen",
+            "Também limpar o JRE e o cache de downloads",
+            "同时清除 JRE 和下载缓存",
+            "JRE और डाउनलोड कैश भी साफ़ करें",
+            "Также очистить JRE и кэш загрузок",
+            "JRE ve indirme önbelleğini de temizle",
+            "Cancella anche JRE e cache di download",
+            "JREとダウンロードキャッシュも消去する",
+            "JRE 및 다운로드 캐시도 지우기",
+            "مسح JRE وذاكرة التخزين المؤقت للتنزيل أيضًا",
+            "Wyczyść także JRE i pamięć podręczną pobierania",
+            "Đồng thời xóa JRE và bộ nhớ đệm tải xuống",
+        )
+    }
+
+    pub fn reinstall_confirm_yes(self) -> &'static str {
+        self.pick(
+            "Yes, reinstall",
+            "Так, перевстановити",
+            "Sí, reinstalar",
+            "Oui, réinstaller",
+            "Ja, neu installieren",
+            "Sim, reinstalar",
+            "是的，重新安装",
+            "हाँ, पुनः स्थापित करें",
+            "Да, переустановить",
+            "Evet, yeniden yükle",
+            "Sì, reinstalla",
+            "はい、再インストールします",
+            "예, 재설치합니다",
+            "نعم، إعادة التثبيت",
+            "Tak, zainstaluj ponownie",
+            "Có, cài đặt lại",
+        )
+    }
+
+    pub fn close_confirm_title(self) -> &'static str {
+        self.pick(
+            "Cancel and quit?",
+            "Скасувати та вийти?",
+            "¿Cancelar y salir?",
+            "Annuler et quitter ?",
+            "Abbrechen und beenden?",
+            "Cancelar e sair?",
+            "取消并退出？",
+            "रद्द करें और बाहर निकलें?",
+            "Отменить и выйти?",
+            "İptal edip çıkılsın mı?",
+            "Annullare e uscire?",
+            "キャンセルして終了しますか？",
+            "취소하고 종료할까요?",
+            "إلغاء والخروج؟",
+            "Anulować i wyjść?",
+            "Hủy và thoát?",
+        )
+    }
+
+    pub fn close_confirm_body(self) -> &'static str {
+        self.pick(
+            "A download or install is still in progress. Closing now will cancel it and you'll need to start over. Quit anyway?",
+            "Завантаження або встановлення ще триває. Закриття зараз скасує його, і доведеться почати спочатку. Все одно вийти?",
+            "Todavía hay una descarga o instalación en curso. Cerrar ahora la cancelará y tendrás que empezar de nuevo. ¿Salir de todos modos?",
+            "Un téléchargement ou une installation est encore en cours. Fermer maintenant l'annulera et il faudra recommencer. Quitter quand même ?",
+            "Ein Download oder eine Installation läuft noch. Ein Schließen jetzt bricht ihn ab und du musst von vorn beginnen. Trotzdem beenden?",
+            "Um download ou instalação ainda está em andamento. Fechar agora vai cancelá-lo e você terá que recomeçar. Sair mesmo assim?",
+            "下载或安装仍在进行中。现在关闭将取消它，需要重新开始。仍要退出吗？",
+            "डाउनलोड या इंस्टॉल अभी भी जारी है। अभी बंद करने से यह रद्द हो जाएगा और आपको फिर से शुरू करना होगा। फिर भी बाहर निकलें?",
+            "Загрузка или установка всё ещё выполняется. Закрытие сейчас отменит её, и придётся начать заново. Всё равно выйти?",
+            "Bir indirme veya yükleme hâlâ devam ediyor. Şimdi kapatmak onu iptal eder ve baştan başlamanız gerekir. Yine de çıkılsın mı?",
+            "Un download o un'installazione sono ancora in corso. Chiudendo ora verrà annullato e dovrai ricominciare. Uscire comunque?",
+            "ダウンロードまたはインストールがまだ進行中です。今閉じるとキャンセルされ、最初からやり直す必要があります。それでも終了しますか？",
+            "다운로드 또는 설치가 아직 진행 중입니다. 지금 닫으면 취소되며 처음부터 다시 시작해야 합니다. 그래도 종료할까요?",
+            "لا يزال التنزيل أو التثبيت قيد التقدم. سيؤدي الإغلاق الآن إلى إلغائه وستحتاج إلى البدء من جديد. هل تريد الخروج على أي حال؟",
+            "Pobieranie lub instalacja wciąż trwa. Zamknięcie teraz je anuluje i trzeba będzie zacząć od nowa. Zamknąć mimo to?",
+            "Một lượt tải xuống hoặc cài đặt vẫn đang diễn ra. Đóng ngay bây giờ sẽ hủy nó và bạn sẽ phải bắt đầu lại. Vẫn thoát?",
+        )
+    }
+
+    pub fn close_confirm_yes(self) -> &'static str {
+        self.pick(
+            "Yes, cancel and quit",
+            "Так, скасувати й вийти",
+            "Sí, cancelar y salir",
+            "Oui, annuler et quitter",
+            "Ja, abbrechen und beenden",
+            "Sim, cancelar e sair",
+            "是，取消并退出",
+            "हाँ, रद्द करें और बाहर निकलें",
+            "Да, отменить и выйти",
+            "Evet, iptal edip çık",
+            "Sì, annulla ed esci",
+            "はい、キャンセルして終了",
+            "예, 취소하고 종료",
+            "نعم، إلغاء والخروج",
+            "Tak, anuluj i wyjdź",
+            "Có, hủy và thoát",
+        )
+    }
+
+    pub fn close_confirm_no(self) -> &'static str {
+        self.pick(
+            "No, keep going",
+            "Ні, продовжити",
+            "No, continuar",
+            "Non, continuer",
+            "Nein, weitermachen",
+            "Não, continuar",
+            "否，继续",
+            "नहीं, जारी रखें",
+            "Нет, продолжить",
+            "Hayır, devam et",
+            "No, continua",
+            "いいえ、続ける",
+            "아니요, 계속",
+            "لا، تابع",
+            "Nie, kontynuuj",
+            "Không, tiếp tục",
         )
     }
 
@@ -1433,6 +4377,11 @@ impl I18n {
             "Удалить игру",
             "Oyunu kaldır",
             "Disinstalla gioco",
+            "ゲームをアンインストール",
+            "게임 제거",
+            "إلغاء تثبيت اللعبة",
+            "Odinstaluj grę",
+            "Gỡ cài đặt trò chơi",
         )
     }
 
@@ -1449,22 +4398,74 @@ impl I18n {
             "Подтверждение удаления",
             "Kaldırmayı onayla",
             "Confirm uninstall",
+            "アンインストールの確認",
+            "제거 확인",
+            "تأكيد إلغاء التثبيت",
+            "Potwierdź odinstalowanie",
+            "Xác nhận gỡ cài đặt",
         )
     }
 
     pub fn uninstall_confirm_body(self) -> &'static str {
         self.pick(
-            "This will remove the game files and bundled JRE. Are you sure?",
-            "Це видалить файли гри та вбудовану JRE. Ви впевнені?",
-            "Esto eliminará los archivos del juego y la JRE incluida. ¿Seguro?",
-            "Cela supprimera les fichiers du jeu et la JRE incluse. Êtes-vous sûr ?",
-            "Dies entfernt die Spieldateien und die mitgelieferte JRE. Bist du sicher?",
-            "Isso removerá os arquivos do jogo e a JRE incluída. Tem certeza?",
-            "这将删除游戏文件和捆绑的 JRE。确定吗？",
-            "यह गेम फ़ाइलें और बंडल की गई JRE हटा देगा। क्या आप सुनिश्चित हैं?",
-            "Будут удалены файлы игры и встроенная JRE. Вы уверены?",
-            "Bu, oyun dosyalarını ve paketli JRE'yi kaldıracak. Emin misiniz?",
-            "This will remove the game files and bundled JRE. Are you sure?",
+            "This will remove the game files and bundled JRE. Choose what to keep below. Are you sure?",
+            "Це видалить файли гри та вбудовану JRE. Оберіть нижче, що залишити. Ви впевнені?",
+            "Esto eliminará los archivos del juego y la JRE incluida. Elige abajo qué conservar. ¿Seguro?",
+            "Cela supprimera les fichiers du jeu et la JRE incluse. Choisissez ci-dessous ce qu'il faut conserver. Êtes-vous sûr ?",
+            "Dies entfernt die Spieldateien und die mitgelieferte JRE. Wähle unten, was erhalten bleiben soll. Bist du sicher?",
+            "Isso removerá os arquivos do jogo e a JRE incluída. Escolha abaixo o que manter. Tem certeza?",
+            "这将删除游戏文件和捆绑的 JRE。请在下方选择要保留的内容。确定吗？",
+            "यह गेम फ़ाइलें और बंडल की गई JRE हटा देगा। नीचे चुनें कि क्या रखना है। क्या आप सुनिश्चित हैं?",
+            "Будут удалены файлы игры и встроенная JRE. Выберите ниже, что оставить. Вы уверены?",
+            "Bu, oyun dosyalarını ve paketli JRE'yi kaldıracak. Aşağıdan ne saklanacağını seçin. Emin misiniz?",
+            "Questo rimuoverà i file di gioco e la JRE inclusa. Scegli qui sotto cosa conservare. Sei sicuro?",
+            "ゲームファイルと同梱のJREが削除されます。以下で保持する項目を選択してください。本当に実行しますか？",
+            "게임 파일과 포함된 JRE가 제거됩니다. 아래에서 유지할 항목을 선택하세요. 계속하시겠습니까?",
+            "سيؤدي هذا إلى إزالة ملفات اللعبة وبيئة JRE المرفقة. اختر أدناه ما تريد الاحتفاظ به. هل أنت متأكد؟",
+            "To usunie pliki gry i dołączone JRE. Wybierz poniżej, co zachować. Czy na pewno?",
+            "Thao tác này sẽ xóa các tệp trò chơi và JRE đi kèm. Chọn bên dưới những gì cần giữ lại. Bạn có chắc không?",
+        )
+    }
+
+    pub fn uninstall_keep_user_data_checkbox(self) -> &'static str {
+        self.pick(
+            "Keep my saves (UserData)",
+            "Зберегти мої збереження (UserData)",
+            "Conservar mis partidas guardadas (UserData)",
+            "Conserver mes sauvegardes (UserData)",
+            "Meine Spielstände behalten (UserData)",
+            "Manter meus saves (UserData)",
+            "保留我的存档（UserData）",
+            "मेरी सेव फ़ाइलें रखें (UserData)",
+            "Сохранить мои сохранения (UserData)",
+            "Kayıtlarımı koru (UserData)",
+            "Conserva i miei salvataggi (UserData)",
+            "セーブデータを保持する（UserData）",
+            "내 저장 데이터 유지 (UserData)",
+            "الاحتفاظ بحفظاتي (UserData)",
+            "Zachowaj moje zapisy (UserData)",
+            "Giữ lại dữ liệu lưu của tôi (UserData)",
+        )
+    }
+
+    pub fn uninstall_keep_jre_checkbox(self) -> &'static str {
+        self.pick(
+            "Keep the bundled Java Runtime",
+            "Зберегти вбудовану Java Runtime",
+            "Conservar el entorno de ejecución de Java incluido",
+            "Conserver le runtime Java inclus",
+            "Die mitgelieferte Java-Runtime behalten",
+            "Manter o runtime Java incluído",
+            "保留捆绑的 Java 运行时",
+            "बंडल किया गया Java रनटाइम रखें",
+            "Сохранить встроенную Java Runtime",
+            "Paketli Java Runtime'ı koru",
+            "Conserva il runtime Java incluso",
+            "同梱のJavaランタイムを保持する",
+            "포함된 Java 런타임 유지",
+            "الاحتفاظ ببيئة تشغيل Java المرفقة",
+            "Zachowaj dołączone środowisko uruchomieniowe Java",
+            "Giữ lại Java Runtime đi kèm",
         )
     }
 
@@ -1481,6 +4482,11 @@ impl I18n {
             "Да, удалить",
             "Evet, kaldır",
             "Sì, disinstalla",
+            "はい、アンインストールします",
+            "예, 제거합니다",
+            "نعم، إلغاء التثبيت",
+            "Tak, odinstaluj",
+            "Có, gỡ cài đặt",
         )
     }
 
@@ -1497,9 +4503,687 @@ impl I18n {
             "Отмена",
             "İptal",
             "Annulla",
+            "キャンセル",
+            "취소",
+            "إلغاء",
+            "Anuluj",
+            "Hủy",
+        )
+    }
+
+    pub fn downgrade_confirm_title(self) -> &'static str {
+        self.pick(
+            "Downgrade game version?",
+            "Понизити версію гри?",
+            "¿Bajar la versión del juego?",
+            "Rétrograder la version du jeu ?",
+            "Spielversion herabstufen?",
+            "Rebaixar a versão do jogo?",
+            "降级游戏版本？",
+            "गेम संस्करण डाउनग्रेड करें?",
+            "Понизить версию игры?",
+            "Oyun sürümü düşürülsün mü?",
+            "Eseguire il downgrade della versione del gioco?",
+            "ゲームのバージョンをダウングレードしますか？",
+            "게임 버전을 다운그레이드하시겠습니까?",
+            "هل تريد خفض إصدار اللعبة؟",
+            "Obniżyć wersję gry?",
+            "Hạ cấp phiên bản trò chơi?",
+        )
+    }
+
+    pub fn downgrade_confirm_body(self, installed: u32, target: u32) -> String {
+        match self.language {
+            Language::English => format!(
+                "You have version {installed} installed and are about to switch to version {target}, which is older. Saves made with {installed} may not load correctly after this. Continue?"
+            ),
+            Language::Ukrainian => format!(
+                "У вас встановлена версія {installed}, і ви збираєтеся перейти на версію {target}, яка старіша. Збереження, створені з {installed}, можуть не завантажитися коректно після цього. Продовжити?"
+            ),
+            Language::Spanish => format!(
+                "Tienes la versión {installed} instalada y vas a cambiar a la versión {target}, que es más antigua. Las partidas guardadas con {installed} podrían no cargar correctamente después. ¿Continuar?"
+            ),
+            Language::French => format!(
+                "Vous avez la version {installed} installée et êtes sur le point de passer à la version {target}, plus ancienne. Les sauvegardes faites avec {installed} pourraient ne plus se charger correctement. Continuer ?"
+            ),
+            Language::German => format!(
+                "Du hast Version {installed} installiert und wechselst zu Version {target}, die älter ist. Speicherstände aus {installed} lassen sich danach möglicherweise nicht mehr korrekt laden. Fortfahren?"
+            ),
+            Language::Portuguese => format!(
+                "Você tem a versão {installed} instalada e está prestes a mudar para a versão {target}, que é mais antiga. Jogos salvos com a {installed} podem não carregar corretamente depois. Continuar?"
+            ),
+            Language::Chinese => format!(
+                "你当前安装的是版本 {installed}，即将切换到更旧的版本 {target}。使用 {installed} 创建的存档之后可能无法正常加载。是否继续？"
+            ),
+            Language::Hindi => format!(
+                "आपके पास संस्करण {installed} इंस्टॉल है और आप पुराने संस्करण {target} पर स्विच करने वाले हैं। {installed} के साथ बनाई गई सेव इसके बाद सही से लोड नहीं हो सकती। जारी रखें?"
+            ),
+            Language::Russian => format!(
+                "У вас установлена версия {installed}, и вы собираетесь перейти на более старую версию {target}. Сохранения, сделанные в {installed}, могут некорректно загрузиться после этого. Продолжить?"
+            ),
+            Language::Turkish => format!(
+                "{installed} sürümü yüklü ve daha eski olan {target} sürümüne geçmek üzeresiniz. {installed} ile oluşturulan kayıtlar bundan sonra düzgün yüklenemeyebilir. Devam edilsin mi?"
+            ),
+            Language::Italian => format!(
+                "Hai la versione {installed} installata e stai per passare alla versione {target}, più vecchia. I salvataggi creati con {installed} potrebbero non caricarsi correttamente dopo. Continuare?"
+            ),
+            Language::Japanese => format!(
+                "現在バージョン{installed}がインストールされていますが、より古いバージョン{target}に切り替えようとしています。{installed}で作成したセーブデータが正しく読み込めなくなる可能性があります。続行しますか？"
+            ),
+            Language::Korean => format!(
+                "현재 버전 {installed}이 설치되어 있으며 더 오래된 버전 {target}으로 전환하려고 합니다. {installed}에서 만든 저장 데이터가 이후 제대로 로드되지 않을 수 있습니다. 계속하시겠습니까?"
+            ),
+            Language::Arabic => format!(
+                "لديك الإصدار {installed} مثبتًا وأنت على وشك التبديل إلى الإصدار {target}، وهو أقدم. قد لا يتم تحميل الحفظات التي تم إنشاؤها بالإصدار {installed} بشكل صحيح بعد ذلك. هل تريد الاستمرار؟"
+            ),
+            Language::Polish => format!(
+                "Masz zainstalowaną wersję {installed} i zamierzasz przełączyć się na wersję {target}, która jest starsza. Zapisy stanu gry utworzone w wersji {installed} mogą się po tym nie wczytać poprawnie. Kontynuować?"
+            ),
+            Language::Vietnamese => format!(
+                "Bạn đang cài đặt phiên bản {installed} và sắp chuyển sang phiên bản {target} cũ hơn. Các bản lưu được tạo với {installed} có thể không tải đúng sau đó. Tiếp tục?"
+            ),
+        }
+    }
+
+    pub fn downgrade_confirm_yes(self) -> &'static str {
+        self.pick(
+            "Yes, downgrade",
+            "Так, понизити",
+            "Sí, bajar versión",
+            "Oui, rétrograder",
+            "Ja, herabstufen",
+            "Sim, rebaixar",
+            "是的，降级",
+            "हाँ, डाउनग्रेड करें",
+            "Да, понизить",
+            "Evet, düşür",
+            "Sì, esegui il downgrade",
+            "はい、ダウングレードします",
+            "예, 다운그레이드합니다",
+            "نعم، قم بالخفض",
+            "Tak, obniż wersję",
+            "Có, hạ cấp",
+        )
+    }
+
+    pub fn remove_mod_confirm_title(self) -> &'static str {
+        self.pick(
+            "Remove mod",
+            "Видалити мод",
+            "Quitar mod",
+            "Supprimer le mod",
+            "Mod entfernen",
+            "Remover mod",
+            "移除模组",
+            "मॉड हटाएँ",
+            "Удалить мод",
+            "Modu kaldır",
+            "Rimuovi mod",
+            "Modを削除",
+            "모드 제거",
+            "إزالة الإضافة",
+            "Usuń moda",
+            "Xóa mod",
+        )
+    }
+
+    pub fn remove_mod_confirm_body(self, name: &str) -> String {
+        match self.language {
+            Language::English => format!("This will delete \"{name}\" and its downloaded file. Are you sure?"),
+            Language::Ukrainian => format!("Це видалить «{name}» та його завантажений файл. Ви впевнені?"),
+            Language::Spanish => format!("Esto eliminará \"{name}\" y su archivo descargado. ¿Seguro?"),
+            Language::French => format!("Cela supprimera « {name} » et son fichier téléchargé. Êtes-vous sûr ?"),
+            Language::German => format!("Dies löscht \"{name}\" und die zugehörige heruntergeladene Datei. Bist du sicher?"),
+            Language::Portuguese => format!("Isso excluirá \"{name}\" e seu arquivo baixado. Tem certeza?"),
+            Language::Chinese => format!("这将删除“{name}”及其下载的文件。确定吗？"),
+            Language::Hindi => format!("यह \"{name}\" और उसकी डाउनलोड की गई फ़ाइल हटा देगा। क्या आप सुनिश्चित हैं?"),
+            Language::Russian => format!("Будет удалён «{name}» и его загруженный файл. Вы уверены?"),
+            Language::Turkish => format!("Bu, \"{name}\" modunu ve indirilen dosyasını silecek. Emin misiniz?"),
+            Language::Italian => format!("Questo eliminerà \"{name}\" e il suo file scaricato. Sei sicuro?"),
+            Language::Japanese => format!("「{name}」とダウンロード済みファイルが削除されます。本当に実行しますか？"),
+            Language::Korean => format!("\"{name}\"와(과) 다운로드된 파일이 삭제됩니다. 계속하시겠습니까?"),
+            Language::Arabic => format!("سيؤدي هذا إلى حذف \"{name}\" وملفه الذي تم تنزيله. هل أنت متأكد؟"),
+            Language::Polish => format!("To usunie \"{name}\" i jego pobrany plik. Czy na pewno?"),
+            Language::Vietnamese => format!("Thao tác này sẽ xóa \"{name}\" và tệp đã tải của nó. Bạn có chắc không?"),
+        }
+    }
+
+    pub fn mods_applied_badge(self) -> &'static str {
+        self.pick(
+            "Applied",
+            "Застосовано",
+            "Aplicado",
+            "Appliqué",
+            "Angewendet",
+            "Aplicado",
+            "已应用",
+            "लागू किया गया",
+            "Применено",
+            "Uygulandı",
+            "Applicato",
+            "適用済み",
+            "적용됨",
+            "مطبَّق",
+            "Zastosowano",
+            "Đã áp dụng",
+        )
+    }
+
+    pub fn mods_pending_badge(self) -> &'static str {
+        self.pick(
+            "Pending",
+            "Очікує",
+            "Pendiente",
+            "En attente",
+            "Ausstehend",
+            "Pendente",
+            "待处理",
+            "लंबित",
+            "Ожидает",
+            "Bekliyor",
+            "In sospeso",
+            "保留中",
+            "대기 중",
+            "قيد الانتظار",
+            "Oczekujące",
+            "Đang chờ",
+        )
+    }
+
+    pub fn mods_pending_relaunch_hint(self) -> &'static str {
+        self.pick(
+            "Relaunch the game to apply this mod.",
+            "Перезапустіть гру, щоб застосувати цей мод.",
+            "Reinicia el juego para aplicar este mod.",
+            "Relancez le jeu pour appliquer ce mod.",
+            "Starte das Spiel neu, um diesen Mod anzuwenden.",
+            "Reinicie o jogo para aplicar este mod.",
+            "重新启动游戏以应用此模组。",
+            "इस मॉड को लागू करने के लिए गेम को पुनः लॉन्च करें।",
+            "Перезапустите игру, чтобы применить этот мод.",
+            "Bu modu uygulamak için oyunu yeniden başlatın.",
+            "Riavvia il gioco per applicare questa mod.",
+            "このModを適用するにはゲームを再起動してください。",
+            "이 모드를 적용하려면 게임을 다시 시작하세요.",
+            "أعد تشغيل اللعبة لتطبيق هذه الإضافة.",
+            "Uruchom grę ponownie, aby zastosować tego moda.",
+            "Khởi chạy lại trò chơi để áp dụng mod này.",
+        )
+    }
+
+    pub fn mods_remove_all_button(self) -> &'static str {
+        self.pick(
+            "Remove all mods",
+            "Видалити всі моди",
+            "Quitar todos los mods",
+            "Supprimer tous les mods",
+            "Alle Mods entfernen",
+            "Remover todos os mods",
+            "移除所有模组",
+            "सभी मॉड हटाएँ",
+            "Удалить все моды",
+            "Tüm modları kaldır",
+            "Rimuovi tutte le mod",
+            "すべてのModを削除",
+            "모든 모드 제거",
+            "إزالة جميع الإضافات",
+            "Usuń wszystkie mody",
+            "Xóa tất cả mod",
         )
     }
 
+    pub fn remove_all_mods_confirm_title(self) -> &'static str {
+        self.pick(
+            "Remove all mods",
+            "Видалити всі моди",
+            "Quitar todos los mods",
+            "Supprimer tous les mods",
+            "Alle Mods entfernen",
+            "Remover todos os mods",
+            "移除所有模组",
+            "सभी मॉड हटाएँ",
+            "Удалить все моды",
+            "Tüm modları kaldır",
+            "Rimuovi tutte le mod",
+            "すべてのModを削除",
+            "모든 모드 제거",
+            "إزالة جميع الإضافات",
+            "Usuń wszystkie mody",
+            "Xóa tất cả mod",
+        )
+    }
+
+    pub fn remove_all_mods_confirm_body(self) -> &'static str {
+        self.pick(
+            "This will delete every installed mod and its downloaded file. The game itself is untouched. Are you sure?",
+            "Це видалить усі встановлені моди та їхні завантажені файли. Саму гру не буде зачеплено. Ви впевнені?",
+            "Esto eliminará todos los mods instalados y sus archivos descargados. El juego en sí no se verá afectado. ¿Seguro?",
+            "Cela supprimera tous les mods installés et leurs fichiers téléchargés. Le jeu lui-même reste intact. Êtes-vous sûr ?",
+            "Dies löscht alle installierten Mods und ihre heruntergeladenen Dateien. Das Spiel selbst bleibt unberührt. Bist du sicher?",
+            "Isso excluirá todos os mods instalados e seus arquivos baixados. O jogo em si não será afetado. Tem certeza?",
+            "这将删除所有已安装的模组及其下载的文件。游戏本身不受影响。确定吗？",
+            "यह सभी इंस्टॉल किए गए मॉड और उनकी डाउनलोड की गई फ़ाइलें हटा देगा। गेम स्वयं प्रभावित नहीं होगा। क्या आप सुनिश्चित हैं?",
+            "Будут удалены все установленные моды и их загруженные файлы. Сама игра затронута не будет. Вы уверены?",
+            "Bu, yüklü tüm modları ve indirilen dosyalarını silecek. Oyunun kendisi etkilenmez. Emin misiniz?",
+            "Questo eliminerà tutte le mod installate e i relativi file scaricati. Il gioco stesso resterà intatto. Sei sicuro?",
+            "インストール済みのすべてのModとダウンロード済みファイルが削除されます。ゲーム本体には影響しません。本当に実行しますか？",
+            "설치된 모든 모드와 다운로드된 파일이 삭제됩니다. 게임 자체는 영향을 받지 않습니다. 계속하시겠습니까?",
+            "سيؤدي هذا إلى حذف جميع الإضافات المثبتة وملفاتها التي تم تنزيلها. لن تتأثر اللعبة نفسها. هل أنت متأكد؟",
+            "To usunie każdego zainstalowanego moda i jego pobrany plik. Sama gra pozostanie nietknięta. Czy na pewno?",
+            "Thao tác này sẽ xóa mọi mod đã cài đặt và tệp đã tải của nó. Bản thân trò chơi sẽ không bị ảnh hưởng. Bạn có chắc không?",
+        )
+    }
+
+    pub fn remove_mod_confirm_yes(self) -> &'static str {
+        self.pick(
+            "Yes, remove",
+            "Так, видалити",
+            "Sí, quitar",
+            "Oui, supprimer",
+            "Ja, entfernen",
+            "Sim, remover",
+            "是的，移除",
+            "हाँ, हटाएँ",
+            "Да, удалить",
+            "Evet, kaldır",
+            "Sì, rimuovi",
+            "はい、削除します",
+            "예, 제거합니다",
+            "نعم، إزالة",
+            "Tak, usuń",
+            "Có, xóa",
+        )
+    }
+
+    pub fn mods_repair_button(self) -> &'static str {
+        self.pick(
+            "Repair mods",
+            "Відновити моди",
+            "Reparar mods",
+            "Réparer les mods",
+            "Mods reparieren",
+            "Reparar mods",
+            "修复模组",
+            "मॉड्स ठीक करें",
+            "Восстановить моды",
+            "Modları onar",
+            "Ripara le mod",
+            "Modを修復",
+            "모드 복구",
+            "إصلاح الإضافات",
+            "Napraw mody",
+            "Sửa chữa mod",
+        )
+    }
+
+    pub fn mods_export_button(self) -> &'static str {
+        self.pick(
+            "Export list",
+            "Експортувати список",
+            "Exportar lista",
+            "Exporter la liste",
+            "Liste exportieren",
+            "Exportar lista",
+            "导出列表",
+            "सूची निर्यात करें",
+            "Экспортировать список",
+            "Listeyi dışa aktar",
+            "Esporta elenco",
+            "リストをエクスポート",
+            "목록 내보내기",
+            "تصدير القائمة",
+            "Eksportuj listę",
+            "Xuất danh sách",
+        )
+    }
+
+    pub fn mods_export_dialog_title(self) -> &'static str {
+        self.pick(
+            "Save mod list",
+            "Зберегти список модів",
+            "Guardar lista de mods",
+            "Enregistrer la liste des mods",
+            "Mod-Liste speichern",
+            "Salvar lista de mods",
+            "保存模组列表",
+            "मॉड सूची सहेजें",
+            "Сохранить список модов",
+            "Mod listesini kaydet",
+            "Salva elenco mod",
+            "Modリストを保存",
+            "모드 목록 저장",
+            "حفظ قائمة الإضافات",
+            "Zapisz listę modów",
+            "Lưu danh sách mod",
+        )
+    }
+
+    pub fn mods_export_success(self) -> &'static str {
+        self.pick(
+            "Mod list exported",
+            "Список модів експортовано",
+            "Lista de mods exportada",
+            "Liste des mods exportée",
+            "Mod-Liste exportiert",
+            "Lista de mods exportada",
+            "模组列表已导出",
+            "मॉड सूची निर्यात की गई",
+            "Список модов экспортирован",
+            "Mod listesi dışa aktarıldı",
+            "Elenco mod esportato",
+            "Modリストをエクスポートしました",
+            "모드 목록을 내보냈습니다",
+            "تم تصدير قائمة الإضافات",
+            "Wyeksportowano listę modów",
+            "Đã xuất danh sách mod",
+        )
+    }
+
+    pub fn mods_export_failed(self, err: &str) -> String {
+        match self.language {
+            Language::English => format!("Failed to export mod list: {err}"),
+            Language::Ukrainian => format!("Не вдалося експортувати список модів: {err}"),
+            Language::Spanish => format!("No se pudo exportar la lista de mods: {err}"),
+            Language::French => format!("Échec de l'exportation de la liste des mods : {err}"),
+            Language::German => format!("Mod-Liste konnte nicht exportiert werden: {err}"),
+            Language::Portuguese => format!("Falha ao exportar a lista de mods: {err}"),
+            Language::Chinese => format!("导出模组列表失败：{err}"),
+            Language::Hindi => format!("मॉड सूची निर्यात करने में विफल: {err}"),
+            Language::Russian => format!("Не удалось экспортировать список модов: {err}"),
+            Language::Turkish => format!("Mod listesi dışa aktarılamadı: {err}"),
+            Language::Italian => format!("Impossibile esportare l'elenco delle mod: {err}"),
+            Language::Japanese => format!("Modリストのエクスポートに失敗しました: {err}"),
+            Language::Korean => format!("모드 목록 내보내기 실패: {err}"),
+            Language::Arabic => format!("فشل تصدير قائمة الإضافات: {err}"),
+            Language::Polish => format!("Nie udało się wyeksportować listy modów: {err}"),
+            Language::Vietnamese => format!("Không thể xuất danh sách mod: {err}"),
+        }
+    }
+
+    pub fn mods_import_button(self) -> &'static str {
+        self.pick(
+            "Import list",
+            "Імпортувати список",
+            "Importar lista",
+            "Importer la liste",
+            "Liste importieren",
+            "Importar lista",
+            "导入列表",
+            "सूची आयात करें",
+            "Импортировать список",
+            "Listeyi içe aktar",
+            "Importa elenco",
+            "リストをインポート",
+            "목록 가져오기",
+            "استيراد القائمة",
+            "Importuj listę",
+            "Nhập danh sách",
+        )
+    }
+
+    pub fn mods_import_dialog_title(self) -> &'static str {
+        self.pick(
+            "Open mod list",
+            "Відкрити список модів",
+            "Abrir lista de mods",
+            "Ouvrir la liste des mods",
+            "Mod-Liste öffnen",
+            "Abrir lista de mods",
+            "打开模组列表",
+            "मॉड सूची खोलें",
+            "Открыть список модов",
+            "Mod listesini aç",
+            "Apri elenco mod",
+            "Modリストを開く",
+            "모드 목록 열기",
+            "فتح قائمة الإضافات",
+            "Otwórz listę modów",
+            "Mở danh sách mod",
+        )
+    }
+
+    pub fn mods_import_nothing_to_do(self) -> &'static str {
+        self.pick(
+            "All mods in that list are already installed",
+            "Усі моди зі списку вже встановлено",
+            "Todos los mods de esa lista ya están instalados",
+            "Tous les mods de cette liste sont déjà installés",
+            "Alle Mods aus dieser Liste sind bereits installiert",
+            "Todos os mods dessa lista já estão instalados",
+            "该列表中的所有模组均已安装",
+            "उस सूची के सभी मॉड पहले से इंस्टॉल हैं",
+            "Все моды из этого списка уже установлены",
+            "O listedeki tüm modlar zaten yüklü",
+            "Tutte le mod di quell'elenco sono già installate",
+            "そのリストのModはすべてインストール済みです",
+            "해당 목록의 모든 모드가 이미 설치되어 있습니다",
+            "جميع الإضافات في تلك القائمة مثبتة بالفعل",
+            "Wszystkie mody z tej listy są już zainstalowane",
+            "Tất cả các mod trong danh sách đó đã được cài đặt",
+        )
+    }
+
+    pub fn mods_import_queued(self, count: usize) -> String {
+        match self.language {
+            Language::English => format!("Queued {count} mod(s) for download"),
+            Language::Ukrainian => format!("У чергу додано {count} мод(ів) для завантаження"),
+            Language::Spanish => format!("{count} mod(s) en cola para descargar"),
+            Language::French => format!("{count} mod(s) mis en file d'attente pour téléchargement"),
+            Language::German => format!("{count} Mod(s) zum Herunterladen eingereiht"),
+            Language::Portuguese => format!("{count} mod(s) na fila para download"),
+            Language::Chinese => format!("已将 {count} 个模组加入下载队列"),
+            Language::Hindi => format!("डाउनलोड के लिए {count} मॉड कतारबद्ध किए गए"),
+            Language::Russian => format!("В очередь на загрузку добавлено {count} мод(ов)"),
+            Language::Turkish => format!("{count} mod indirme için kuyruğa alındı"),
+            Language::Italian => format!("{count} mod in coda per il download"),
+            Language::Japanese => format!("{count} 個のModをダウンロードキューに追加しました"),
+            Language::Korean => format!("{count}개 모드를 다운로드 대기열에 추가했습니다"),
+            Language::Arabic => format!("تمت إضافة {count} إضافة إلى قائمة انتظار التنزيل"),
+            Language::Polish => format!("Dodano do kolejki {count} mod(ów) do pobrania"),
+            Language::Vietnamese => format!("Đã xếp hàng {count} mod để tải xuống"),
+        }
+    }
+
+    pub fn mods_import_failed(self, err: &str) -> String {
+        match self.language {
+            Language::English => format!("Failed to import mod list: {err}"),
+            Language::Ukrainian => format!("Не вдалося імпортувати список модів: {err}"),
+            Language::Spanish => format!("No se pudo importar la lista de mods: {err}"),
+            Language::French => format!("Échec de l'importation de la liste des mods : {err}"),
+            Language::German => format!("Mod-Liste konnte nicht importiert werden: {err}"),
+            Language::Portuguese => format!("Falha ao importar a lista de mods: {err}"),
+            Language::Chinese => format!("导入模组列表失败：{err}"),
+            Language::Hindi => format!("मॉड सूची आयात करने में विफल: {err}"),
+            Language::Russian => format!("Не удалось импортировать список модов: {err}"),
+            Language::Turkish => format!("Mod listesi içe aktarılamadı: {err}"),
+            Language::Italian => format!("Impossibile importare l'elenco delle mod: {err}"),
+            Language::Japanese => format!("Modリストのインポートに失敗しました: {err}"),
+            Language::Korean => format!("모드 목록 가져오기 실패: {err}"),
+            Language::Arabic => format!("فشل استيراد قائمة الإضافات: {err}"),
+            Language::Polish => format!("Nie udało się zaimportować listy modów: {err}"),
+            Language::Vietnamese => format!("Không thể nhập danh sách mod: {err}"),
+        }
+    }
+
+    pub fn mods_url_input_label(self) -> &'static str {
+        self.pick(
+            "Install from URL:",
+            "Встановити за URL:",
+            "Instalar desde URL:",
+            "Installer depuis une URL :",
+            "Von URL installieren:",
+            "Instalar via URL:",
+            "从网址安装：",
+            "URL से इंस्टॉल करें:",
+            "Установить по URL:",
+            "URL'den yükle:",
+            "Installa da URL:",
+            "URLからインストール:",
+            "URL로 설치:",
+            "التثبيت من رابط:",
+            "Zainstaluj z adresu URL:",
+            "Cài đặt từ URL:",
+        )
+    }
+
+    pub fn mods_url_input_hint(self) -> &'static str {
+        self.pick(
+            "https://... (direct download or CurseForge project link)",
+            "https://... (пряме посилання або сторінка проєкту CurseForge)",
+            "https://... (descarga directa o enlace del proyecto en CurseForge)",
+            "https://... (téléchargement direct ou lien de projet CurseForge)",
+            "https://... (Direktdownload oder CurseForge-Projektlink)",
+            "https://... (download direto ou link do projeto no CurseForge)",
+            "https://...（直接下载链接或 CurseForge 项目链接）",
+            "https://... (सीधा डाउनलोड या CurseForge प्रोजेक्ट लिंक)",
+            "https://... (прямая ссылка или ссылка на проект CurseForge)",
+            "https://... (doğrudan indirme veya CurseForge proje bağlantısı)",
+            "https://... (download diretto o link al progetto CurseForge)",
+            "https://...（直接ダウンロードまたはCurseForgeプロジェクトリンク）",
+            "https://... (직접 다운로드 또는 CurseForge 프로젝트 링크)",
+            "https://... (رابط تنزيل مباشر أو رابط مشروع CurseForge)",
+            "https://... (bezpośredni link do pobrania lub link do projektu CurseForge)",
+            "https://... (liên kết tải trực tiếp hoặc liên kết dự án CurseForge)",
+        )
+    }
+
+    pub fn mods_url_install_button(self) -> &'static str {
+        self.pick(
+            "Install",
+            "Встановити",
+            "Instalar",
+            "Installer",
+            "Installieren",
+            "Instalar",
+            "安装",
+            "इंस्टॉल करें",
+            "Установить",
+            "Yükle",
+            "Installa",
+            "インストール",
+            "설치",
+            "تثبيت",
+            "Zainstaluj",
+            "Cài đặt",
+        )
+    }
+
+    pub fn mods_url_install_success(self, name: &str) -> String {
+        match self.language {
+            Language::English => format!("Installed {name} from URL"),
+            Language::Ukrainian => format!("Встановлено {name} за URL"),
+            Language::Spanish => format!("{name} instalado desde URL"),
+            Language::French => format!("{name} installé depuis l'URL"),
+            Language::German => format!("{name} von URL installiert"),
+            Language::Portuguese => format!("{name} instalado via URL"),
+            Language::Chinese => format!("已通过网址安装 {name}"),
+            Language::Hindi => format!("URL से {name} इंस्टॉल किया गया"),
+            Language::Russian => format!("{name} установлен по URL"),
+            Language::Turkish => format!("{name} URL'den yüklendi"),
+            Language::Italian => format!("{name} installato da URL"),
+            Language::Japanese => format!("{name} をURLからインストールしました"),
+            Language::Korean => format!("{name}을(를) URL에서 설치했습니다"),
+            Language::Arabic => format!("تم تثبيت {name} من الرابط"),
+            Language::Polish => format!("Zainstalowano {name} z adresu URL"),
+            Language::Vietnamese => format!("Đã cài đặt {name} từ URL"),
+        }
+    }
+
+    pub fn mods_url_install_failed(self, err: &str) -> String {
+        match self.language {
+            Language::English => format!("Failed to install from URL: {err}"),
+            Language::Ukrainian => format!("Не вдалося встановити за URL: {err}"),
+            Language::Spanish => format!("No se pudo instalar desde la URL: {err}"),
+            Language::French => format!("Échec de l'installation depuis l'URL : {err}"),
+            Language::German => format!("Installation von URL fehlgeschlagen: {err}"),
+            Language::Portuguese => format!("Falha ao instalar via URL: {err}"),
+            Language::Chinese => format!("从网址安装失败: {err}"),
+            Language::Hindi => format!("URL से इंस्टॉल करने में विफल: {err}"),
+            Language::Russian => format!("Не удалось установить по URL: {err}"),
+            Language::Turkish => format!("URL'den yükleme başarısız: {err}"),
+            Language::Italian => format!("Installazione da URL non riuscita: {err}"),
+            Language::Japanese => format!("URLからのインストールに失敗しました: {err}"),
+            Language::Korean => format!("URL에서 설치하지 못했습니다: {err}"),
+            Language::Arabic => format!("فشل التثبيت من الرابط: {err}"),
+            Language::Polish => format!("Nie udało się zainstalować z adresu URL: {err}"),
+            Language::Vietnamese => format!("Không thể cài đặt từ URL: {err}"),
+        }
+    }
+
+    pub fn mods_repair_clean(self) -> &'static str {
+        self.pick(
+            "Mods and files are in sync, nothing to repair.",
+            "Моди та файли синхронізовані, нічого виправляти.",
+            "Los mods y los archivos están sincronizados, no hay nada que reparar.",
+            "Les mods et les fichiers sont synchronisés, rien à réparer.",
+            "Mods und Dateien sind synchron, nichts zu reparieren.",
+            "Mods e arquivos estão sincronizados, nada para reparar.",
+            "模组与文件已同步，无需修复。",
+            "मॉड्स और फ़ाइलें समन्वित हैं, ठीक करने के लिए कुछ नहीं है।",
+            "Моды и файлы синхронизированы, исправлять нечего.",
+            "Modlar ve dosyalar eşitlenmiş durumda, onarılacak bir şey yok.",
+            "Mod e file sono sincronizzati, nulla da riparare.",
+            "Modとファイルは同期しています。修復の必要はありません。",
+            "모드와 파일이 동기화되어 있습니다. 복구할 항목이 없습니다.",
+            "الإضافات والملفات متزامنة، لا شيء لإصلاحه.",
+            "Mody i pliki są zsynchronizowane, nie ma nic do naprawy.",
+            "Mod và tệp đã đồng bộ, không có gì cần sửa.",
+        )
+    }
+
+    pub fn mods_repair_summary(self, removed_missing: usize, untracked_files: usize) -> String {
+        match self.language {
+            Language::English => format!(
+                "Repair complete: removed {removed_missing} missing mod entries, found {untracked_files} untracked files in the mods folder."
+            ),
+            Language::Ukrainian => format!(
+                "Відновлення завершено: видалено {removed_missing} відсутніх записів модів, знайдено {untracked_files} невідстежуваних файлів у папці модів."
+            ),
+            Language::Spanish => format!(
+                "Reparación completa: se quitaron {removed_missing} entradas de mods faltantes, se encontraron {untracked_files} archivos no rastreados en la carpeta de mods."
+            ),
+            Language::French => format!(
+                "Réparation terminée : {removed_missing} entrées de mods manquantes supprimées, {untracked_files} fichiers non suivis trouvés dans le dossier des mods."
+            ),
+            Language::German => format!(
+                "Reparatur abgeschlossen: {removed_missing} fehlende Mod-Einträge entfernt, {untracked_files} nicht erfasste Dateien im Mods-Ordner gefunden."
+            ),
+            Language::Portuguese => format!(
+                "Reparo concluído: {removed_missing} entradas de mods ausentes removidas, {untracked_files} arquivos não rastreados encontrados na pasta de mods."
+            ),
+            Language::Chinese => format!(
+                "修复完成：移除了 {removed_missing} 个缺失的模组记录，在模组文件夹中发现 {untracked_files} 个未跟踪的文件。"
+            ),
+            Language::Hindi => format!(
+                "मरम्मत पूर्ण: {removed_missing} लापता मॉड प्रविष्टियाँ हटाई गईं, मॉड फ़ोल्डर में {untracked_files} अज्ञात फ़ाइलें मिलीं।"
+            ),
+            Language::Russian => format!(
+                "Восстановление завершено: удалено {removed_missing} отсутствующих записей модов, найдено {untracked_files} неотслеживаемых файлов в папке модов."
+            ),
+            Language::Turkish => format!(
+                "Onarım tamamlandı: {removed_missing} eksik mod kaydı kaldırıldı, mod klasöründe {untracked_files} izlenmeyen dosya bulundu."
+            ),
+            Language::Italian => format!(
+                "Riparazione completata: rimosse {removed_missing} voci di mod mancanti, trovati {untracked_files} file non tracciati nella cartella delle mod."
+            ),
+            Language::Japanese => format!(
+                "修復が完了しました: 見つからないModエントリを{removed_missing}件削除し、Modフォルダ内で未追跡のファイルを{untracked_files}件見つけました。"
+            ),
+            Language::Korean => format!(
+                "복구 완료: 누락된 모드 항목 {removed_missing}개를 제거했고, 모드 폴더에서 추적되지 않은 파일 {untracked_files}개를 찾았습니다."
+            ),
+            Language::Arabic => format!(
+                "اكتمل الإصلاح: تمت إزالة {removed_missing} من إدخالات الإضافات المفقودة، وتم العثور على {untracked_files} ملفًا غير متتبع في مجلد الإضافات."
+            ),
+            Language::Polish => format!(
+                "Naprawa zakończona: usunięto {removed_missing} brakujących wpisów modów, znaleziono {untracked_files} nieśledzonych plików w folderze modów."
+            ),
+            Language::Vietnamese => format!(
+                "Sửa chữa hoàn tất: đã xóa {removed_missing} mục mod bị thiếu, tìm thấy {untracked_files} tệp không được theo dõi trong thư mục mod."
+            ),
+        }
+    }
+
     pub fn news_heading(self) -> &'static str {
         self.pick(
             "News",
@@ -1513,6 +5197,11 @@ impl I18n {
             "Новости",
             "Haberler",
             "Notizie",
+            "ニュース",
+            "뉴스",
+            "الأخبار",
+            "Aktualności",
+            "Tin tức",
         )
     }
 
@@ -1529,6 +5218,11 @@ impl I18n {
             "Новости недоступны.",
             "Haber yok.",
             "Nessuna notizia disponibile.",
+            "利用可能なニュースはありません。",
+            "사용 가능한 뉴스가 없습니다.",
+            "لا توجد أخبار متاحة.",
+            "Brak dostępnych aktualności.",
+            "Không có tin tức nào.",
         )
     }
 
@@ -1545,6 +5239,95 @@ impl I18n {
             Language::Russian => format!("Доступно обновление: {version}"),
             Language::Turkish => format!("Güncelleme mevcut: {version}"),
             Language::Italian => format!("Aggiornamento disponibile: {version}"),
+            Language::Japanese => format!("更新が利用可能です: {version}"),
+            Language::Korean => format!("업데이트 사용 가능: {version}"),
+            Language::Arabic => format!("يتوفر تحديث: {version}"),
+            Language::Polish => format!("Dostępna aktualizacja: {version}"),
+            Language::Vietnamese => format!("Có bản cập nhật: {version}"),
         }
     }
+
+    pub fn logs_heading(self) -> &'static str {
+        self.pick(
+            "Logs",
+            "Журнали",
+            "Registros",
+            "Journaux",
+            "Protokolle",
+            "Registos",
+            "日志",
+            "लॉग",
+            "Журналы",
+            "Günlükler",
+            "Registri",
+            "ログ",
+            "로그",
+            "السجلات",
+            "Dzienniki",
+            "Nhật ký",
+        )
+    }
+
+    pub fn view_logs_button(self) -> &'static str {
+        self.pick(
+            "View logs",
+            "Переглянути журнали",
+            "Ver registros",
+            "Voir les journaux",
+            "Protokolle anzeigen",
+            "Ver registos",
+            "查看日志",
+            "लॉग देखें",
+            "Просмотреть журналы",
+            "Günlükleri görüntüle",
+            "Visualizza registri",
+            "ログを表示",
+            "로그 보기",
+            "عرض السجلات",
+            "Wyświetl dzienniki",
+            "Xem nhật ký",
+        )
+    }
+
+    pub fn open_logs_folder_button(self) -> &'static str {
+        self.pick(
+            "Open logs folder",
+            "Відкрити теку журналів",
+            "Abrir carpeta de registros",
+            "Ouvrir le dossier des journaux",
+            "Protokollordner öffnen",
+            "Abrir pasta de registos",
+            "打开日志文件夹",
+            "लॉग फ़ोल्डर खोलें",
+            "Открыть папку журналов",
+            "Günlük klasörünü aç",
+            "Apri cartella registri",
+            "ログフォルダを開く",
+            "로그 폴더 열기",
+            "فتح مجلد السجلات",
+            "Otwórz folder dzienników",
+            "Mở thư mục nhật ký",
+        )
+    }
+
+    pub fn logs_empty(self) -> &'static str {
+        self.pick(
+            "No log output yet.",
+            "Журналів ще немає.",
+            "Aún no hay registros.",
+            "Aucun journal pour le moment.",
+            "Noch keine Protokolle.",
+            "Ainda não há registos.",
+            "暂无日志。",
+            "अभी तक कोई लॉग नहीं है।",
+            "Журналов пока нет.",
+            "Henüz günlük yok.",
+            "Nessun registro ancora.",
+            "ログはまだありません。",
+            "아직 로그가 없습니다.",
+            "لا توجد سجلات حتى الآن.",
+            "Brak jeszcze danych dziennika.",
+            "Chưa có dữ liệu nhật ký.",
+        )
+    }
 }
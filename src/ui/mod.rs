@@ -1,7 +1,8 @@
 use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::time::{Duration, Instant};
 use std::{
     fs,
     path::{Path, PathBuf},
@@ -11,7 +12,8 @@ use eframe::egui::{
     self, Align, Color32, CornerRadius, FontData, FontDefinitions, FontFamily, Frame, Layout,
     Margin, RichText, Stroke, Vec2, epaint::Shadow,
 };
-use log::{error, warn};
+use futures_util::future::join_all;
+use log::{error, info, warn};
 use rfd::FileDialog;
 use scraper::{Html, Selector};
 use serde::Deserialize;
@@ -19,28 +21,74 @@ use tokio::runtime::{Builder, Runtime};
 use tokio::sync::{Mutex, mpsc};
 
 use crate::engine::LauncherEngine;
+use crate::engine::read_last_played;
 use crate::engine::state::{AppState, AuthMode, UserAction};
 use crate::env;
-use crate::mods::{CurseForgeMod, InstalledMod, ModAuthor};
-use crate::process::ProcessLauncher;
+use crate::mods::queue::{ModDownloadOutcome, ModDownloadQueue};
+use crate::mods::{CurseForgeMod, InstalledMod, ModAuthor, ReconcileReport};
+use crate::process::{self, GarbageCollector, ProcessLauncher};
+use crate::profile::{self, DEFAULT_PROFILE_NAME, Profile};
 use crate::storage::StorageManager;
+use crate::tray::{Tray, TrayEvent};
 use crate::updater::{self, UpdateStatus};
+use crate::util::{format_size, tokenize_launch_args};
 
 mod i18n;
 use self::i18n::{I18n, Language};
 
 const NEWS_PATH: &str = "assets/news.json";
-const NEWS_URL: &str = "https://hytale.com/news";
 const NEWS_MAX_ITEMS: usize = 6;
+/// Caps how many messages a `sync_*` method drains from its channel in a
+/// single frame, so a burst of rapid updates (the download loop can emit
+/// many in quick succession) can't stall the UI thread. Anything left over
+/// is picked up next frame; hitting the cap also triggers an immediate
+/// repaint so the backlog drains promptly instead of waiting for the idle
+/// poll interval.
+const MAX_SYNC_MESSAGES_PER_FRAME: usize = 64;
 const NEWS_PREVIEW_FALLBACK_EN: &str = "Read more on hytale.com.";
 const PLAYER_NAME_FILE: &str = "player_name.txt";
 const SELECTED_VERSION_FILE: &str = "selected_version.txt";
+const ACTIVE_PROFILE_FILE: &str = "active_profile.txt";
+const EXTRA_LAUNCH_ARGS_FILE: &str = "extra_launch_args.txt";
+const MAX_MEMORY_FILE: &str = "max_memory_gb.txt";
+const MIN_MEMORY_FILE: &str = "min_memory_gb.txt";
+const GC_FILE: &str = "gc.txt";
+const TRAY_ENABLED_FILE: &str = "tray_enabled.txt";
+const MINIMIZE_TO_TRAY_FILE: &str = "minimize_to_tray.txt";
+const FORCE_CONTINUOUS_REPAINT_FILE: &str = "force_continuous_repaint.txt";
+const USE_SYSTEM_JAVA_FILE: &str = "use_system_java.txt";
+/// How often to repaint while idle (no spinner on screen, no transfer in
+/// flight). Short enough that a background result still shows up promptly,
+/// but far from the 60 fps a visible [`egui::Spinner`] demands.
+const IDLE_REPAINT_INTERVAL: Duration = Duration::from_millis(250);
+const ONBOARDED_FILE: &str = "onboarded.txt";
+/// How long the "Copied" feedback label replaces the copy-link button for.
+const COPY_FEEDBACK_DURATION: std::time::Duration = std::time::Duration::from_secs(2);
+const MOD_DENSITY_FILE: &str = "mod_density.txt";
+const UI_SCALE_FILE: &str = "ui_scale.txt";
+const UI_SCALE_MIN: f32 = 0.8;
+const UI_SCALE_MAX: f32 = 2.0;
+const UI_SCALE_DEFAULT: f32 = 1.0;
+const READ_NEWS_FILE: &str = "read_news.txt";
+/// Caps how many read news URLs are persisted, so the file can't grow
+/// forever as the feed accumulates posts over the launcher's lifetime.
+const READ_NEWS_CAP: usize = 200;
 const DEFAULT_PLAYER_NAME: &str = "Player";
+const MAX_PLAYER_NAME_LEN: usize = 16;
 const DIAGNOSTICS_REPORT_HEIGHT: f32 = 720.0;
+const LOG_VIEWER_MAX_LINES: usize = 500;
+const LOG_VIEWER_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+const MOD_SEARCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(400);
 const NOTO_SANS_FONT_ID: &str = "noto_sans_regular";
 const NOTO_SANS_FONT_CN_ID: &str = "noto_sans_sc_regular";
+/// Arabic-capable fallback font. DejaVu Sans covers the basic Arabic block,
+/// so Arabic renders as legible individual letterforms; egui/epaint don't do
+/// Arabic shaping, so joined/contextual forms aren't available regardless of
+/// which Arabic font is bundled.
+const DEJAVU_SANS_FONT_ID: &str = "dejavu_sans_regular";
 const NOTO_SANS_REGULAR: &[u8] = include_bytes!("../../assets/NotoSans-Regular.ttf");
 const NOTO_SANS_SC_REGULAR: &[u8] = include_bytes!("../../assets/NotoSansSC-Regular.ttf");
+const DEJAVU_SANS_REGULAR: &[u8] = include_bytes!("../../assets/DejaVuSans.ttf");
 const CTA_HEIGHT: f32 = 34.0;
 const CONTROL_BUTTON_WIDTH: f32 = 168.0;
 
@@ -130,8 +178,59 @@ fn tint(color: Color32, alpha: u8) -> Color32 {
     Color32::from_rgba_premultiplied(color.r(), color.g(), color.b(), alpha)
 }
 
-const LOCALE_LANGUAGE_CODES: [(&[&str], Language); 11] = [
+// egui has no bidi text shaping, so Arabic glyphs still render left-to-right
+// within a line; this only mirrors widget order and label alignment, which
+// covers the request's "at minimum" bar for RTL support.
+fn row_layout(i18n: I18n) -> Layout {
+    if i18n.language().is_rtl() {
+        Layout::right_to_left(Align::Center)
+    } else {
+        Layout::left_to_right(Align::Center)
+    }
+}
+
+/// Gates hidden troubleshooting actions (currently: revealing the download
+/// cache) behind an opt-in env var, so they don't clutter the UI for most
+/// users but are one flag away for anyone debugging a support report.
+fn debug_mode_enabled() -> bool {
+    std::env::var("HRS_LAUNCHER_DEBUG").is_ok_and(|v| v == "1")
+}
+
+fn is_mod_archive_path(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("zip") || ext.eq_ignore_ascii_case("jar"))
+}
+
+fn paint_drop_overlay(ctx: &egui::Context, colors: &ThemePalette, i18n: I18n, can_install_mods: bool) {
+    let screen = ctx.content_rect();
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Foreground,
+        egui::Id::new("mod_drop_overlay"),
+    ));
+    painter.rect_filled(screen, 0.0, tint(colors.bg, 200));
+    let message = if can_install_mods {
+        i18n.mods_drop_hint()
+    } else {
+        i18n.mods_drop_disabled()
+    };
+    painter.text(
+        screen.center(),
+        egui::Align2::CENTER_CENTER,
+        message,
+        egui::FontId::proportional(22.0),
+        colors.text_primary,
+    );
+}
+
+// Korean isn't detected here: no bundled font covers hangul yet, so a
+// system locale of ko/kor falls through to English rather than silently
+// selecting a language that would render as missing-glyph boxes. See
+// setup_custom_fonts.
+const LOCALE_LANGUAGE_CODES: [(&[&str], Language); 15] = [
     (&["zh", "zho", "chi"], Language::Chinese),
+    (&["ja", "jpn"], Language::Japanese),
+    (&["ar", "ara"], Language::Arabic),
     (&["hi", "hin"], Language::Hindi),
     (&["ru", "rus"], Language::Russian),
     (&["tr", "tur"], Language::Turkish),
@@ -141,6 +240,8 @@ const LOCALE_LANGUAGE_CODES: [(&[&str], Language); 11] = [
     (&["fr", "fra", "fre"], Language::French),
     (&["de", "deu", "ger"], Language::German),
     (&["pt", "por"], Language::Portuguese),
+    (&["pl", "pol"], Language::Polish),
+    (&["vi", "vie"], Language::Vietnamese),
     (&["en", "eng"], Language::English),
 ];
 
@@ -192,6 +293,14 @@ mod tests {
             ("it_IT.UTF-8", Language::Italian),
             ("ua-UA", Language::Ukrainian),
             ("eng_US", Language::English),
+            ("ja_JP.UTF-8", Language::Japanese),
+            ("jpn_JP", Language::Japanese),
+            ("ar_SA.UTF-8", Language::Arabic),
+            ("ara_EG", Language::Arabic),
+            ("pl_PL.UTF-8", Language::Polish),
+            ("pol_PL", Language::Polish),
+            ("vi_VN.UTF-8", Language::Vietnamese),
+            ("vie_VN", Language::Vietnamese),
         ];
 
         for (token, expected) in samples {
@@ -201,7 +310,7 @@ mod tests {
 
     #[test]
     fn ignores_unknown_language_tokens() {
-        assert_eq!(parse_locale_token("pl_PL"), None);
+        assert_eq!(parse_locale_token("xx_XX"), None);
     }
 }
 
@@ -255,6 +364,60 @@ enum ModSort {
     Name,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum ModRecency {
+    #[default]
+    Any,
+    LastMonth,
+    Last3Months,
+    Last6Months,
+    LastYear,
+}
+
+impl ModRecency {
+    /// How many months back counts as "recent" for this option, or `None`
+    /// for [`ModRecency::Any`] (no recency filtering).
+    fn months(self) -> Option<i64> {
+        match self {
+            ModRecency::Any => None,
+            ModRecency::LastMonth => Some(1),
+            ModRecency::Last3Months => Some(3),
+            ModRecency::Last6Months => Some(6),
+            ModRecency::LastYear => Some(12),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum ModDensity {
+    #[default]
+    Comfortable,
+    Compact,
+}
+
+impl ModDensity {
+    fn key(self) -> &'static str {
+        match self {
+            ModDensity::Comfortable => "comfortable",
+            ModDensity::Compact => "compact",
+        }
+    }
+
+    fn from_key(key: &str) -> Self {
+        match key {
+            "compact" => ModDensity::Compact,
+            _ => ModDensity::Comfortable,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InstalledModSort {
+    Name,
+    InstallDate,
+    Size,
+}
+
 fn load_news_from_file() -> Vec<NewsItem> {
     let path = Path::new(NEWS_PATH);
     if let Ok(raw) = fs::read_to_string(path)
@@ -266,7 +429,7 @@ fn load_news_from_file() -> Vec<NewsItem> {
 }
 
 fn load_player_name_from_file() -> String {
-    let path = env::default_app_dir().join(PLAYER_NAME_FILE);
+    let path = env::config_dir().join(PLAYER_NAME_FILE);
     if let Ok(raw) = fs::read_to_string(path) {
         let trimmed = raw.trim();
         if !trimmed.is_empty() {
@@ -277,7 +440,7 @@ fn load_player_name_from_file() -> String {
 }
 
 fn load_selected_version_from_file() -> Option<u32> {
-    let path = env::default_app_dir().join(SELECTED_VERSION_FILE);
+    let path = env::config_dir().join(SELECTED_VERSION_FILE);
     if let Ok(raw) = fs::read_to_string(path) {
         let trimmed = raw.trim();
         if trimmed.is_empty() {
@@ -288,17 +451,257 @@ fn load_selected_version_from_file() -> Option<u32> {
     None
 }
 
+fn load_max_memory_gb_from_file() -> Option<u32> {
+    let path = env::config_dir().join(MAX_MEMORY_FILE);
+    let raw = fs::read_to_string(path).ok()?;
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    trimmed.parse::<u32>().ok().filter(|value| *value > 0)
+}
+
+fn load_min_memory_gb_from_file() -> Option<u32> {
+    let path = env::config_dir().join(MIN_MEMORY_FILE);
+    let raw = fs::read_to_string(path).ok()?;
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    trimmed.parse::<u32>().ok().filter(|value| *value > 0)
+}
+
+fn save_max_memory_gb_to_file(value: Option<u32>) -> Result<(), String> {
+    let path = env::config_dir().join(MAX_MEMORY_FILE);
+    match value {
+        Some(gb) => {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|err| format!("failed to create max memory dir: {err}"))?;
+            }
+            crate::util::write_atomic(&path, gb.to_string().as_bytes())
+                .map_err(|err| format!("failed to save max memory: {err}"))
+        }
+        None => {
+            if fs::metadata(&path).is_ok() {
+                fs::remove_file(&path).map_err(|err| format!("failed to clear max memory: {err}"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+fn save_min_memory_gb_to_file(value: Option<u32>) -> Result<(), String> {
+    let path = env::config_dir().join(MIN_MEMORY_FILE);
+    match value {
+        Some(gb) => {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|err| format!("failed to create min memory dir: {err}"))?;
+            }
+            crate::util::write_atomic(&path, gb.to_string().as_bytes())
+                .map_err(|err| format!("failed to save min memory: {err}"))
+        }
+        None => {
+            if fs::metadata(&path).is_ok() {
+                fs::remove_file(&path).map_err(|err| format!("failed to clear min memory: {err}"))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Validates that an explicit min/max memory override is sane: positive,
+/// min not above max, and neither exceeding the host's physical memory.
+fn validate_memory_settings(
+    min_gb: Option<u32>,
+    max_gb: Option<u32>,
+    i18n: I18n,
+) -> Option<String> {
+    if min_gb == Some(0) || max_gb == Some(0) {
+        return Some(i18n.memory_settings_error_not_positive().to_owned());
+    }
+    if let (Some(min), Some(max)) = (min_gb, max_gb)
+        && min > max
+    {
+        return Some(i18n.memory_settings_error_min_exceeds_max().to_owned());
+    }
+    if let Some(system_gb) = process::system_memory_gb() {
+        let exceeds = [min_gb, max_gb]
+            .into_iter()
+            .flatten()
+            .any(|value| value > system_gb);
+        if exceeds {
+            return Some(i18n.memory_settings_error_exceeds_system(system_gb));
+        }
+    }
+    None
+}
+
+fn load_gc_from_file() -> GarbageCollector {
+    let path = env::config_dir().join(GC_FILE);
+    fs::read_to_string(path)
+        .map(|raw| GarbageCollector::from_key(raw.trim()))
+        .unwrap_or_default()
+}
+
+fn save_gc_to_file(gc: GarbageCollector) -> Result<(), String> {
+    let path = env::config_dir().join(GC_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("failed to create garbage collector dir: {err}"))?;
+    }
+    crate::util::write_atomic(&path, gc.key().as_bytes())
+        .map_err(|err| format!("failed to save garbage collector: {err}"))
+}
+
+fn load_tray_enabled_from_file() -> bool {
+    let path = env::config_dir().join(TRAY_ENABLED_FILE);
+    fs::read_to_string(path).is_ok_and(|raw| raw.trim() == "true")
+}
+
+fn save_tray_enabled_to_file(enabled: bool) -> Result<(), String> {
+    let path = env::config_dir().join(TRAY_ENABLED_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("failed to create tray settings dir: {err}"))?;
+    }
+    crate::util::write_atomic(&path, if enabled { b"true" } else { b"false" })
+        .map_err(|err| format!("failed to save tray setting: {err}"))
+}
+
+fn load_minimize_to_tray_from_file() -> bool {
+    let path = env::config_dir().join(MINIMIZE_TO_TRAY_FILE);
+    fs::read_to_string(path).is_ok_and(|raw| raw.trim() == "true")
+}
+
+fn save_minimize_to_tray_to_file(enabled: bool) -> Result<(), String> {
+    let path = env::config_dir().join(MINIMIZE_TO_TRAY_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("failed to create tray settings dir: {err}"))?;
+    }
+    crate::util::write_atomic(&path, if enabled { b"true" } else { b"false" })
+        .map_err(|err| format!("failed to save minimize-to-tray setting: {err}"))
+}
+
+fn load_force_continuous_repaint_from_file() -> bool {
+    let path = env::config_dir().join(FORCE_CONTINUOUS_REPAINT_FILE);
+    fs::read_to_string(path).is_ok_and(|raw| raw.trim() == "true")
+}
+
+fn save_force_continuous_repaint_to_file(enabled: bool) -> Result<(), String> {
+    let path = env::config_dir().join(FORCE_CONTINUOUS_REPAINT_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("failed to create repaint settings dir: {err}"))?;
+    }
+    crate::util::write_atomic(&path, if enabled { b"true" } else { b"false" })
+        .map_err(|err| format!("failed to save repaint setting: {err}"))
+}
+
+fn load_use_system_java_from_file() -> bool {
+    let path = env::config_dir().join(USE_SYSTEM_JAVA_FILE);
+    fs::read_to_string(path).is_ok_and(|raw| raw.trim() == "true")
+}
+
+fn save_use_system_java_to_file(enabled: bool) -> Result<(), String> {
+    let path = env::config_dir().join(USE_SYSTEM_JAVA_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("failed to create use-system-java settings dir: {err}"))?;
+    }
+    crate::util::write_atomic(&path, if enabled { b"true" } else { b"false" })
+        .map_err(|err| format!("failed to save use-system-java setting: {err}"))
+}
+
+/// Missing file means either a fresh install (show onboarding) or an
+/// existing user updating from a version that predates this flag (skip
+/// it, since they already have a game installed).
+fn load_onboarded_from_file() -> bool {
+    let path = env::config_dir().join(ONBOARDED_FILE);
+    match fs::read_to_string(path) {
+        Ok(raw) => raw.trim() == "true",
+        Err(_) => game_already_installed(),
+    }
+}
+
+fn save_onboarded_to_file() -> Result<(), String> {
+    let path = env::config_dir().join(ONBOARDED_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|err| format!("failed to create onboarding dir: {err}"))?;
+    }
+    crate::util::write_atomic(&path, b"true")
+        .map_err(|err| format!("failed to save onboarding flag: {err}"))
+}
+
+fn load_mod_density_from_file() -> ModDensity {
+    let path = env::config_dir().join(MOD_DENSITY_FILE);
+    fs::read_to_string(path)
+        .map(|raw| ModDensity::from_key(raw.trim()))
+        .unwrap_or_default()
+}
+
+fn save_mod_density_to_file(density: ModDensity) -> Result<(), String> {
+    let path = env::config_dir().join(MOD_DENSITY_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("failed to create mod density dir: {err}"))?;
+    }
+    crate::util::write_atomic(&path, density.key().as_bytes())
+        .map_err(|err| format!("failed to save mod density: {err}"))
+}
+
+fn load_ui_scale_from_file() -> f32 {
+    let path = env::config_dir().join(UI_SCALE_FILE);
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|raw| raw.trim().parse::<f32>().ok())
+        .map(|scale| scale.clamp(UI_SCALE_MIN, UI_SCALE_MAX))
+        .unwrap_or(UI_SCALE_DEFAULT)
+}
+
+fn save_ui_scale_to_file(scale: f32) -> Result<(), String> {
+    let path = env::config_dir().join(UI_SCALE_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("failed to create ui scale dir: {err}"))?;
+    }
+    crate::util::write_atomic(&path, scale.to_string().as_bytes())
+        .map_err(|err| format!("failed to save ui scale: {err}"))
+}
+
+fn load_read_news_from_file() -> Vec<String> {
+    let path = env::config_dir().join(READ_NEWS_FILE);
+    fs::read_to_string(path)
+        .map(|raw| raw.lines().map(str::to_owned).collect())
+        .unwrap_or_default()
+}
+
+fn save_read_news_to_file(urls: &[String]) -> Result<(), String> {
+    let path = env::config_dir().join(READ_NEWS_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("failed to create read-news dir: {err}"))?;
+    }
+    crate::util::write_atomic(&path, urls.join("\n").as_bytes())
+        .map_err(|err| format!("failed to save read news: {err}"))
+}
+
 fn save_player_name_to_file(name: &str) -> Result<(), String> {
-    let path = env::default_app_dir().join(PLAYER_NAME_FILE);
+    let path = env::config_dir().join(PLAYER_NAME_FILE);
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)
             .map_err(|err| format!("failed to create player name dir: {err}"))?;
     }
-    fs::write(path, name.as_bytes()).map_err(|err| format!("failed to save player name: {err}"))
+    crate::util::write_atomic(&path, name.as_bytes())
+        .map_err(|err| format!("failed to save player name: {err}"))
 }
 
 fn save_selected_version_to_file(version: Option<u32>) -> Result<(), String> {
-    let path = env::default_app_dir().join(SELECTED_VERSION_FILE);
+    let path = env::config_dir().join(SELECTED_VERSION_FILE);
     match version {
         Some(value) => {
             if let Some(parent) = path.parent() {
@@ -306,7 +709,7 @@ fn save_selected_version_to_file(version: Option<u32>) -> Result<(), String> {
                     .map_err(|err| format!("failed to create selected version dir: {err}"))?;
             }
             let contents = value.to_string();
-            fs::write(&path, contents.as_bytes())
+            crate::util::write_atomic(&path, contents.as_bytes())
                 .map_err(|err| format!("failed to save selected version: {err}"))
         }
         None => {
@@ -320,6 +723,38 @@ fn save_selected_version_to_file(version: Option<u32>) -> Result<(), String> {
     }
 }
 
+fn load_active_profile_from_file() -> Option<String> {
+    let path = env::config_dir().join(ACTIVE_PROFILE_FILE);
+    let raw = fs::read_to_string(path).ok()?;
+    let trimmed = raw.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_owned())
+}
+
+fn save_active_profile_to_file(name: &str) -> Result<(), String> {
+    let path = env::config_dir().join(ACTIVE_PROFILE_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("failed to create active profile dir: {err}"))?;
+    }
+    crate::util::write_atomic(&path, name.as_bytes())
+        .map_err(|err| format!("failed to save active profile: {err}"))
+}
+
+fn load_extra_launch_args_from_file() -> String {
+    let path = env::config_dir().join(EXTRA_LAUNCH_ARGS_FILE);
+    fs::read_to_string(path).unwrap_or_default()
+}
+
+fn save_extra_launch_args_to_file(raw: &str) -> Result<(), String> {
+    let path = env::config_dir().join(EXTRA_LAUNCH_ARGS_FILE);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("failed to create extra launch args dir: {err}"))?;
+    }
+    crate::util::write_atomic(&path, raw.as_bytes())
+        .map_err(|err| format!("failed to save extra launch arguments: {err}"))
+}
+
 fn sanitize_player_name(name: &str) -> String {
     let trimmed = name.trim();
     if trimmed.is_empty() {
@@ -329,6 +764,20 @@ fn sanitize_player_name(name: &str) -> String {
     }
 }
 
+// Mirrors the Mojang-style username rules Hytale is expected to enforce.
+fn validate_player_name(name: &str, i18n: I18n) -> Option<String> {
+    if name.chars().count() > MAX_PLAYER_NAME_LEN {
+        return Some(i18n.player_name_too_long(MAX_PLAYER_NAME_LEN));
+    }
+    let allowed = name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if !allowed {
+        return Some(i18n.player_name_invalid_chars().to_owned());
+    }
+    None
+}
+
 fn format_downloads(count: i64) -> String {
     let count = count.max(0) as f64;
     if count >= 1_000_000_000.0 {
@@ -342,6 +791,89 @@ fn format_downloads(count: i64) -> String {
     }
 }
 
+/// Formats the gap between `then` and `now` as a short, human-readable
+/// relative string (e.g. "just now", "5m ago", "2d ago"). Clamped to zero so
+/// a clock skew that puts `then` slightly in the future doesn't print a
+/// negative duration.
+fn format_relative_time(
+    then: chrono::DateTime<chrono::Utc>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> String {
+    let seconds = now.signed_duration_since(then).num_seconds().max(0);
+    if seconds < 60 {
+        "just now".to_owned()
+    } else if seconds < 3_600 {
+        format!("{}m ago", seconds / 60)
+    } else if seconds < 86_400 {
+        format!("{}h ago", seconds / 3_600)
+    } else if seconds < 2_592_000 {
+        format!("{}d ago", seconds / 86_400)
+    } else if seconds < 31_536_000 {
+        format!("{}mo ago", seconds / 2_592_000)
+    } else {
+        format!("{}y ago", seconds / 31_536_000)
+    }
+}
+
+#[cfg(test)]
+mod play_time_tests {
+    use super::format_play_time;
+
+    #[test]
+    fn formats_hours_and_minutes() {
+        assert_eq!(format_play_time(0), "0m");
+        assert_eq!(format_play_time(59), "0m");
+        assert_eq!(format_play_time(45 * 60), "45m");
+        assert_eq!(format_play_time(12 * 3_600 + 34 * 60), "12h 34m");
+    }
+}
+
+#[cfg(test)]
+mod relative_time_tests {
+    use super::format_relative_time;
+
+    #[test]
+    fn formats_buckets_from_seconds_to_years() {
+        let now = chrono::DateTime::<chrono::Utc>::UNIX_EPOCH + chrono::Duration::days(1_000);
+        let samples = [
+            (30, "just now"),
+            (90, "1m ago"),
+            (3_700, "1h ago"),
+            (90_000, "1d ago"),
+            (2_600_000, "1mo ago"),
+            (31_600_000, "1y ago"),
+        ];
+        for (seconds_ago, expected) in samples {
+            let then = now - chrono::Duration::seconds(seconds_ago);
+            assert_eq!(format_relative_time(then, now), expected);
+        }
+    }
+
+    #[test]
+    fn clamps_future_timestamps_to_just_now() {
+        let now = chrono::DateTime::<chrono::Utc>::UNIX_EPOCH;
+        let then = now + chrono::Duration::seconds(120);
+        assert_eq!(format_relative_time(then, now), "just now");
+    }
+}
+
+/// Formats accumulated seconds as "12h 34m" (or just "34m" under an hour).
+fn format_play_time(total_seconds: u64) -> String {
+    let hours = total_seconds / 3_600;
+    let minutes = (total_seconds % 3_600) / 60;
+    if hours > 0 {
+        format!("{hours}h {minutes:02}m")
+    } else {
+        format!("{minutes}m")
+    }
+}
+
+fn parse_installed_at(installed: &InstalledMod) -> chrono::DateTime<chrono::Utc> {
+    chrono::DateTime::parse_from_rfc3339(&installed.installed_at)
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+        .unwrap_or_else(|_| chrono::DateTime::<chrono::Utc>::UNIX_EPOCH)
+}
+
 fn format_mod_date(date: &str) -> Option<String> {
     let trimmed = date.trim();
     if trimmed.is_empty() {
@@ -472,6 +1004,37 @@ fn link_from_element(element: &scraper::element_ref::ElementRef<'_>) -> Option<S
         .next()
 }
 
+const DATE_SELECTORS: &[&str] = &[
+    "time",
+    ".date",
+    ".post-date",
+    ".news-card__date",
+    ".post__details__date",
+];
+
+/// Extracts a publish date from a news card, preferring a `<time>` element's
+/// `datetime` attribute (machine-readable) over its visible text, and
+/// normalizes it with [`format_mod_date`].
+fn extract_news_date(card: &scraper::element_ref::ElementRef<'_>) -> Option<String> {
+    for selector in DATE_SELECTORS {
+        let Ok(selector) = Selector::parse(selector) else {
+            continue;
+        };
+        let Some(element) = card.select(&selector).next() else {
+            continue;
+        };
+        let raw = element
+            .value()
+            .attr("datetime")
+            .map(str::to_owned)
+            .unwrap_or_else(|| element_text(element));
+        if let Some(date) = format_mod_date(&raw) {
+            return Some(date);
+        }
+    }
+    None
+}
+
 fn normalize_news_url(href: &str) -> Option<String> {
     if href.starts_with("http://") || href.starts_with("https://") {
         return Some(href.to_owned());
@@ -568,11 +1131,14 @@ fn parse_news_from_html(body: &str) -> Vec<NewsItem> {
             } else {
                 summary
             };
+            let date = extract_news_date(&card);
 
             items.push(NewsItem {
                 title: truncate_text(&title, 80),
                 preview: truncate_text(&summary, 160),
                 url,
+                source: None,
+                date,
             });
 
             if items.len() >= NEWS_MAX_ITEMS {
@@ -606,10 +1172,13 @@ fn parse_news_from_html(body: &str) -> Vec<NewsItem> {
             continue;
         }
 
+        let date = extract_news_date(&link);
         items.push(NewsItem {
             title: truncate_text(&title, 80),
             preview: NEWS_PREVIEW_FALLBACK_EN.into(),
             url,
+            source: None,
+            date,
         });
 
         if items.len() >= NEWS_MAX_ITEMS {
@@ -620,34 +1189,252 @@ fn parse_news_from_html(body: &str) -> Vec<NewsItem> {
     items
 }
 
-async fn fetch_news_from_web() -> Result<Vec<NewsItem>, String> {
+/// A news feed to merge into `news`. Currently only the official site is
+/// configured, but sources are fetched independently and merged by URL, so
+/// an RSS/Atom feed or a community-run JSON feed can be added here without
+/// touching the merge logic.
+struct NewsSource {
+    name: &'static str,
+    url: &'static str,
+}
+
+fn news_sources() -> Vec<NewsSource> {
+    vec![NewsSource { name: "Hytale", url: crate::endpoints::news_url() }]
+}
+
+#[cfg(test)]
+mod news_parsing_tests {
+    use super::*;
+
+    const CARD_LAYOUT: &str = include_str!("../../tests/fixtures/news_cards.html");
+    const PLAIN_LINKS: &str = include_str!("../../tests/fixtures/news_plain_links.html");
+    const MALFORMED: &str = include_str!("../../tests/fixtures/news_malformed.html");
+
+    #[test]
+    fn card_layout_extracts_titles_previews_and_normalized_urls() {
+        let items = parse_news_from_html(CARD_LAYOUT);
+        assert_eq!(items[0].title, "Alpha Update");
+        assert_eq!(items[0].preview, "Patch notes for the springtime event.");
+        assert_eq!(items[0].url, "https://hytale.com/news/alpha-update");
+        assert_eq!(items[0].date.as_deref(), Some("2024-01-15"));
+    }
+
+    #[test]
+    fn card_layout_dedupes_by_url_and_caps_at_news_max_items() {
+        let items = parse_news_from_html(CARD_LAYOUT);
+        assert_eq!(items.len(), NEWS_MAX_ITEMS);
+        let urls: Vec<&str> = items.iter().map(|item| item.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://hytale.com/news/alpha-update",
+                "https://hytale.com/news/beta-update",
+                "https://hytale.com/news/gamma-update",
+                "https://hytale.com/news/delta-update",
+                "https://hytale.com/news/epsilon-update",
+                "https://hytale.com/news/zeta-update",
+            ]
+        );
+        // The eighth card (eta-update) is never reached because the cap is
+        // hit right after the sixth unique card is pushed.
+        assert!(!urls.contains(&"https://hytale.com/news/eta-update"));
+    }
+
+    #[test]
+    fn plain_link_fallback_extracts_titles_and_skips_news_index_links() {
+        let items = parse_news_from_html(PLAIN_LINKS);
+        let urls: Vec<&str> = items.iter().map(|item| item.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://hytale.com/news/foo-update",
+                "https://hytale.com/news/bar-update",
+            ]
+        );
+        assert_eq!(items[0].title, "Foo Update");
+        assert_eq!(items[0].preview, NEWS_PREVIEW_FALLBACK_EN);
+    }
+
+    #[test]
+    fn malformed_html_returns_empty_vec_without_panicking() {
+        let items = parse_news_from_html(MALFORMED);
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn strips_duplicated_title_prefix_and_trailing_punctuation() {
+        let cleaned = clean_news_preview(
+            "Alpha Update",
+            "Alpha Update - Patch notes for the springtime event.",
+        );
+        assert_eq!(cleaned, "Patch notes for the springtime event.");
+    }
+
+    #[test]
+    fn splits_camel_case_words_only_past_the_short_word_threshold() {
+        // "Hytale" is long enough (word_len > 2 before the uppercase letter)
+        // for the lowercase-to-uppercase transition to insert a space.
+        assert_eq!(
+            clean_news_preview("", "PlayHytaleToday"),
+            "Play Hytale Today"
+        );
+    }
+
+    #[test]
+    fn does_not_split_short_acronyms() {
+        // "PvP" and "v2" are short enough (word_len <= 2 at the transition)
+        // that the camelCase heuristic must not insert a space.
+        assert_eq!(clean_news_preview("", "PvP is live in v2"), "PvP is live in v2");
+    }
+
+    #[test]
+    fn inserts_space_at_digit_to_uppercase_boundary() {
+        assert_eq!(clean_news_preview("", "Version2Released"), "Version2 Released");
+    }
+
+    #[test]
+    fn inserts_space_at_sentence_end_to_uppercase_boundary() {
+        assert_eq!(
+            clean_news_preview("", "First sentence.Second sentence!Third one?Fourth."),
+            "First sentence. Second sentence! Third one? Fourth."
+        );
+    }
+}
+
+async fn fetch_news_from_source(source: &NewsSource) -> Result<Vec<NewsItem>, String> {
     let client = reqwest::Client::new();
-    let resp = client
-        .get(NEWS_URL)
-        .header("User-Agent", "HytaleLauncher/0.1")
-        .send()
-        .await
-        .map_err(|err| err.to_string())?;
+    let resp = crate::util::send_with_retry(|| {
+        client.get(source.url).header("User-Agent", "HytaleLauncher/0.1")
+    })
+    .await?;
     if !resp.status().is_success() {
         return Err(format!("News request failed: {}", resp.status()));
     }
     let body = resp.text().await.map_err(|err| err.to_string())?;
-    let items = parse_news_from_html(&body);
+    let mut items = parse_news_from_html(&body);
     if items.is_empty() {
         return Err("No news entries found.".into());
     }
+    for item in &mut items {
+        item.source = Some(source.name.to_owned());
+    }
     Ok(items)
 }
 
-fn build_runtime() -> Arc<Runtime> {
-    match Runtime::new() {
-        Ok(rt) => Arc::new(rt),
-        Err(err) => {
-            warn!(
-                "ui: failed to create multithreaded runtime ({}); trying single-threaded runtime",
-                err
-            );
-            match Builder::new_current_thread().enable_all().build() {
+/// Fetches every configured source concurrently and merges the results,
+/// deduped by URL. A source that fails doesn't fail the whole refresh: its
+/// error is logged and the other sources' results are still returned. Only
+/// errors out if every source failed.
+async fn fetch_news_from_web() -> Result<Vec<NewsItem>, String> {
+    let sources = news_sources();
+    let fetches = sources.iter().map(fetch_news_from_source);
+    let results = join_all(fetches).await;
+
+    let mut seen = HashSet::new();
+    let mut merged = Vec::new();
+    let mut failures = Vec::new();
+    for (source, result) in sources.iter().zip(results) {
+        match result {
+            Ok(items) => {
+                for item in items {
+                    if seen.insert(item.url.clone()) {
+                        merged.push(item);
+                    }
+                }
+            }
+            Err(err) => {
+                warn!("news: source '{}' failed: {err}", source.name);
+                failures.push(format!("{}: {err}", source.name));
+            }
+        }
+    }
+
+    if merged.is_empty() {
+        return Err(failures.join("; "));
+    }
+
+    // Newest-first when a date is known; undated items keep their original
+    // relative order and sort after every dated item.
+    merged.sort_by(|a, b| match (&a.date, &b.date) {
+        (Some(a_date), Some(b_date)) => b_date.cmp(a_date),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+
+    Ok(merged)
+}
+
+/// Fetches a news article page and extracts its main body text, for the
+/// inline "read more" view. Falls back to an error (and the caller falls
+/// back to opening the browser) if no recognizable article body is found.
+async fn fetch_news_article(url: &str) -> Result<String, String> {
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(url)
+        .header("User-Agent", "HytaleLauncher/0.1")
+        .send()
+        .await
+        .map_err(|err| err.to_string())?;
+    if !resp.status().is_success() {
+        return Err(format!("Article request failed: {}", resp.status()));
+    }
+    let body = resp.text().await.map_err(|err| err.to_string())?;
+    parse_news_article_from_html(&body)
+}
+
+fn parse_news_article_from_html(body: &str) -> Result<String, String> {
+    let document = Html::parse_document(body);
+
+    let body_selectors = [
+        ".post__details__body",
+        "article .content",
+        "article",
+        ".post-content",
+        ".article-content",
+        ".news-article",
+        "main",
+    ];
+
+    let paragraph_selector = Selector::parse("p").ok();
+    for selector in &body_selectors {
+        let Ok(selector) = Selector::parse(selector) else {
+            continue;
+        };
+        if let Some(container) = document.select(&selector).next() {
+            let paragraphs: Vec<String> = paragraph_selector
+                .as_ref()
+                .map(|p_sel| {
+                    container
+                        .select(p_sel)
+                        .map(element_text)
+                        .filter(|text| !text.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default();
+            let content = if paragraphs.is_empty() {
+                element_text(container)
+            } else {
+                paragraphs.join("\n\n")
+            };
+            if !content.is_empty() {
+                return Ok(content);
+            }
+        }
+    }
+
+    Err("could not find article content".into())
+}
+
+fn build_runtime() -> Arc<Runtime> {
+    match Runtime::new() {
+        Ok(rt) => Arc::new(rt),
+        Err(err) => {
+            warn!(
+                "ui: failed to create multithreaded runtime ({}); trying single-threaded runtime",
+                err
+            );
+            match Builder::new_current_thread().enable_all().build() {
                 Ok(rt) => Arc::new(rt),
                 Err(fallback_err) => {
                     error!(
@@ -665,6 +1452,10 @@ pub struct LauncherApp {
     runtime: Arc<Runtime>,
     engine: Arc<Mutex<LauncherEngine>>,
     cancel_flag: Arc<AtomicBool>,
+    /// PID of the currently running game process, or `0` if none. Shared
+    /// with the engine, which sets it on launch and clears it once the
+    /// exit-detection task observes the process has ended.
+    running_game_pid: Arc<AtomicU32>,
     updates_rx: mpsc::UnboundedReceiver<AppState>,
     updates_tx: mpsc::UnboundedSender<AppState>,
     state: AppState,
@@ -678,27 +1469,107 @@ pub struct LauncherApp {
     player_name: String,
     player_name_error: Option<String>,
     auth_mode: AuthMode,
+    extra_launch_args: String,
+    extra_launch_args_error: Option<String>,
+    max_memory_gb: Option<u32>,
+    min_memory_gb: Option<u32>,
+    max_memory_input: String,
+    min_memory_input: String,
+    memory_settings_error: Option<String>,
+    gc: GarbageCollector,
+    current_profile: String,
+    available_profiles: Vec<String>,
     available_versions: Vec<u32>,
     selected_version: Option<u32>,
+    installed_version: Option<u32>,
+    /// Timestamp of the last successful game launch, refreshed whenever the
+    /// app settles back into [`AppState::Idle`]. `None` if the game has
+    /// never been launched from this install.
+    last_played: Option<chrono::DateTime<chrono::Utc>>,
     version_input: String,
     version_loading: bool,
     version_fetch_error: Option<String>,
     version_input_error: Option<String>,
     diagnostics: Option<String>,
     show_diagnostics_modal: bool,
+    /// Whether the first-run onboarding modal is currently shown; set from
+    /// [`load_onboarded_from_file`] and cleared for good once dismissed.
+    show_onboarding: bool,
+    java_test_output: Option<String>,
+    /// Path to the most recently created crash report zip, shown next to the
+    /// "Create crash report" button so the user knows where to find it.
+    crash_report_path: Option<String>,
     show_uninstall_confirm: bool,
+    uninstall_keep_jre: bool,
+    uninstall_keep_user_data: bool,
+    show_reinstall_confirm: bool,
+    reinstall_clear_cache: bool,
+    /// Shown when the user tries to close the window while a download or
+    /// install is in flight, so a multi-GB download isn't lost to an
+    /// accidental close.
+    show_close_confirm: bool,
+    /// Set once the user has confirmed the close prompt, so the next close
+    /// request is let through instead of being intercepted again.
+    confirmed_exit: bool,
+    pending_downgrade: Option<(UserAction, u32, u32)>,
     mod_query: String,
     mod_sort: ModSort,
     mod_category_filter: Option<String>,
+    /// Raw text from the "minimum downloads" filter box; parsed on the fly
+    /// when building `visible_mods` so an invalid or empty value just means
+    /// no filtering, without a separate apply step.
+    mod_min_downloads_input: String,
+    mod_recency_filter: ModRecency,
     mod_results: Vec<CurseForgeMod>,
     mod_loading: bool,
     mod_error: Option<String>,
+    live_mod_search: bool,
+    mod_query_dirty_at: Option<Instant>,
+    last_searched_mod_query: String,
+    mod_search_generation: u64,
+    /// Index into the currently visible (filtered/sorted) mod results,
+    /// highlighted and driven by arrow-key navigation in `render_mods`.
+    selected_mod_index: Option<usize>,
+    /// Mod id and timestamp of the most recent "copy page URL" click, used to
+    /// show a transient "Copied" label in place of the copy button.
+    copied_mod_url_id: Option<(i32, Instant)>,
+    /// Stacked transient notifications shown in a corner of the window;
+    /// pushed via [`LauncherApp::push_toast`] and drawn by
+    /// [`LauncherApp::render_toasts`].
+    toasts: Vec<Toast>,
     installed_mods: Vec<InstalledMod>,
+    /// Update-availability check and "What's new" changelog for installed
+    /// mods, keyed by installed mod id and fetched lazily the first time an
+    /// installed mod's card is drawn. `Ok(None)` means the installed file is
+    /// already the latest one, so no expander is shown for that mod;
+    /// `Ok(Some(text))` is the latest file's changelog (an empty string
+    /// means the fetch succeeded but CurseForge had nothing to show).
+    mod_changelog_cache: HashMap<String, Result<Option<String>, String>>,
+    mod_changelog_loading: HashSet<String>,
     installed_loading: bool,
     installed_error: Option<String>,
     removing_mod: Option<String>,
+    pending_remove_mod: Option<(String, String)>,
+    removing_all_mods: bool,
+    show_remove_all_mods_confirm: bool,
+    reconciling_mods: bool,
+    mod_reconcile_report: Option<ReconcileReport>,
+    exporting_mods: bool,
+    importing_mod_list: bool,
+    installed_mod_filter: String,
+    installed_mod_sort: InstalledModSort,
     mod_updates_rx: mpsc::UnboundedReceiver<ModUpdate>,
     mod_updates_tx: mpsc::UnboundedSender<ModUpdate>,
+    mod_download_queue: ModDownloadQueue,
+    mod_download_outcomes_rx: mpsc::UnboundedReceiver<ModDownloadOutcome>,
+    downloading_mod_ids: HashSet<i32>,
+    mod_download_completed: u32,
+    mod_download_total: u32,
+    mod_download_error: Option<String>,
+    mod_dependency_warning: Option<String>,
+    mod_show_file_error: Option<String>,
+    mod_url_input: String,
+    installing_mod_url: bool,
     news_updates_rx: mpsc::UnboundedReceiver<NewsUpdate>,
     news_updates_tx: mpsc::UnboundedSender<NewsUpdate>,
     version_updates_rx: mpsc::UnboundedReceiver<VersionUpdate>,
@@ -707,6 +1578,35 @@ pub struct LauncherApp {
     updater_loading: bool,
     updater_updates_rx: mpsc::UnboundedReceiver<UpdaterUpdate>,
     updater_updates_tx: mpsc::UnboundedSender<UpdaterUpdate>,
+    last_updater_check: Instant,
+    show_logs_modal: bool,
+    log_lines: Vec<String>,
+    last_log_refresh: Option<Instant>,
+    tray_enabled: bool,
+    tray: Option<Tray>,
+    minimize_to_tray: bool,
+    expanded_news_url: Option<String>,
+    news_article_cache: HashMap<String, String>,
+    news_article_loading: bool,
+    news_article_error: Option<String>,
+    /// URLs the user has already opened, oldest-read first, capped at
+    /// [`READ_NEWS_CAP`]. Used to draw the unread dot and heading badge.
+    read_news: Vec<String>,
+    ui_scale: f32,
+    mod_density: ModDensity,
+    /// Forces a full 60 fps repaint even while idle. Off by default so the
+    /// app backs off to [`IDLE_REPAINT_INTERVAL`] when nothing is animating;
+    /// exists as an escape hatch for diagnosing UI responsiveness issues.
+    force_continuous_repaint: bool,
+    /// Whether the engine may use a compatible `java` found on PATH instead
+    /// of downloading the bundled runtime. Persisted, and pushed to the
+    /// engine via [`UserAction::SetAllowSystemJava`] on every change and once
+    /// at startup so it's in effect before the first bootstrap.
+    use_system_java: bool,
+    /// Result of a one-time, synchronous PATH scan for a compatible system
+    /// `java`, computed at startup for display next to the checkbox above.
+    /// `None` means no compatible java was found on PATH.
+    detected_system_java: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -714,12 +1614,22 @@ struct NewsItem {
     title: String,
     preview: String,
     url: String,
+    #[serde(default)]
+    source: Option<String>,
+    #[serde(default)]
+    date: Option<String>,
 }
 
 #[derive(Debug)]
 enum ModUpdate {
-    Results(Vec<CurseForgeMod>),
-    Error(String),
+    Results {
+        results: Vec<CurseForgeMod>,
+        generation: u64,
+    },
+    Error {
+        message: String,
+        generation: u64,
+    },
     Installed(Vec<InstalledMod>),
     InstalledError(String),
     Imported {
@@ -730,12 +1640,27 @@ enum ModUpdate {
         id: String,
         error: Option<String>,
     },
+    RemovedAll {
+        error: Option<String>,
+    },
+    Reconciled(Result<ReconcileReport, String>),
+    Exported(Result<String, String>),
+    ManifestImported(Result<Vec<i32>, String>),
+    UrlInstalled(Box<Result<(InstalledMod, Vec<i32>), String>>),
+    Changelog {
+        mod_id: String,
+        /// `Ok(None)` means the installed file is already the latest one
+        /// (no update available); `Ok(Some(text))` is the latest file's
+        /// changelog (possibly empty).
+        result: Result<Option<String>, String>,
+    },
 }
 
 #[derive(Debug)]
 enum NewsUpdate {
     Results(Vec<NewsItem>),
     Error(String),
+    Article { url: String, result: Result<String, String> },
 }
 
 #[derive(Debug)]
@@ -757,6 +1682,23 @@ fn section_frame(colors: &ThemePalette) -> Frame {
         .inner_margin(Margin::same(14))
 }
 
+fn game_already_installed() -> bool {
+    let game_dir = env::game_latest_dir();
+    let client_path = if cfg!(target_os = "windows") {
+        game_dir.join("Client").join("HytaleClient.exe")
+    } else if cfg!(target_os = "macos") {
+        game_dir
+            .join("Client")
+            .join("Hytale.app")
+            .join("Contents")
+            .join("MacOS")
+            .join("HytaleClient")
+    } else {
+        game_dir.join("Client").join("HytaleClient")
+    };
+    client_path.exists() || game_dir.exists()
+}
+
 fn elevated_frame(colors: &ThemePalette) -> Frame {
     Frame::new()
         .fill(colors.surface_elev)
@@ -771,6 +1713,36 @@ fn elevated_frame(colors: &ThemePalette) -> Frame {
         })
 }
 
+/// How severe a [`Toast`] is, controlling which [`ThemePalette`] color it's
+/// drawn with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    fn color(self, colors: &ThemePalette) -> Color32 {
+        match self {
+            Severity::Info => colors.info,
+            Severity::Warning => colors.warning,
+            Severity::Error => colors.danger,
+        }
+    }
+}
+
+/// A transient notification shown in the corner of the window; see
+/// [`LauncherApp::push_toast`] and [`LauncherApp::render_toasts`].
+struct Toast {
+    message: String,
+    shown_at: Instant,
+    severity: Severity,
+}
+
+/// How long a toast stays on screen before it's dropped.
+const TOAST_DURATION: std::time::Duration = std::time::Duration::from_secs(5);
+
 fn setup_custom_fonts(ctx: &egui::Context, language: Language) {
     let mut fonts = FontDefinitions::default();
     fonts.font_data.insert(
@@ -781,11 +1753,24 @@ fn setup_custom_fonts(ctx: &egui::Context, language: Language) {
         NOTO_SANS_FONT_CN_ID.to_owned(),
         FontData::from_static(NOTO_SANS_SC_REGULAR).into(),
     );
+    fonts.font_data.insert(
+        DEJAVU_SANS_FONT_ID.to_owned(),
+        FontData::from_static(DEJAVU_SANS_REGULAR).into(),
+    );
 
-    let (primary, fallback) = if language == Language::Chinese {
-        (NOTO_SANS_FONT_CN_ID, NOTO_SANS_FONT_ID)
-    } else {
-        (NOTO_SANS_FONT_ID, NOTO_SANS_FONT_CN_ID)
+    // NotoSansSC covers the CJK Unified Ideographs shared by Japanese kanji and
+    // Korean hanja, and also covers hiragana/katakana, so Japanese renders
+    // correctly through it. It has no hangul coverage at all, which is why
+    // Korean isn't offered as a selectable language yet (see the language
+    // picker in render_settings) even though its translations already exist.
+    // Arabic renders through DejaVu Sans instead, the only bundled font with
+    // any Arabic coverage.
+    let (primary, fallback) = match language {
+        Language::Chinese | Language::Japanese | Language::Korean => {
+            (NOTO_SANS_FONT_CN_ID, NOTO_SANS_FONT_ID)
+        }
+        Language::Arabic => (DEJAVU_SANS_FONT_ID, NOTO_SANS_FONT_ID),
+        _ => (NOTO_SANS_FONT_ID, NOTO_SANS_FONT_CN_ID),
     };
 
     fonts
@@ -890,13 +1875,26 @@ fn refresh_fonts_if_needed(app: &mut LauncherApp, ctx: &egui::Context) {
 
 impl LauncherApp {
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        env::migrate_legacy_config_files();
+        crate::desktop_entry::ensure_desktop_entry();
+
         let runtime = build_runtime();
 
         let cancel_flag = Arc::new(AtomicBool::new(false));
+        // Seed with a game that's already running (started outside the
+        // launcher, or left over from a previous launcher session) so the
+        // Play button and "game running" indicator are correct immediately.
+        let running_game_pid = Arc::new(AtomicU32::new(
+            crate::process::find_running_game_pid().unwrap_or(0),
+        ));
+        if let Some(pid) = std::num::NonZeroU32::new(running_game_pid.load(Ordering::SeqCst)) {
+            crate::engine::spawn_external_game_watcher(pid.get(), running_game_pid.clone());
+        }
         let engine = LauncherEngine::new(
             StorageManager::new(),
             ProcessLauncher::new(),
             cancel_flag.clone(),
+            running_game_pid.clone(),
         );
         let engine = Arc::new(Mutex::new(engine));
         let (tx, rx) = mpsc::unbounded_channel();
@@ -904,6 +1902,19 @@ impl LauncherApp {
         let (news_tx, news_rx) = mpsc::unbounded_channel();
         let (version_tx, version_rx) = mpsc::unbounded_channel();
         let (updater_tx, updater_rx) = mpsc::unbounded_channel();
+        let (mod_download_tx, mod_download_rx) = mpsc::unbounded_channel();
+
+        let mod_download_queue = ModDownloadQueue::new();
+        let queue_engine = engine.clone();
+        let queue_for_pump = mod_download_queue.clone();
+        let queue_rt = runtime.clone();
+        queue_rt.spawn(async move {
+            let service = {
+                let locked = queue_engine.lock().await;
+                locked.mods_service()
+            };
+            queue_for_pump.run(service, mod_download_tx).await;
+        });
 
         let bootstrap_engine = engine.clone();
         let bootstrap_tx = tx.clone();
@@ -913,7 +1924,31 @@ impl LauncherApp {
             locked.load_local_state(&bootstrap_tx).await;
         });
         let saved_version = load_selected_version_from_file();
-        let version_input = saved_version
+
+        let mut available_profiles = profile::list_profiles();
+        if available_profiles.is_empty() {
+            available_profiles.push(DEFAULT_PROFILE_NAME.to_owned());
+        }
+        let current_profile =
+            load_active_profile_from_file().unwrap_or_else(|| DEFAULT_PROFILE_NAME.to_owned());
+        if !available_profiles.contains(&current_profile) {
+            available_profiles.push(current_profile.clone());
+        }
+        available_profiles.sort();
+
+        let loaded_profile = profile::load_profile(&current_profile);
+        let player_name = loaded_profile
+            .as_ref()
+            .map(|saved| saved.player_name.clone())
+            .unwrap_or_else(load_player_name_from_file);
+        let auth_mode = loaded_profile
+            .as_ref()
+            .map(|saved| saved.auth_mode)
+            .unwrap_or(AuthMode::Offline);
+        let selected_version = loaded_profile
+            .as_ref()
+            .map_or(saved_version, |saved| saved.selected_version);
+        let version_input = selected_version
             .map(|version| version.to_string())
             .unwrap_or_default();
         let language = detect_system_language();
@@ -923,6 +1958,7 @@ impl LauncherApp {
             runtime,
             engine,
             cancel_flag,
+            running_game_pid,
             updates_rx: rx,
             updates_tx: tx,
             state: AppState::Initialising,
@@ -933,30 +1969,86 @@ impl LauncherApp {
             news: load_news_from_file(),
             news_loading: false,
             news_error: None,
-            player_name: load_player_name_from_file(),
+            player_name,
             player_name_error: None,
-            auth_mode: AuthMode::Offline,
+            auth_mode,
+            extra_launch_args: load_extra_launch_args_from_file(),
+            extra_launch_args_error: None,
+            max_memory_gb: load_max_memory_gb_from_file(),
+            min_memory_gb: load_min_memory_gb_from_file(),
+            max_memory_input: load_max_memory_gb_from_file()
+                .map(|v| v.to_string())
+                .unwrap_or_default(),
+            min_memory_input: load_min_memory_gb_from_file()
+                .map(|v| v.to_string())
+                .unwrap_or_default(),
+            memory_settings_error: None,
+            gc: load_gc_from_file(),
+            current_profile,
+            available_profiles,
             available_versions: Vec::new(),
-            selected_version: saved_version,
+            selected_version,
+            installed_version: None,
+            last_played: read_last_played(),
             version_input,
             version_loading: false,
             version_fetch_error: None,
             version_input_error: None,
             diagnostics: None,
             show_diagnostics_modal: false,
+            show_onboarding: !load_onboarded_from_file(),
+            java_test_output: None,
+            crash_report_path: None,
             show_uninstall_confirm: false,
+            uninstall_keep_jre: false,
+            uninstall_keep_user_data: false,
+            show_reinstall_confirm: false,
+            reinstall_clear_cache: false,
+            show_close_confirm: false,
+            confirmed_exit: false,
+            pending_downgrade: None,
             mod_query: String::new(),
             mod_sort: ModSort::Downloads,
             mod_category_filter: None,
+            mod_min_downloads_input: String::new(),
+            mod_recency_filter: ModRecency::Any,
             mod_results: Vec::new(),
             mod_loading: false,
             mod_error: None,
+            live_mod_search: true,
+            mod_query_dirty_at: None,
+            last_searched_mod_query: String::new(),
+            mod_search_generation: 0,
+            selected_mod_index: None,
+            copied_mod_url_id: None,
+            toasts: Vec::new(),
             installed_mods: Vec::new(),
+            mod_changelog_cache: HashMap::new(),
+            mod_changelog_loading: HashSet::new(),
             installed_loading: false,
             installed_error: None,
             removing_mod: None,
+            pending_remove_mod: None,
+            removing_all_mods: false,
+            show_remove_all_mods_confirm: false,
+            reconciling_mods: false,
+            mod_reconcile_report: None,
+            exporting_mods: false,
+            importing_mod_list: false,
+            installed_mod_filter: String::new(),
+            installed_mod_sort: InstalledModSort::Name,
             mod_updates_rx: mod_rx,
             mod_updates_tx: mod_tx,
+            mod_download_queue,
+            mod_download_outcomes_rx: mod_download_rx,
+            downloading_mod_ids: HashSet::new(),
+            mod_download_completed: 0,
+            mod_download_total: 0,
+            mod_download_error: None,
+            mod_dependency_warning: None,
+            mod_show_file_error: None,
+            mod_url_input: String::new(),
+            installing_mod_url: false,
             news_updates_rx: news_rx,
             news_updates_tx: news_tx,
             version_updates_rx: version_rx,
@@ -965,15 +2057,106 @@ impl LauncherApp {
             updater_loading: false,
             updater_updates_rx: updater_rx,
             updater_updates_tx: updater_tx,
+            last_updater_check: Instant::now(),
+            show_logs_modal: false,
+            log_lines: Vec::new(),
+            last_log_refresh: None,
+            tray_enabled: load_tray_enabled_from_file(),
+            tray: None,
+            minimize_to_tray: load_minimize_to_tray_from_file(),
+            expanded_news_url: None,
+            news_article_cache: HashMap::new(),
+            news_article_loading: false,
+            news_article_error: None,
+            read_news: load_read_news_from_file(),
+            ui_scale: load_ui_scale_from_file(),
+            mod_density: load_mod_density_from_file(),
+            force_continuous_repaint: load_force_continuous_repaint_from_file(),
+            use_system_java: load_use_system_java_from_file(),
+            detected_system_java: crate::jre::detect_system_java(crate::jre::min_system_java_major()),
         };
 
         app.start_news_fetch();
         app.start_version_discovery();
         app.start_updater_check();
         app.start_load_installed_mods();
+        app.sync_tray();
+        if app.use_system_java {
+            app.trigger_action(UserAction::SetAllowSystemJava(true));
+        }
         app
     }
 
+    /// Builds or tears down the tray icon to match `self.tray_enabled`,
+    /// logging (rather than failing) if the platform or environment can't
+    /// provide one — the tray is a convenience, not a requirement.
+    fn sync_tray(&mut self) {
+        if !self.tray_enabled {
+            self.tray = None;
+            return;
+        }
+        if self.tray.is_some() {
+            return;
+        }
+        match Tray::build(&crate::app_icon()) {
+            Ok(tray) => self.tray = Some(tray),
+            Err(err) => warn!("failed to create tray icon: {err}"),
+        }
+    }
+
+    fn play_enabled(&self) -> bool {
+        matches!(self.state, AppState::ReadyToPlay { .. }) && !self.game_running()
+    }
+
+    /// `true` while a game process launched by this launcher is still
+    /// alive, per the PID tracked by the exit-detection task in the engine.
+    fn game_running(&self) -> bool {
+        self.running_game_pid.load(Ordering::SeqCst) != 0
+    }
+
+    /// Validates the pending player-name/launch-arg edits and, if they're
+    /// clean, fires `ClickPlay`. Shared by the tray, the main Play button,
+    /// and the "force launch another instance" override.
+    fn trigger_play(&mut self) {
+        let player_name = self.commit_player_name();
+        self.commit_extra_launch_args();
+        if self.player_name_error.is_none()
+            && self.extra_launch_args_error.is_none()
+            && self.memory_settings_error.is_none()
+            && let Ok(extra_args) = tokenize_launch_args(&self.extra_launch_args)
+        {
+            self.trigger_action(UserAction::ClickPlay {
+                player_name,
+                auth_mode: self.auth_mode,
+                profile: self.current_profile.clone(),
+                extra_args,
+                max_memory_gb: self.max_memory_gb,
+                min_memory_gb: self.min_memory_gb,
+                gc: self.gc,
+            });
+        }
+    }
+
+    fn poll_tray_events(&mut self, ctx: &egui::Context) {
+        let Some(tray) = &self.tray else { return };
+        tray.set_play_enabled(self.play_enabled());
+        let Some(event) = tray.poll_event() else { return };
+        match event {
+            TrayEvent::Play => self.trigger_play(),
+            TrayEvent::CheckForUpdates => {
+                self.trigger_action(UserAction::CheckForUpdates {
+                    target_version: self.selected_version,
+                });
+            }
+            TrayEvent::OpenGameFolder => {
+                self.trigger_action(UserAction::OpenGameFolder);
+            }
+            TrayEvent::Quit => {
+                ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+            }
+        }
+    }
+
     fn colors(&self) -> ThemePalette {
         self.theme.palette()
     }
@@ -983,20 +2166,7 @@ impl LauncherApp {
     }
 
     fn game_installed(&self) -> bool {
-        let game_dir = env::game_latest_dir();
-        let client_path = if cfg!(target_os = "windows") {
-            game_dir.join("Client").join("HytaleClient.exe")
-        } else if cfg!(target_os = "macos") {
-            game_dir
-                .join("Client")
-                .join("Hytale.app")
-                .join("Contents")
-                .join("MacOS")
-                .join("HytaleClient")
-        } else {
-            game_dir.join("Client").join("HytaleClient")
-        };
-        client_path.exists() || game_dir.exists()
+        game_already_installed()
     }
 
     fn trigger_action(&self, action: UserAction) {
@@ -1012,6 +2182,26 @@ impl LauncherApp {
         });
     }
 
+    /// Triggers a `CheckForUpdates`/`DownloadGame` action, first asking for
+    /// confirmation if it would downgrade the already-installed version:
+    /// older game versions aren't guaranteed to load saves made with a
+    /// newer one.
+    fn trigger_version_change(&mut self, action: UserAction) {
+        let target_version = match &action {
+            UserAction::DownloadGame { target_version } | UserAction::CheckForUpdates { target_version } => {
+                *target_version
+            }
+            _ => None,
+        };
+        if let (Some(target), Some(installed)) = (target_version, self.current_ready_version())
+            && target < installed
+        {
+            self.pending_downgrade = Some((action, installed, target));
+            return;
+        }
+        self.trigger_action(action);
+    }
+
     fn start_mod_search(&mut self) {
         let trimmed = self.mod_query.trim();
         if trimmed.is_empty() || self.mod_loading {
@@ -1019,6 +2209,11 @@ impl LauncherApp {
         }
         self.mod_error = None;
         self.mod_loading = true;
+        self.mod_query_dirty_at = None;
+        self.selected_mod_index = None;
+        self.last_searched_mod_query = trimmed.to_owned();
+        self.mod_search_generation += 1;
+        let generation = self.mod_search_generation;
         let query = trimmed.to_owned();
         let tx = self.mod_updates_tx.clone();
         let engine = self.engine.clone();
@@ -1031,15 +2226,43 @@ impl LauncherApp {
             let result = service.search(&query, 0).await;
             match result {
                 Ok(resp) => {
-                    let _ = tx.send(ModUpdate::Results(resp.data));
+                    let _ = tx.send(ModUpdate::Results {
+                        results: resp.data,
+                        generation,
+                    });
                 }
                 Err(err) => {
-                    let _ = tx.send(ModUpdate::Error(err));
+                    let _ = tx.send(ModUpdate::Error {
+                        message: err,
+                        generation,
+                    });
                 }
             }
         });
     }
 
+    fn poll_live_mod_search(&mut self, ctx: &egui::Context) {
+        if !self.live_mod_search {
+            return;
+        }
+        let Some(dirty_at) = self.mod_query_dirty_at else {
+            return;
+        };
+        let trimmed = self.mod_query.trim();
+        if trimmed.is_empty() || trimmed == self.last_searched_mod_query {
+            self.mod_query_dirty_at = None;
+            return;
+        }
+        let elapsed = dirty_at.elapsed();
+        if elapsed >= MOD_SEARCH_DEBOUNCE {
+            if !self.mod_loading {
+                self.start_mod_search();
+            }
+        } else {
+            ctx.request_repaint_after(MOD_SEARCH_DEBOUNCE - elapsed);
+        }
+    }
+
     fn start_load_installed_mods(&mut self) {
         if self.installed_loading {
             return;
@@ -1096,6 +2319,121 @@ impl LauncherApp {
         });
     }
 
+    fn start_remove_all_mods(&mut self) {
+        if self.installed_loading {
+            return;
+        }
+        self.removing_all_mods = true;
+        self.installed_loading = true;
+        self.installed_error = None;
+        let tx = self.mod_updates_tx.clone();
+        let engine = self.engine.clone();
+        let rt = self.runtime.clone();
+        rt.spawn(async move {
+            let service = {
+                let locked = engine.lock().await;
+                locked.mods_service()
+            };
+            let result = service.remove_all_mods().await;
+            let update = ModUpdate::RemovedAll {
+                error: result.err(),
+            };
+            let _ = tx.send(update);
+        });
+    }
+
+    fn start_reconcile_mods(&mut self) {
+        if self.reconciling_mods {
+            return;
+        }
+        self.reconciling_mods = true;
+        self.mod_reconcile_report = None;
+        let tx = self.mod_updates_tx.clone();
+        let engine = self.engine.clone();
+        let rt = self.runtime.clone();
+        rt.spawn(async move {
+            let service = {
+                let locked = engine.lock().await;
+                locked.mods_service()
+            };
+            let result = service.reconcile().await;
+            let _ = tx.send(ModUpdate::Reconciled(result));
+        });
+    }
+
+    fn start_export_mods(&mut self) {
+        if self.exporting_mods {
+            return;
+        }
+        self.exporting_mods = true;
+        let tx = self.mod_updates_tx.clone();
+        let engine = self.engine.clone();
+        let rt = self.runtime.clone();
+        rt.spawn(async move {
+            let service = {
+                let locked = engine.lock().await;
+                locked.mods_service()
+            };
+            let result = service.export_manifest().await;
+            let _ = tx.send(ModUpdate::Exported(result));
+        });
+    }
+
+    fn save_mod_export(&mut self, json: &str) {
+        let i18n = self.i18n();
+        let Some(path) = FileDialog::new()
+            .set_title(i18n.mods_export_dialog_title())
+            .set_file_name("hrs-launcher-mods.json")
+            .add_filter("JSON", &["json"])
+            .save_file()
+        else {
+            return;
+        };
+        match crate::util::write_atomic(&path, json.as_bytes()) {
+            Ok(()) => self.push_toast(i18n.mods_export_success(), Severity::Info),
+            Err(err) => {
+                self.installed_error = Some(err.to_string());
+                self.push_toast(i18n.mods_export_failed(&err.to_string()), Severity::Error);
+            }
+        }
+    }
+
+    fn open_mod_manifest_import_dialog(&mut self) {
+        let i18n = self.i18n();
+        let Some(path) = FileDialog::new()
+            .set_title(i18n.mods_import_dialog_title())
+            .add_filter("JSON", &["json"])
+            .pick_file()
+        else {
+            return;
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(json) => self.start_import_mod_manifest(json),
+            Err(err) => {
+                self.installed_error = Some(err.to_string());
+                self.push_toast(i18n.mods_import_failed(&err.to_string()), Severity::Error);
+            }
+        }
+    }
+
+    fn start_import_mod_manifest(&mut self, json: String) {
+        if self.importing_mod_list {
+            return;
+        }
+        self.importing_mod_list = true;
+        let tx = self.mod_updates_tx.clone();
+        let engine = self.engine.clone();
+        let rt = self.runtime.clone();
+        rt.spawn(async move {
+            let service = {
+                let locked = engine.lock().await;
+                locked.mods_service()
+            };
+            let result = service.import_manifest(&json).await;
+            let _ = tx.send(ModUpdate::ManifestImported(result));
+        });
+    }
+
     fn start_import_mod_files(&mut self, files: Vec<PathBuf>) {
         if files.is_empty() || self.installed_loading {
             return;
@@ -1132,56 +2470,321 @@ impl LauncherApp {
         });
     }
 
-    fn commit_player_name(&mut self) -> String {
-        let cleaned = sanitize_player_name(&self.player_name);
-        self.player_name = cleaned.clone();
-        match save_player_name_to_file(&cleaned) {
-            Ok(()) => {
-                self.player_name_error = None;
-            }
-            Err(err) => {
-                self.player_name_error = Some(err);
-            }
-        }
-        cleaned
-    }
-
-    fn start_news_fetch(&mut self) {
-        if self.news_loading {
+    /// Checks whether an installed mod has an update available and, if so,
+    /// fetches and caches the latest file's changelog for its "What's new"
+    /// expander, unless it's already cached or a fetch is already in flight.
+    fn start_mod_changelog_fetch(&mut self, mod_id: String, curseforge_id: i32, installed_file_id: i32) {
+        if self.mod_changelog_cache.contains_key(&mod_id) || self.mod_changelog_loading.contains(&mod_id) {
             return;
         }
-        self.news_loading = true;
-        let tx = self.news_updates_tx.clone();
+        self.mod_changelog_loading.insert(mod_id.clone());
+        let tx = self.mod_updates_tx.clone();
+        let engine = self.engine.clone();
         let rt = self.runtime.clone();
+        let game_version = self.selected_version.map(|v| v.to_string());
         rt.spawn(async move {
-            match fetch_news_from_web().await {
-                Ok(items) => {
-                    let _ = tx.send(NewsUpdate::Results(items));
-                }
-                Err(err) => {
-                    let _ = tx.send(NewsUpdate::Error(err));
+            let service = {
+                let locked = engine.lock().await;
+                locked.mods_service()
+            };
+            let result = async {
+                let latest = service
+                    .latest_file(curseforge_id, game_version.as_deref())
+                    .await?;
+                match latest {
+                    Some(latest) if latest.id != installed_file_id => service
+                        .file_changelog(curseforge_id, latest.id)
+                        .await
+                        .map(Some),
+                    _ => Ok(None),
                 }
             }
+            .await;
+            let _ = tx.send(ModUpdate::Changelog { mod_id, result });
         });
     }
 
-    fn start_version_discovery(&mut self) {
-        if self.version_loading {
+    fn start_install_mod_from_url(&mut self) {
+        let url = self.mod_url_input.trim().to_owned();
+        if url.is_empty() || self.installing_mod_url {
             return;
         }
-        self.version_loading = true;
-        self.version_fetch_error = None;
-        let tx = self.version_updates_tx.clone();
+        self.installing_mod_url = true;
+        self.installed_error = None;
+        let game_version = self.selected_version;
+        let tx = self.mod_updates_tx.clone();
         let engine = self.engine.clone();
         let rt = self.runtime.clone();
         rt.spawn(async move {
-            let storage = {
+            let service = {
                 let locked = engine.lock().await;
-                locked.storage_clone()
+                locked.mods_service()
             };
-            let result = LauncherEngine::available_versions_with_storage(storage).await;
-            if let Some(err) = result.error {
-                let _ = tx.send(VersionUpdate::Error(err));
+            let result = service
+                .install_from_url(&url, game_version, None, |_pct, _msg| {})
+                .await;
+            let _ = tx.send(ModUpdate::UrlInstalled(Box::new(result)));
+        });
+    }
+
+    fn queue_mod_download(&mut self, mod_id: i32) {
+        if self.downloading_mod_ids.contains(&mod_id) {
+            return;
+        }
+        self.downloading_mod_ids.insert(mod_id);
+        self.mod_download_total += 1;
+        self.mod_download_error = None;
+        let queue = self.mod_download_queue.clone();
+        let game_version = self.selected_version;
+        self.runtime.spawn(async move {
+            queue.enqueue(mod_id, game_version).await;
+        });
+    }
+
+    fn cancel_mod_downloads(&mut self) {
+        let queue = self.mod_download_queue.clone();
+        self.runtime.spawn(async move {
+            queue.cancel().await;
+        });
+    }
+
+    fn sync_mod_download_outcomes(&mut self, ctx: &egui::Context) {
+        let mut finished_any = false;
+        let mut drained = 0usize;
+        while drained < MAX_SYNC_MESSAGES_PER_FRAME {
+            let Ok(outcome) = self.mod_download_outcomes_rx.try_recv() else {
+                break;
+            };
+            drained += 1;
+            self.downloading_mod_ids.remove(&outcome.mod_id);
+            self.mod_download_completed = outcome.completed;
+            self.mod_download_total = outcome.total;
+            match outcome.result {
+                Ok(_) => {
+                    finished_any = true;
+                    if !outcome.missing_dependencies.is_empty() {
+                        let ids = outcome
+                            .missing_dependencies
+                            .iter()
+                            .map(|id| id.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        self.mod_dependency_warning = Some(ids);
+                    }
+                }
+                Err(err) => self.mod_download_error = Some(err),
+            }
+        }
+        if self.mod_download_completed >= self.mod_download_total {
+            self.mod_download_completed = 0;
+            self.mod_download_total = 0;
+        }
+        if finished_any {
+            self.start_load_installed_mods();
+        }
+        if drained == MAX_SYNC_MESSAGES_PER_FRAME {
+            ctx.request_repaint();
+        }
+    }
+
+    fn commit_player_name(&mut self) -> String {
+        let cleaned = sanitize_player_name(&self.player_name);
+        self.player_name = cleaned.clone();
+
+        if let Some(message) = validate_player_name(&cleaned, self.i18n()) {
+            self.player_name_error = Some(message);
+            return cleaned;
+        }
+
+        match save_player_name_to_file(&cleaned) {
+            Ok(()) => {
+                self.player_name_error = None;
+                self.save_current_profile();
+            }
+            Err(err) => {
+                self.player_name_error = Some(err);
+            }
+        }
+        cleaned
+    }
+
+    fn commit_extra_launch_args(&mut self) {
+        match tokenize_launch_args(&self.extra_launch_args) {
+            Ok(_) => {
+                self.extra_launch_args_error = None;
+                if let Err(err) = save_extra_launch_args_to_file(&self.extra_launch_args) {
+                    self.extra_launch_args_error = Some(err);
+                }
+            }
+            Err(err) => {
+                self.extra_launch_args_error = Some(err);
+            }
+        }
+    }
+
+    fn apply_memory_settings(&mut self) {
+        let i18n = self.i18n();
+
+        let parsed_max = if self.max_memory_input.trim().is_empty() {
+            Ok(None)
+        } else {
+            self.max_memory_input.trim().parse::<u32>().map(Some)
+        };
+        let parsed_min = if self.min_memory_input.trim().is_empty() {
+            Ok(None)
+        } else {
+            self.min_memory_input.trim().parse::<u32>().map(Some)
+        };
+
+        let (max_gb, min_gb) = match (parsed_max, parsed_min) {
+            (Ok(max_gb), Ok(min_gb)) => (max_gb, min_gb),
+            _ => {
+                self.memory_settings_error =
+                    Some(i18n.memory_settings_error_not_positive().to_owned());
+                return;
+            }
+        };
+
+        if let Some(message) = validate_memory_settings(min_gb, max_gb, i18n) {
+            self.memory_settings_error = Some(message);
+            return;
+        }
+
+        self.memory_settings_error = None;
+        self.max_memory_gb = max_gb;
+        self.min_memory_gb = min_gb;
+        if let Err(err) = save_max_memory_gb_to_file(max_gb) {
+            self.memory_settings_error = Some(err);
+            return;
+        }
+        if let Err(err) = save_min_memory_gb_to_file(min_gb) {
+            self.memory_settings_error = Some(err);
+        }
+    }
+
+    fn save_current_profile(&self) {
+        let profile = Profile::new(
+            self.current_profile.clone(),
+            self.player_name.clone(),
+            self.auth_mode,
+            self.selected_version,
+        );
+        if let Err(err) = profile::save_profile(&profile) {
+            warn!("failed to save profile {}: {err}", self.current_profile);
+        }
+    }
+
+    fn switch_profile(&mut self, name: String) {
+        if name == self.current_profile {
+            return;
+        }
+        if let Some(saved) = profile::load_profile(&name) {
+            self.player_name = saved.player_name;
+            self.auth_mode = saved.auth_mode;
+            self.selected_version = saved.selected_version;
+            self.version_input = self
+                .selected_version
+                .map(|version| version.to_string())
+                .unwrap_or_default();
+        }
+        self.current_profile = name;
+        self.player_name_error = None;
+        if let Err(err) = save_active_profile_to_file(&self.current_profile) {
+            warn!("failed to remember active profile: {err}");
+        }
+    }
+
+    fn create_new_profile(&mut self) {
+        let mut index = self.available_profiles.len() + 1;
+        let mut candidate = format!("Profile {index}");
+        while self.available_profiles.contains(&candidate) {
+            index += 1;
+            candidate = format!("Profile {index}");
+        }
+        self.available_profiles.push(candidate.clone());
+        self.available_profiles.sort();
+        self.current_profile = candidate.clone();
+        self.player_name = DEFAULT_PLAYER_NAME.to_owned();
+        self.player_name_error = None;
+        self.auth_mode = AuthMode::Offline;
+        self.selected_version = None;
+        self.version_input = String::new();
+        self.save_current_profile();
+        if let Err(err) = save_active_profile_to_file(&candidate) {
+            warn!("failed to remember active profile: {err}");
+        }
+    }
+
+    fn is_news_read(&self, url: &str) -> bool {
+        self.read_news.iter().any(|read| read == url)
+    }
+
+    /// Marks a news URL as read, persisting the (capped) set to disk.
+    fn mark_news_read(&mut self, url: &str) {
+        if self.is_news_read(url) {
+            return;
+        }
+        self.read_news.push(url.to_owned());
+        if self.read_news.len() > READ_NEWS_CAP {
+            self.read_news.remove(0);
+        }
+        if let Err(err) = save_read_news_to_file(&self.read_news) {
+            warn!("failed to persist read news: {err}");
+        }
+    }
+
+    fn start_news_fetch(&mut self) {
+        if self.news_loading {
+            return;
+        }
+        self.news_loading = true;
+        let tx = self.news_updates_tx.clone();
+        let rt = self.runtime.clone();
+        rt.spawn(async move {
+            match fetch_news_from_web().await {
+                Ok(items) => {
+                    let _ = tx.send(NewsUpdate::Results(items));
+                }
+                Err(err) => {
+                    let _ = tx.send(NewsUpdate::Error(err));
+                }
+            }
+        });
+    }
+
+    /// Fetches and caches a news article's full content for inline reading,
+    /// unless it's already cached or a fetch is already in flight.
+    fn start_news_article_fetch(&mut self, url: String) {
+        if self.news_article_cache.contains_key(&url) || self.news_article_loading {
+            return;
+        }
+        self.news_article_loading = true;
+        self.news_article_error = None;
+        let tx = self.news_updates_tx.clone();
+        let rt = self.runtime.clone();
+        rt.spawn(async move {
+            let result = fetch_news_article(&url).await;
+            let _ = tx.send(NewsUpdate::Article { url, result });
+        });
+    }
+
+    fn start_version_discovery(&mut self) {
+        if self.version_loading {
+            return;
+        }
+        self.version_loading = true;
+        self.version_fetch_error = None;
+        let tx = self.version_updates_tx.clone();
+        let engine = self.engine.clone();
+        let rt = self.runtime.clone();
+        rt.spawn(async move {
+            let storage = {
+                let locked = engine.lock().await;
+                locked.storage_clone()
+            };
+            let result = LauncherEngine::available_versions_with_storage(storage).await;
+            if let Some(err) = result.error {
+                let _ = tx.send(VersionUpdate::Error(err));
             } else {
                 let _ = tx.send(VersionUpdate::Available {
                     versions: result.available_versions,
@@ -1191,35 +2794,218 @@ impl LauncherApp {
         });
     }
 
-    fn sync_state(&mut self) {
-        while let Ok(state) = self.updates_rx.try_recv() {
-            match &state {
-                AppState::DiagnosticsReady { report } => {
-                    self.diagnostics = Some(report.clone());
-                    self.show_diagnostics_modal = true;
-                    self.state = AppState::Idle;
-                }
-                AppState::ReadyToPlay { version } => {
-                    if let Ok(parsed) = version.parse::<u32>() {
-                        self.set_selected_version(Some(parsed));
-                    }
-                    self.state = state;
-                }
-                AppState::Idle => {
-                    self.state = state;
-                    self.start_load_installed_mods();
-                }
-                _ => {
-                    self.state = state;
+    /// Drains pending state updates, collapsing consecutive
+    /// `Downloading` progress ticks down to just the latest one: the
+    /// download loop reports progress every ~0.2s and only the most recent
+    /// value is ever shown, so applying every tick is wasted work. A
+    /// `Downloading` run is flushed as soon as a different state arrives (or
+    /// the drain ends), so terminal states interleaved between progress
+    /// ticks still land in their original order.
+    fn sync_state(&mut self, ctx: &egui::Context) {
+        let mut drained = 0usize;
+        let mut pending_downloading = None;
+        while drained < MAX_SYNC_MESSAGES_PER_FRAME {
+            let Ok(state) = self.updates_rx.try_recv() else {
+                break;
+            };
+            drained += 1;
+            if matches!(state, AppState::Downloading { .. }) {
+                pending_downloading = Some(state);
+                continue;
+            }
+            if let Some(latest) = pending_downloading.take() {
+                self.apply_state_update(latest, ctx);
+            }
+            self.apply_state_update(state, ctx);
+        }
+        if let Some(latest) = pending_downloading.take() {
+            self.apply_state_update(latest, ctx);
+        }
+        if drained == MAX_SYNC_MESSAGES_PER_FRAME {
+            ctx.request_repaint();
+        }
+    }
+
+    fn apply_state_update(&mut self, state: AppState, ctx: &egui::Context) {
+        let was_playing = matches!(self.state, AppState::Playing);
+        match &state {
+            AppState::DiagnosticsReady { report } => {
+                self.diagnostics = Some(report.clone());
+                self.show_diagnostics_modal = true;
+                self.state = AppState::Idle;
+            }
+            AppState::JavaTestReady { output } => {
+                self.java_test_output = Some(output.clone());
+                self.state = AppState::Idle;
+            }
+            AppState::CrashReportReady { path } => {
+                self.crash_report_path = Some(path.clone());
+                self.state = AppState::Idle;
+            }
+            AppState::ReadyToPlay { version } => {
+                if let Ok(parsed) = version.parse::<u32>() {
+                    self.installed_version = Some(parsed);
+                    self.set_selected_version(Some(parsed));
                 }
+                self.state = state;
+            }
+            AppState::Idle => {
+                self.installed_version = None;
+                self.state = state;
+                self.last_played = read_last_played();
+                self.start_load_installed_mods();
             }
+            _ => {
+                self.state = state;
+            }
+        }
+
+        if self.minimize_to_tray {
+            let now_playing = matches!(self.state, AppState::Playing);
+            if now_playing && !was_playing {
+                self.hide_window(ctx);
+            } else if was_playing && !now_playing {
+                self.restore_window(ctx);
+            }
+        }
+    }
+
+    /// Intercepts a window close request while a download or install is in
+    /// flight and asks for confirmation instead of silently losing a
+    /// multi-GB download, via [`Self::render_close_confirm_modal`]. Closes
+    /// normally, without prompting, when idle or once already confirmed.
+    fn handle_close_request(&mut self, ctx: &egui::Context) {
+        if !ctx.input(|i| i.viewport().close_requested()) {
+            return;
+        }
+        if self.confirmed_exit {
+            return;
+        }
+        if self.is_busy_with_transfer() {
+            self.show_close_confirm = true;
+            ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+        }
+    }
+
+    fn is_busy_with_transfer(&self) -> bool {
+        matches!(
+            self.state,
+            AppState::Downloading { .. }
+                | AppState::PreparingRuntime { .. }
+                | AppState::CheckingForUpdates
+                | AppState::Initialising
+                | AppState::TestingJava
+                | AppState::DiagnosticsRunning
+                | AppState::CreatingCrashReport
+                | AppState::Uninstalling { .. }
+        )
+    }
+
+    /// Keeps the UI snappy without spinning the CPU when there's nothing to
+    /// animate. A visible [`egui::Spinner`] already requests a repaint every
+    /// frame on its own, and [`is_busy_with_transfer`](Self::is_busy_with_transfer)
+    /// covers the states that show one; outside of that, back off to
+    /// [`IDLE_REPAINT_INTERVAL`] so background results (a finished update
+    /// check, download progress) still surface promptly without forcing a
+    /// full 60 fps loop while the app is otherwise idle.
+    fn apply_repaint_policy(&self, ctx: &egui::Context) {
+        if self.force_continuous_repaint || self.is_busy_with_transfer() {
+            ctx.request_repaint();
+            return;
+        }
+        ctx.request_repaint_after(IDLE_REPAINT_INTERVAL);
+    }
+
+    /// Confirms cancelling the in-flight operation before actually closing
+    /// the window. Declining leaves the window open and the operation
+    /// running untouched.
+    fn render_close_confirm_modal(&mut self, ctx: &egui::Context, colors: &ThemePalette, i18n: I18n) {
+        if !self.show_close_confirm {
+            return;
+        }
+        egui::Window::new(i18n.close_confirm_title())
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.label(i18n.close_confirm_body());
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add(
+                            egui::Button::new(i18n.close_confirm_yes())
+                                .fill(tint(colors.danger, 60))
+                                .stroke(Stroke::new(1.0, colors.danger)),
+                        )
+                        .clicked()
+                    {
+                        info!("close confirmed while busy ({:?}); cancelling and exiting", self.state);
+                        self.cancel_flag.store(true, Ordering::SeqCst);
+                        self.confirmed_exit = true;
+                        self.show_close_confirm = false;
+                        ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                    }
+                    if ui
+                        .add(
+                            egui::Button::new(i18n.close_confirm_no())
+                                .fill(colors.surface_elev)
+                                .stroke(Stroke::new(1.0, colors.border_strong)),
+                        )
+                        .clicked()
+                    {
+                        self.show_close_confirm = false;
+                    }
+                });
+            });
+    }
+
+    /// Hides the window to the tray icon if one is running, or otherwise
+    /// just minimizes it like a normal window close-to-taskbar.
+    ///
+    /// Note: this fires on `AppState::Playing`, which today only covers the
+    /// moment the game process is spawned — the engine has no way yet to
+    /// detect the game exiting, so `restore_window` below fires as soon as
+    /// the launcher's own state moves on (effectively immediately after
+    /// launch succeeds), not when the game actually closes.
+    fn hide_window(&self, ctx: &egui::Context) {
+        if self.tray.is_some() {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Visible(false));
+        } else {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(true));
+        }
+    }
+
+    fn restore_window(&self, ctx: &egui::Context) {
+        if self.tray.is_some() {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Visible(true));
+            ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+        } else {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
         }
     }
 
-    fn sync_mod_updates(&mut self) {
-        while let Ok(update) = self.mod_updates_rx.try_recv() {
+    /// Queues a transient notification, shown by [`Self::render_toasts`]
+    /// until it ages past [`TOAST_DURATION`].
+    fn push_toast(&mut self, message: impl Into<String>, severity: Severity) {
+        self.toasts.push(Toast {
+            message: message.into(),
+            shown_at: Instant::now(),
+            severity,
+        });
+    }
+
+    fn sync_mod_updates(&mut self, ctx: &egui::Context) {
+        let mut drained = 0usize;
+        while drained < MAX_SYNC_MESSAGES_PER_FRAME {
+            let Ok(update) = self.mod_updates_rx.try_recv() else {
+                break;
+            };
+            drained += 1;
             match update {
-                ModUpdate::Results(results) => {
+                ModUpdate::Results { results, generation } => {
+                    if generation != self.mod_search_generation {
+                        continue;
+                    }
                     self.mod_loading = false;
                     self.mod_results = results;
                     self.mod_error = None;
@@ -1234,9 +3020,12 @@ impl LauncherApp {
                         }
                     }
                 }
-                ModUpdate::Error(err) => {
+                ModUpdate::Error { message, generation } => {
+                    if generation != self.mod_search_generation {
+                        continue;
+                    }
                     self.mod_loading = false;
-                    self.mod_error = Some(err);
+                    self.mod_error = Some(message);
                 }
                 ModUpdate::Installed(mods) => {
                     self.installed_loading = false;
@@ -1259,35 +3048,166 @@ impl LauncherApp {
                     self.installed_loading = false;
                     self.removing_mod = None;
                     if let Some(err) = error {
+                        self.push_toast(self.i18n().mods_installed_error(&err), Severity::Error);
                         self.installed_error = Some(err);
                     } else {
                         self.installed_mods.retain(|m| m.id != id);
                         self.installed_error = None;
                     }
                 }
+                ModUpdate::RemovedAll { error } => {
+                    self.installed_loading = false;
+                    self.removing_all_mods = false;
+                    if let Some(err) = error {
+                        self.push_toast(self.i18n().mods_installed_error(&err), Severity::Error);
+                        self.installed_error = Some(err);
+                    } else {
+                        self.installed_mods.clear();
+                        self.installed_error = None;
+                    }
+                }
+                ModUpdate::Reconciled(result) => {
+                    self.reconciling_mods = false;
+                    match result {
+                        Ok(report) => {
+                            if !report.removed_missing.is_empty() {
+                                self.installed_mods
+                                    .retain(|m| !report.removed_missing.contains(&m.name));
+                            }
+                            let i18n = self.i18n();
+                            let summary = if report.is_clean() {
+                                i18n.mods_repair_clean().to_owned()
+                            } else {
+                                i18n.mods_repair_summary(
+                                    report.removed_missing.len(),
+                                    report.untracked_files.len(),
+                                )
+                            };
+                            self.push_toast(summary, Severity::Info);
+                            self.mod_reconcile_report = Some(report);
+                            self.installed_error = None;
+                        }
+                        Err(err) => self.installed_error = Some(err),
+                    }
+                }
+                ModUpdate::Exported(result) => {
+                    self.exporting_mods = false;
+                    match result {
+                        Ok(json) => self.save_mod_export(&json),
+                        Err(err) => {
+                            self.installed_error = Some(err.clone());
+                            self.push_toast(self.i18n().mods_export_failed(&err), Severity::Error);
+                        }
+                    }
+                }
+                ModUpdate::ManifestImported(result) => {
+                    self.importing_mod_list = false;
+                    match result {
+                        Ok(ids) if ids.is_empty() => {
+                            self.push_toast(self.i18n().mods_import_nothing_to_do(), Severity::Info);
+                        }
+                        Ok(ids) => {
+                            let count = ids.len();
+                            for id in ids {
+                                self.queue_mod_download(id);
+                            }
+                            self.push_toast(self.i18n().mods_import_queued(count), Severity::Info);
+                        }
+                        Err(err) => {
+                            self.installed_error = Some(err.clone());
+                            self.push_toast(self.i18n().mods_import_failed(&err), Severity::Error);
+                        }
+                    }
+                }
+                ModUpdate::UrlInstalled(result) => {
+                    self.installing_mod_url = false;
+                    match *result {
+                        Ok((installed, missing_dependencies)) => {
+                            self.mod_url_input.clear();
+                            self.installed_error = None;
+                            self.push_toast(
+                                self.i18n().mods_url_install_success(&installed.name),
+                                Severity::Info,
+                            );
+                            if !missing_dependencies.is_empty() {
+                                let ids = missing_dependencies
+                                    .iter()
+                                    .map(|id| id.to_string())
+                                    .collect::<Vec<_>>()
+                                    .join(", ");
+                                self.mod_dependency_warning = Some(ids);
+                            }
+                            self.start_load_installed_mods();
+                        }
+                        Err(err) => {
+                            self.push_toast(self.i18n().mods_url_install_failed(&err), Severity::Error);
+                            self.installed_error = Some(err);
+                        }
+                    }
+                }
+                ModUpdate::Changelog { mod_id, result } => {
+                    self.mod_changelog_loading.remove(&mod_id);
+                    self.mod_changelog_cache.insert(mod_id, result);
+                }
             }
         }
+        if drained == MAX_SYNC_MESSAGES_PER_FRAME {
+            ctx.request_repaint();
+        }
     }
 
-    fn sync_news_updates(&mut self) {
-        while let Ok(update) = self.news_updates_rx.try_recv() {
-            self.news_loading = false;
+    fn sync_news_updates(&mut self, ctx: &egui::Context) {
+        let mut drained = 0usize;
+        while drained < MAX_SYNC_MESSAGES_PER_FRAME {
+            let Ok(update) = self.news_updates_rx.try_recv() else {
+                break;
+            };
+            drained += 1;
             match update {
                 NewsUpdate::Results(items) => {
+                    self.news_loading = false;
                     if !items.is_empty() {
                         self.news = items;
                     }
                     self.news_error = None;
                 }
                 NewsUpdate::Error(err) => {
+                    self.news_loading = false;
+                    self.push_toast(self.i18n().news_fetch_failed(&err), Severity::Error);
                     self.news_error = Some(err);
                 }
+                NewsUpdate::Article { url, result } => {
+                    self.news_article_loading = false;
+                    match result {
+                        Ok(content) => {
+                            self.news_article_cache.insert(url, content);
+                        }
+                        Err(err) => {
+                            warn!("failed to fetch article content for {url} ({err}); opening in browser");
+                            self.news_article_error = Some(err);
+                            if self.expanded_news_url.as_deref() == Some(url.as_str())
+                                && let Err(err) = open::that(&url)
+                            {
+                                warn!("failed to open {url} in browser: {err}");
+                            }
+                            self.expanded_news_url = None;
+                        }
+                    }
+                }
             }
         }
+        if drained == MAX_SYNC_MESSAGES_PER_FRAME {
+            ctx.request_repaint();
+        }
     }
 
-    fn sync_version_updates(&mut self) {
-        while let Ok(update) = self.version_updates_rx.try_recv() {
+    fn sync_version_updates(&mut self, ctx: &egui::Context) {
+        let mut drained = 0usize;
+        while drained < MAX_SYNC_MESSAGES_PER_FRAME {
+            let Ok(update) = self.version_updates_rx.try_recv() else {
+                break;
+            };
+            drained += 1;
             self.version_loading = false;
             match update {
                 VersionUpdate::Available { versions, latest } => {
@@ -1312,6 +3232,9 @@ impl LauncherApp {
                 }
             }
         }
+        if drained == MAX_SYNC_MESSAGES_PER_FRAME {
+            ctx.request_repaint();
+        }
     }
 
     fn start_updater_check(&mut self) {
@@ -1319,6 +3242,7 @@ impl LauncherApp {
             return;
         }
         self.updater_loading = true;
+        self.last_updater_check = Instant::now();
         let tx = self.updater_updates_tx.clone();
         let current_version = self.launcher_version.to_owned();
         let rt = self.runtime.clone();
@@ -1334,8 +3258,25 @@ impl LauncherApp {
         });
     }
 
-    fn sync_updater_updates(&mut self) {
-        while let Ok(update) = self.updater_updates_rx.try_recv() {
+    fn poll_periodic_updater_check(&mut self, ctx: &egui::Context) {
+        let Some(interval) = updater::periodic_check_interval() else {
+            return;
+        };
+        let elapsed = self.last_updater_check.elapsed();
+        if elapsed >= interval {
+            self.start_updater_check();
+        } else {
+            ctx.request_repaint_after(interval - elapsed);
+        }
+    }
+
+    fn sync_updater_updates(&mut self, ctx: &egui::Context) {
+        let mut drained = 0usize;
+        while drained < MAX_SYNC_MESSAGES_PER_FRAME {
+            let Ok(update) = self.updater_updates_rx.try_recv() else {
+                break;
+            };
+            drained += 1;
             self.updater_loading = false;
             match update {
                 UpdaterUpdate::Status(status) => {
@@ -1343,6 +3284,9 @@ impl LauncherApp {
                 }
             }
         }
+        if drained == MAX_SYNC_MESSAGES_PER_FRAME {
+            ctx.request_repaint();
+        }
     }
 
     fn current_ready_version(&self) -> Option<u32> {
@@ -1352,6 +3296,20 @@ impl LauncherApp {
         }
     }
 
+    /// `true` when the last version fetch came back with an error and left
+    /// no versions to pick from, i.e. a platform-support gap rather than a
+    /// transient fetch failure that still has a stale list to fall back on.
+    fn no_versions_available(&self) -> bool {
+        self.available_versions.is_empty() && self.version_fetch_error.is_some()
+    }
+
+    /// `true` when the last version fetch failed specifically because
+    /// `pwr::platform_keys` doesn't recognise this OS, as opposed to a
+    /// transient network error.
+    fn is_unsupported_platform(&self) -> bool {
+        self.version_fetch_error.as_deref() == Some("unsupported operating system")
+    }
+
     fn set_selected_version(&mut self, version: Option<u32>) {
         self.selected_version = version;
         self.version_input = version.map(|v| v.to_string()).unwrap_or_default();
@@ -1374,6 +3332,7 @@ impl LauncherApp {
         if let Err(err) = save_selected_version_to_file(self.selected_version) {
             warn!("failed to persist selected version: {}", err);
         }
+        self.save_current_profile();
     }
 
     fn apply_version_input(&mut self) {
@@ -1415,9 +3374,17 @@ impl LauncherApp {
                     AppState::ReadyToPlay { .. } => (i18n.status_ready(), colors.accent),
                     AppState::Playing => (i18n.status_running(), colors.info),
                     AppState::Error(_) => (i18n.status_attention(), colors.danger),
+                    AppState::JreIntegrityFailed(_) => (i18n.status_attention(), colors.danger),
                     AppState::Downloading { .. } => (i18n.status_downloading(), colors.warning),
-                    AppState::Uninstalling => (i18n.status_uninstalling(), colors.danger),
+                    AppState::PreparingRuntime { .. } => {
+                        (i18n.status_preparing_runtime(), colors.warning)
+                    }
+                    AppState::Uninstalling { .. } => (i18n.status_uninstalling(), colors.danger),
                     AppState::DiagnosticsRunning => (i18n.status_diagnostics(), colors.diagnostic),
+                    AppState::TestingJava => (i18n.status_testing_java(), colors.diagnostic),
+                    AppState::CreatingCrashReport => {
+                        (i18n.status_creating_crash_report(), colors.diagnostic)
+                    }
                     _ => (i18n.status_working(), colors.text_faint),
                 };
                 if matches!(self.state, AppState::ReadyToPlay { .. }) {
@@ -1453,6 +3420,7 @@ impl LauncherApp {
                             file,
                             progress,
                             speed,
+                            eta,
                         } => {
                             ui.label(i18n.downloading(file));
                             ui.add(
@@ -1460,16 +3428,22 @@ impl LauncherApp {
                                     .fill(colors.accent)
                                     .corner_radius(CornerRadius::same(10))
                                     .desired_height(22.0)
-                                    .text(i18n.progress(*progress, speed)),
+                                    .text(i18n.progress(*progress, speed, eta.as_deref())),
                             );
                         }
-                        AppState::Uninstalling => {
+                        AppState::Uninstalling { stage } => {
                             ui.horizontal(|ui| {
                                 ui.add(egui::Spinner::new());
-                                ui.label(i18n.uninstalling());
+                                ui.label(i18n.uninstalling(stage));
                             });
                         }
-                        AppState::ReadyToPlay { version } => {
+                        AppState::PreparingRuntime { stage } => {
+                            ui.horizontal(|ui| {
+                                ui.add(egui::Spinner::new());
+                                ui.label(i18n.preparing_runtime(stage));
+                            });
+                        }
+                        AppState::ReadyToPlay { version } => {
                             ui.label(RichText::new(i18n.ready(version)).strong());
                         }
                         AppState::DiagnosticsRunning => {
@@ -1478,12 +3452,27 @@ impl LauncherApp {
                         AppState::DiagnosticsReady { .. } => {
                             ui.label(i18n.diagnostics_completed());
                         }
+                        AppState::TestingJava => {
+                            ui.label(i18n.testing_java());
+                        }
+                        AppState::JavaTestReady { output } => {
+                            ui.label(i18n.java_test_result(output));
+                        }
+                        AppState::CreatingCrashReport => {
+                            ui.label(i18n.creating_crash_report());
+                        }
+                        AppState::CrashReportReady { path } => {
+                            ui.label(i18n.crash_report_ready(path));
+                        }
                         AppState::Playing => {
                             ui.label(i18n.playing());
                         }
                         AppState::Error(msg) => {
                             ui.colored_label(colors.danger, i18n.error(msg));
                         }
+                        AppState::JreIntegrityFailed(msg) => {
+                            ui.colored_label(colors.danger, i18n.jre_integrity_failed(msg));
+                        }
                         AppState::Initialising => {
                             ui.label(i18n.initialising());
                         }
@@ -1499,13 +3488,16 @@ impl LauncherApp {
                     Vec2::new(action_width, 0.0),
                     Layout::top_down(Align::RIGHT),
                     |ui| {
-                        let play_enabled = matches!(self.state, AppState::ReadyToPlay { .. });
+                        let play_enabled = self.play_enabled();
                         let busy_refresh = matches!(
                             self.state,
                             AppState::Downloading { .. }
+                                | AppState::PreparingRuntime { .. }
                                 | AppState::CheckingForUpdates
                                 | AppState::DiagnosticsRunning
-                                | AppState::Uninstalling
+                                | AppState::TestingJava
+                                | AppState::CreatingCrashReport
+                                | AppState::Uninstalling { .. }
                                 | AppState::Initialising
                         );
                         let play_label = RichText::new(i18n.play_button())
@@ -1517,11 +3509,7 @@ impl LauncherApp {
                             .strong();
                         let play_btn = primary_cta_button(play_label, colors, 140.0);
                         if ui.add_enabled(play_enabled, play_btn).clicked() {
-                            let player_name = self.commit_player_name();
-                            self.trigger_action(UserAction::ClickPlay {
-                                player_name,
-                                auth_mode: self.auth_mode,
-                            });
+                            self.trigger_play();
                         }
                         ui.add_space(10.0);
                         let refresh_btn = egui::Button::new(i18n.status_refresh())
@@ -1533,16 +3521,47 @@ impl LauncherApp {
                                 target_version: self.selected_version,
                             });
                         }
+                        if self.game_running() {
+                            let ready_to_play = matches!(self.state, AppState::ReadyToPlay { .. });
+                            ui.add_space(6.0);
+                            ui.label(
+                                RichText::new(i18n.game_running_status())
+                                    .color(colors.text_muted)
+                                    .small(),
+                            );
+                            if ui
+                                .add_enabled(
+                                    ready_to_play,
+                                    egui::Button::new(i18n.force_launch_button()).small(),
+                                )
+                                .clicked()
+                            {
+                                self.trigger_play();
+                            }
+                        }
                     },
                 );
             });
         });
     }
 
-    fn render_news(&self, ui: &mut egui::Ui, colors: &ThemePalette, i18n: I18n) {
+    fn render_news(&mut self, ui: &mut egui::Ui, colors: &ThemePalette, i18n: I18n) {
         section_frame(colors).show(ui, |ui| {
             ui.horizontal(|ui| {
                 ui.heading(i18n.news_heading());
+                let unread = self
+                    .news
+                    .iter()
+                    .filter(|item| !self.is_news_read(&item.url))
+                    .count();
+                if unread > 0 {
+                    ui.label(
+                        RichText::new(i18n.news_unread_badge(unread))
+                            .small()
+                            .strong()
+                            .color(colors.accent),
+                    );
+                }
                 ui.label(
                     RichText::new(i18n.news_subheading())
                         .color(colors.text_muted)
@@ -1568,22 +3587,112 @@ impl LauncherApp {
                 return;
             }
 
-            for item in &self.news {
+            let mut fetch_requested = None;
+            let mut read_requested = None;
+            for item in self.news.clone() {
                 elevated_frame(colors).show(ui, |ui| {
                     ui.vertical(|ui| {
-                        ui.hyperlink_to(RichText::new(&item.title).strong(), &item.url);
+                        ui.horizontal(|ui| {
+                            if !self.is_news_read(&item.url) {
+                                ui.colored_label(colors.accent, "●");
+                            }
+                            if ui
+                                .hyperlink_to(RichText::new(&item.title).strong(), &item.url)
+                                .clicked()
+                            {
+                                read_requested = Some(item.url.clone());
+                            }
+                            if let Some(source) = &item.source {
+                                ui.label(
+                                    RichText::new(source)
+                                        .small()
+                                        .color(colors.text_muted)
+                                        .background_color(colors.surface_elev),
+                                );
+                            }
+                            if let Some(date) = &item.date {
+                                ui.label(RichText::new(date).small().color(colors.text_muted));
+                            }
+                        });
                         let preview = if item.preview == NEWS_PREVIEW_FALLBACK_EN {
                             i18n.news_preview_fallback()
                         } else {
                             item.preview.as_str()
                         };
                         ui.label(preview);
+                        if ui.link(i18n.news_read_more_button()).clicked() {
+                            self.expanded_news_url = Some(item.url.clone());
+                            read_requested = Some(item.url.clone());
+                            if !self.news_article_cache.contains_key(&item.url) {
+                                fetch_requested = Some(item.url.clone());
+                            }
+                        }
                     });
                 });
             }
+            if let Some(url) = fetch_requested {
+                self.start_news_article_fetch(url);
+            }
+            if let Some(url) = read_requested {
+                self.mark_news_read(&url);
+            }
         });
     }
 
+    fn render_news_article_modal(&mut self, ctx: &egui::Context, colors: &ThemePalette, i18n: I18n) {
+        let Some(url) = self.expanded_news_url.clone() else {
+            return;
+        };
+        let title = self
+            .news
+            .iter()
+            .find(|item| item.url == url)
+            .map(|item| item.title.clone())
+            .unwrap_or_else(|| i18n.news_heading().to_owned());
+
+        let mut open = true;
+        egui::Window::new(title)
+            .collapsible(false)
+            .resizable(true)
+            .anchor(egui::Align2::CENTER_CENTER, Vec2::ZERO)
+            .default_width(640.0)
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.set_min_height(280.0);
+                match self.news_article_cache.get(&url) {
+                    Some(content) => {
+                        egui::ScrollArea::vertical().max_height(480.0).show(ui, |ui| {
+                            ui.label(content);
+                        });
+                    }
+                    None if self.news_article_loading => {
+                        ui.horizontal(|ui| {
+                            ui.add(egui::Spinner::new());
+                            ui.label(i18n.news_article_loading());
+                        });
+                    }
+                    None => {
+                        ui.colored_label(colors.danger, i18n.news_article_unavailable());
+                    }
+                }
+                ui.add_space(8.0);
+                if ui
+                    .add(
+                        egui::Button::new(i18n.news_open_in_browser_button())
+                            .fill(colors.surface_elev)
+                            .stroke(Stroke::new(1.0, colors.border_strong)),
+                    )
+                    .clicked()
+                    && let Err(err) = open::that(&url)
+                {
+                    warn!("failed to open {url} in browser: {err}");
+                }
+            });
+        if !open {
+            self.expanded_news_url = None;
+        }
+    }
+
     fn render_mods(&mut self, ui: &mut egui::Ui, colors: &ThemePalette, i18n: I18n) {
         section_frame(colors).show(ui, |ui| {
             ui.set_min_height(676.0);
@@ -1591,12 +3700,13 @@ impl LauncherApp {
             let mod_actions_locked = matches!(
                 self.state,
                 AppState::Downloading { .. }
+                    | AppState::PreparingRuntime { .. }
                     | AppState::CheckingForUpdates
-                    | AppState::Uninstalling
+                    | AppState::Uninstalling { .. }
                     | AppState::Playing
             );
             let can_install_mods = game_installed && !mod_actions_locked && !self.installed_loading;
-            ui.horizontal(|ui| {
+            ui.with_layout(row_layout(i18n), |ui| {
                 ui.heading(i18n.mods_heading());
                 if self.mod_loading {
                     ui.add(egui::Spinner::new());
@@ -1617,6 +3727,21 @@ impl LauncherApp {
                 if ui.add_enabled(can_install_mods, select_btn).clicked() {
                     self.open_mod_file_picker(can_install_mods);
                 }
+                ui.add_space(8.0);
+                let mut compact = self.mod_density == ModDensity::Compact;
+                if ui
+                    .checkbox(&mut compact, i18n.mod_density_label(ModDensity::Compact))
+                    .changed()
+                {
+                    self.mod_density = if compact {
+                        ModDensity::Compact
+                    } else {
+                        ModDensity::Comfortable
+                    };
+                    if let Err(err) = save_mod_density_to_file(self.mod_density) {
+                        warn!("failed to persist mod density setting: {err}");
+                    }
+                }
             });
 
             if !game_installed {
@@ -1628,6 +3753,7 @@ impl LauncherApp {
             ui.separator();
 
             ui.add_space(4.0);
+            let mut search_box_focused = false;
             ui.horizontal_wrapped(|ui| {
                 let mods_search_hint = i18n.mods_search_hint();
                 let resp = ui.add_sized(
@@ -1636,8 +3762,10 @@ impl LauncherApp {
                         .hint_text(mods_search_hint)
                         .vertical_align(Align::Center),
                 );
+                search_box_focused = resp.has_focus();
                 if resp.changed() {
                     self.mod_error = None;
+                    self.mod_query_dirty_at = Some(Instant::now());
                 }
                 let can_search = !self.mod_query.trim().is_empty() && !self.mod_loading;
                 let search_label = if self.mod_loading {
@@ -1673,6 +3801,8 @@ impl LauncherApp {
                     self.mod_results.clear();
                     self.mod_error = None;
                     self.mod_category_filter = None;
+                    self.mod_query_dirty_at = None;
+                    self.last_searched_mod_query.clear();
                 }
                 let enter_pressed =
                     resp.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
@@ -1680,6 +3810,7 @@ impl LauncherApp {
                     self.start_mod_search();
                     ui.memory_mut(|m| m.request_focus(resp.id));
                 }
+                ui.checkbox(&mut self.live_mod_search, i18n.mods_live_search_toggle());
             });
 
             let categories = collect_mod_categories(&self.mod_results);
@@ -1762,11 +3893,71 @@ impl LauncherApp {
                 });
             });
 
+            ui.add_space(8.0);
+            ui.horizontal_wrapped(|ui| {
+                ui.vertical(|ui| {
+                    ui.label(
+                        RichText::new(i18n.mods_min_downloads_label())
+                            .color(colors.text_muted)
+                            .small(),
+                    );
+                    ui.add_space(4.0);
+                    ui.add_sized(
+                        Vec2::new(combo_width, 28.0),
+                        egui::TextEdit::singleline(&mut self.mod_min_downloads_input)
+                            .hint_text(i18n.mods_min_downloads_placeholder()),
+                    );
+                });
+
+                if !is_narrow {
+                    ui.add_space(gutter);
+                } else {
+                    ui.add_space(8.0);
+                }
+
+                ui.vertical(|ui| {
+                    ui.label(
+                        RichText::new(i18n.mods_recency_label())
+                            .color(colors.text_muted)
+                            .small(),
+                    );
+                    ui.add_space(4.0);
+                    ui.set_min_width(combo_width);
+                    egui::ComboBox::from_id_salt("mod_recency")
+                        .selected_text(i18n.mod_recency_label(self.mod_recency_filter))
+                        .show_ui(ui, |ui| {
+                            for option in [
+                                ModRecency::Any,
+                                ModRecency::LastMonth,
+                                ModRecency::Last3Months,
+                                ModRecency::Last6Months,
+                                ModRecency::LastYear,
+                            ] {
+                                ui.selectable_value(
+                                    &mut self.mod_recency_filter,
+                                    option,
+                                    i18n.mod_recency_label(option),
+                                );
+                            }
+                        });
+                });
+            });
+
             let total_results = self.mod_results.len();
             let mut visible_mods: Vec<CurseForgeMod> = self.mod_results.clone();
             if let Some(category) = &self.mod_category_filter {
                 visible_mods.retain(|m| m.categories.iter().any(|c| c.name == *category));
             }
+            if let Ok(min_downloads) = self.mod_min_downloads_input.trim().parse::<i64>() {
+                visible_mods.retain(|m| m.downloadCount >= min_downloads);
+            }
+            if let Some(months) = self.mod_recency_filter.months() {
+                let cutoff = chrono::Utc::now() - chrono::Duration::days(months * 30);
+                visible_mods.retain(|m| {
+                    chrono::DateTime::parse_from_rfc3339(&m.dateModified)
+                        .is_ok_and(|updated| updated.with_timezone(&chrono::Utc) >= cutoff)
+                });
+            }
             match self.mod_sort {
                 ModSort::Downloads => {
                     visible_mods.sort_by(|a, b| b.downloadCount.cmp(&a.downloadCount));
@@ -1812,23 +4003,100 @@ impl LauncherApp {
                 .collect();
             let removing_id = self.removing_mod.clone();
             let remove_locked = mod_actions_locked || self.installed_loading;
+
+            if self
+                .selected_mod_index
+                .is_some_and(|idx| idx >= visible_mods.len())
+            {
+                self.selected_mod_index = None;
+            }
+            if !search_box_focused && !visible_mods.is_empty() {
+                let (down, up, enter) = ui.input(|i| {
+                    (
+                        i.key_pressed(egui::Key::ArrowDown),
+                        i.key_pressed(egui::Key::ArrowUp),
+                        i.key_pressed(egui::Key::Enter),
+                    )
+                });
+                if down {
+                    self.selected_mod_index = Some(
+                        self.selected_mod_index
+                            .map_or(0, |idx| (idx + 1).min(visible_mods.len() - 1)),
+                    );
+                } else if up {
+                    self.selected_mod_index =
+                        Some(self.selected_mod_index.map_or(0, |idx| idx.saturating_sub(1)));
+                }
+                if enter
+                    && let Some(m) = self.selected_mod_index.and_then(|idx| visible_mods.get(idx))
+                {
+                    if installed_by_cf.contains_key(&m.id) {
+                        if let Err(err) = open::that(mod_page_url(m)) {
+                            warn!("failed to open mod page for {}: {err}", m.name);
+                        }
+                    } else if can_install_mods && !self.downloading_mod_ids.contains(&m.id) {
+                        self.queue_mod_download(m.id);
+                    }
+                }
+            }
+
             egui::ScrollArea::vertical()
                 .max_height(scroll_height)
                 .show(ui, |ui| {
-                    for m in &visible_mods {
+                    for (idx, m) in visible_mods.iter().enumerate() {
                         let installed_entry = installed_by_cf.get(&m.id);
                         let removing_match =
                             removing_id.as_deref() == installed_entry.map(|i| i.id.as_str());
-                        elevated_frame(colors).show(ui, |ui| {
+                        let downloading = self.downloading_mod_ids.contains(&m.id);
+                        let selected = self.selected_mod_index == Some(idx);
+                        let frame = if selected {
+                            elevated_frame(colors).stroke(Stroke::new(1.5, colors.accent))
+                        } else {
+                            elevated_frame(colors)
+                        };
+                        let card = frame.show(ui, |ui| {
                             ui.vertical(|ui| {
                                 let downloads = format_downloads(m.downloadCount);
                                 let updated = format_mod_date(&m.dateModified);
                                 let authors = format_authors(&m.authors);
 
+                                let compact = self.mod_density == ModDensity::Compact;
                                 ui.horizontal(|ui| {
                                     let url = mod_page_url(m);
                                     ui.hyperlink_to(RichText::new(&m.name).strong(), url);
+                                    if compact {
+                                        ui.label(
+                                            RichText::new(i18n.mods_downloads(&downloads))
+                                                .color(colors.text_muted)
+                                                .small(),
+                                        );
+                                    }
                                     ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                                        let just_copied = self
+                                            .copied_mod_url_id
+                                            .is_some_and(|(id, at)| {
+                                                id == m.id && at.elapsed() < COPY_FEEDBACK_DURATION
+                                            });
+                                        if just_copied {
+                                            ui.label(
+                                                RichText::new(i18n.mods_link_copied())
+                                                    .color(colors.accent)
+                                                    .small(),
+                                            );
+                                            if let Some((_, at)) = self.copied_mod_url_id {
+                                                ui.ctx().request_repaint_after(
+                                                    COPY_FEEDBACK_DURATION
+                                                        .saturating_sub(at.elapsed()),
+                                                );
+                                            }
+                                        } else if ui
+                                            .button(i18n.mods_copy_link_button())
+                                            .on_hover_text(i18n.mods_copy_link_hint())
+                                            .clicked()
+                                        {
+                                            ui.ctx().copy_text(mod_page_url(m));
+                                            self.copied_mod_url_id = Some((m.id, Instant::now()));
+                                        }
                                         if let Some(installed) = installed_entry {
                                             let remove_btn =
                                                 egui::Button::new(i18n.mods_remove_button())
@@ -1840,30 +4108,38 @@ impl LauncherApp {
                                                 .add_enabled(!remove_locked && !busy, remove_btn)
                                                 .clicked()
                                             {
-                                                self.start_remove_installed_mod(
+                                                self.pending_remove_mod = Some((
                                                     installed.id.clone(),
-                                                );
+                                                    installed.name.clone(),
+                                                ));
                                             }
                                             if busy {
                                                 ui.add(egui::Spinner::new());
                                             }
-                                        } else if ui
-                                            .add_enabled(
-                                                can_install_mods,
-                                                egui::Button::new(i18n.mods_install_button())
-                                                    .fill(colors.accent)
-                                                    .stroke(Stroke::new(1.0, colors.accent_glow))
-                                                    .min_size(Vec2::new(96.0, 30.0)),
-                                            )
-                                            .clicked()
-                                        {
-                                            self.trigger_action(UserAction::DownloadMod {
-                                                mod_id: m.id,
-                                            });
+                                        } else {
+                                            if ui
+                                                .add_enabled(
+                                                    can_install_mods && !downloading,
+                                                    egui::Button::new(i18n.mods_install_button())
+                                                        .fill(colors.accent)
+                                                        .stroke(Stroke::new(1.0, colors.accent_glow))
+                                                        .min_size(Vec2::new(96.0, 30.0)),
+                                                )
+                                                .clicked()
+                                            {
+                                                self.queue_mod_download(m.id);
+                                            }
+                                            if downloading {
+                                                ui.add(egui::Spinner::new());
+                                            }
                                         }
                                     });
                                 });
 
+                                if compact {
+                                    return;
+                                }
+
                                 ui.add_space(4.0);
                                 ui.horizontal_wrapped(|ui| {
                                     for category in m.categories.iter().take(2) {
@@ -1906,11 +4182,58 @@ impl LauncherApp {
                                 ui.label(RichText::new(&m.summary).color(colors.text_muted));
                             });
                         });
+                        if selected {
+                            card.response.scroll_to_me(Some(egui::Align::Center));
+                        }
                     }
                 });
         });
     }
 
+    fn handle_dropped_mod_files(&mut self, ctx: &egui::Context, colors: &ThemePalette, i18n: I18n) {
+        let mod_actions_locked = matches!(
+            self.state,
+            AppState::Downloading { .. }
+                | AppState::PreparingRuntime { .. }
+                | AppState::CheckingForUpdates
+                | AppState::Uninstalling { .. }
+                | AppState::Playing
+        );
+        let can_install_mods = self.game_installed() && !mod_actions_locked && !self.installed_loading;
+
+        let hovering = ctx.input(|i| !i.raw.hovered_files.is_empty());
+        if hovering {
+            paint_drop_overlay(ctx, colors, i18n, can_install_mods);
+        }
+
+        let dropped = ctx.input(|i| i.raw.dropped_files.clone());
+        if dropped.is_empty() {
+            return;
+        }
+
+        if !can_install_mods {
+            self.installed_error = Some(i18n.mods_drop_disabled().to_owned());
+            return;
+        }
+
+        let mut archive_paths = Vec::new();
+        let mut rejected = false;
+        for file in dropped {
+            match file.path {
+                Some(path) if is_mod_archive_path(&path) => archive_paths.push(path),
+                _ => rejected = true,
+            }
+        }
+
+        if !archive_paths.is_empty() {
+            self.start_import_mod_files(archive_paths);
+        }
+        if rejected {
+            self.push_toast(i18n.mods_drop_rejected().to_owned(), Severity::Warning);
+            self.installed_error = Some(i18n.mods_drop_rejected().to_owned());
+        }
+    }
+
     fn open_mod_file_picker(&mut self, can_install_mods: bool) {
         if !can_install_mods {
             self.installed_error = Some(self.i18n().mods_drop_disabled().to_string());
@@ -1942,6 +4265,14 @@ impl LauncherApp {
     ) {
         ui.horizontal(|ui| {
             ui.heading(i18n.mods_installed_heading());
+            if !self.installed_mods.is_empty() {
+                let total_bytes: u64 = self.installed_mods.iter().map(|m| m.size_bytes).sum();
+                ui.label(
+                    RichText::new(i18n.mods_total_size(&format_size(total_bytes)))
+                        .color(colors.text_muted)
+                        .small(),
+                );
+            }
             if self.installed_loading {
                 ui.add(egui::Spinner::new());
             } else if ui
@@ -1955,12 +4286,144 @@ impl LauncherApp {
             {
                 self.start_load_installed_mods();
             }
+            if ui
+                .add(
+                    egui::Button::new(i18n.open_mods_folder_button())
+                        .fill(colors.surface_elev)
+                        .stroke(Stroke::new(1.0, colors.border_strong))
+                        .min_size(Vec2::new(140.0, 28.0)),
+                )
+                .clicked()
+            {
+                self.trigger_action(UserAction::OpenModsFolder);
+            }
+            if self.reconciling_mods {
+                ui.add(egui::Spinner::new());
+            } else if ui
+                .add(
+                    egui::Button::new(i18n.mods_repair_button())
+                        .fill(colors.surface_elev)
+                        .stroke(Stroke::new(1.0, colors.border_strong))
+                        .min_size(Vec2::new(120.0, 28.0)),
+                )
+                .clicked()
+            {
+                self.start_reconcile_mods();
+            }
+            if self.exporting_mods {
+                ui.add(egui::Spinner::new());
+            } else if ui
+                .add_enabled(
+                    !self.installed_mods.is_empty(),
+                    egui::Button::new(i18n.mods_export_button())
+                        .fill(colors.surface_elev)
+                        .stroke(Stroke::new(1.0, colors.border_strong))
+                        .min_size(Vec2::new(120.0, 28.0)),
+                )
+                .clicked()
+            {
+                self.start_export_mods();
+            }
+            if self.importing_mod_list {
+                ui.add(egui::Spinner::new());
+            } else if ui
+                .add(
+                    egui::Button::new(i18n.mods_import_button())
+                        .fill(colors.surface_elev)
+                        .stroke(Stroke::new(1.0, colors.border_strong))
+                        .min_size(Vec2::new(120.0, 28.0)),
+                )
+                .clicked()
+            {
+                self.open_mod_manifest_import_dialog();
+            }
+            if ui
+                .add_enabled(
+                    !self.installed_mods.is_empty() && !self.installed_loading,
+                    egui::Button::new(i18n.mods_remove_all_button())
+                        .fill(tint(colors.danger, 40))
+                        .stroke(Stroke::new(1.0, colors.danger))
+                        .min_size(Vec2::new(150.0, 28.0)),
+                )
+                .clicked()
+            {
+                self.show_remove_all_mods_confirm = true;
+            }
         });
 
+        ui.horizontal(|ui| {
+            ui.label(i18n.mods_url_input_label());
+            ui.add_enabled(
+                !self.installing_mod_url,
+                egui::TextEdit::singleline(&mut self.mod_url_input)
+                    .hint_text(i18n.mods_url_input_hint())
+                    .desired_width(320.0),
+            );
+            if self.installing_mod_url {
+                ui.add(egui::Spinner::new());
+            } else if ui
+                .add_enabled(
+                    !self.mod_url_input.trim().is_empty(),
+                    egui::Button::new(i18n.mods_url_install_button())
+                        .fill(colors.surface_elev)
+                        .stroke(Stroke::new(1.0, colors.border_strong))
+                        .min_size(Vec2::new(100.0, 26.0)),
+                )
+                .clicked()
+            {
+                self.start_install_mod_from_url();
+            }
+        });
+
+        if let Some(report) = &self.mod_reconcile_report {
+            let text = if report.is_clean() {
+                i18n.mods_repair_clean().to_owned()
+            } else {
+                i18n.mods_repair_summary(report.removed_missing.len(), report.untracked_files.len())
+            };
+            ui.label(RichText::new(text).color(colors.text_muted).small());
+            ui.add_space(4.0);
+        }
         if let Some(err) = &self.installed_error {
             ui.colored_label(colors.danger, i18n.mods_installed_error(err));
             ui.add_space(4.0);
         }
+        if let Some(err) = &self.mod_download_error {
+            ui.colored_label(colors.danger, i18n.mods_download_failed(err));
+            ui.add_space(4.0);
+        }
+        if let Some(err) = &self.mod_show_file_error {
+            ui.colored_label(colors.danger, i18n.mods_show_file_error(err));
+            ui.add_space(4.0);
+        }
+        if let Some(ids) = &self.mod_dependency_warning {
+            ui.colored_label(colors.warning, i18n.mods_missing_dependencies(ids));
+            ui.add_space(4.0);
+        }
+        if self.mod_download_total > 0 {
+            ui.horizontal(|ui| {
+                ui.add(egui::Spinner::new());
+                ui.label(
+                    RichText::new(i18n.mods_installing_progress(
+                        self.mod_download_completed,
+                        self.mod_download_total,
+                    ))
+                    .color(colors.text_muted),
+                );
+                if ui
+                    .add(
+                        egui::Button::new(i18n.mods_cancel_installs())
+                            .fill(colors.surface_elev)
+                            .stroke(Stroke::new(1.0, colors.border_strong))
+                            .min_size(Vec2::new(100.0, 26.0)),
+                    )
+                    .clicked()
+                {
+                    self.cancel_mod_downloads();
+                }
+            });
+            ui.add_space(4.0);
+        }
 
         ui.add_space(6.0);
 
@@ -1976,10 +4439,65 @@ impl LauncherApp {
             return;
         }
 
-        ui.add_space(4.0);
+        ui.horizontal_wrapped(|ui| {
+            let filter_hint = i18n.installed_mod_filter_hint();
+            ui.add_sized(
+                Vec2::new(220.0, 30.0),
+                egui::TextEdit::singleline(&mut self.installed_mod_filter)
+                    .hint_text(filter_hint)
+                    .vertical_align(Align::Center),
+            );
+            ui.add_space(8.0);
+            egui::ComboBox::from_id_salt("installed_mod_sort")
+                .selected_text(i18n.installed_mod_sort_label(self.installed_mod_sort))
+                .show_ui(ui, |ui| {
+                    for option in [
+                        InstalledModSort::Name,
+                        InstalledModSort::InstallDate,
+                        InstalledModSort::Size,
+                    ] {
+                        ui.selectable_value(
+                            &mut self.installed_mod_sort,
+                            option,
+                            i18n.installed_mod_sort_label(option),
+                        );
+                    }
+                });
+        });
+        ui.add_space(6.0);
+
         let removing_id = self.removing_mod.clone();
         let remove_locked = mod_actions_locked || self.installed_loading;
-        let installed_list = self.installed_mods.clone();
+        let filter = self.installed_mod_filter.trim().to_lowercase();
+        let mut installed_list: Vec<InstalledMod> = self
+            .installed_mods
+            .iter()
+            .filter(|m| {
+                filter.is_empty()
+                    || m.name.to_lowercase().contains(&filter)
+                    || m.author.to_lowercase().contains(&filter)
+                    || m.category
+                        .as_deref()
+                        .is_some_and(|category| category.to_lowercase().contains(&filter))
+            })
+            .cloned()
+            .collect();
+        match self.installed_mod_sort {
+            InstalledModSort::Name => {
+                installed_list.sort_by_key(|m| m.name.to_lowercase());
+            }
+            InstalledModSort::InstallDate => {
+                installed_list.sort_by_key(|m| std::cmp::Reverse(parse_installed_at(m)));
+            }
+            InstalledModSort::Size => {
+                installed_list.sort_by_key(|m| std::cmp::Reverse(m.size_bytes));
+            }
+        }
+        if installed_list.is_empty() && !filter.is_empty() {
+            ui.label(RichText::new(i18n.mods_installed_no_matches()).color(colors.text_faint));
+            ui.add_space(6.0);
+        }
+        let compact = self.mod_density == ModDensity::Compact;
         for installed in installed_list {
             elevated_frame(colors).show(ui, |ui| {
                 ui.vertical(|ui| {
@@ -1990,6 +4508,13 @@ impl LauncherApp {
                                 .color(colors.text_muted)
                                 .small(),
                         );
+                        if compact {
+                            ui.label(
+                                RichText::new(i18n.mods_size(&format_size(installed.size_bytes)))
+                                    .color(colors.text_muted)
+                                    .small(),
+                            );
+                        }
                         ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
                             let busy = removing_id.as_deref() == Some(&installed.id);
                             let remove_btn = egui::Button::new(i18n.mods_remove_button())
@@ -2000,13 +4525,30 @@ impl LauncherApp {
                                 .add_enabled(!remove_locked && !busy, remove_btn)
                                 .clicked()
                             {
-                                self.start_remove_installed_mod(installed.id.clone());
+                                self.pending_remove_mod =
+                                    Some((installed.id.clone(), installed.name.clone()));
                             }
                             if busy {
                                 ui.add(egui::Spinner::new());
                             }
+                            let show_file_btn = egui::Button::new(i18n.mods_show_file_button())
+                                .min_size(Vec2::new(88.0, 26.0));
+                            if ui.add(show_file_btn).clicked() {
+                                let file = std::path::Path::new(&installed.file_path);
+                                match crate::util::reveal_in_file_manager(file) {
+                                    Ok(()) => self.mod_show_file_error = None,
+                                    Err(err) => self.mod_show_file_error = Some(err),
+                                }
+                            }
                         });
                     });
+                    if compact {
+                        return;
+                    }
+                    let applied = installed
+                        .applied_at
+                        .as_deref()
+                        .is_some_and(|applied_at| applied_at >= installed.updated_at.as_str());
                     ui.horizontal_wrapped(|ui| {
                         meta_chip_frame(colors).show(ui, |ui| {
                             ui.label(
@@ -2024,17 +4566,150 @@ impl LauncherApp {
                                 );
                             });
                         }
+                        meta_chip_frame(colors).show(ui, |ui| {
+                            ui.label(
+                                RichText::new(i18n.mods_size(&format_size(installed.size_bytes)))
+                                    .color(colors.text_muted)
+                                    .small(),
+                            );
+                        });
+                        if applied {
+                            chip_frame(colors.accent_soft).show(ui, |ui| {
+                                ui.label(
+                                    RichText::new(i18n.mods_applied_badge())
+                                        .color(colors.accent_glow)
+                                        .small(),
+                                );
+                            });
+                        } else {
+                            chip_frame(colors.warning).show(ui, |ui| {
+                                ui.label(
+                                    RichText::new(i18n.mods_pending_badge())
+                                        .color(colors.text_primary)
+                                        .small(),
+                                );
+                            });
+                        }
                     });
+                    if !applied {
+                        ui.label(
+                            RichText::new(i18n.mods_pending_relaunch_hint())
+                                .color(colors.text_muted)
+                                .small(),
+                        );
+                    }
                     ui.add_space(4.0);
                     ui.label(
                         RichText::new(&installed.description)
                             .color(colors.text_muted)
                             .small(),
                     );
+                    if installed.curseforge_id >= 0
+                        && !matches!(self.mod_changelog_cache.get(&installed.id), Some(Ok(None)))
+                    {
+                        ui.add_space(4.0);
+                        egui::CollapsingHeader::new(i18n.mods_whats_new_header())
+                            .id_salt(("mod_changelog", &installed.id))
+                            .show(ui, |ui| match self.mod_changelog_cache.get(&installed.id) {
+                                Some(Ok(Some(changelog))) => {
+                                    if changelog.is_empty() {
+                                        ui.label(
+                                            RichText::new(i18n.mods_changelog_empty())
+                                                .color(colors.text_muted)
+                                                .small(),
+                                        );
+                                    } else {
+                                        ui.label(RichText::new(changelog).small());
+                                    }
+                                }
+                                Some(Ok(None)) => unreachable!("filtered out above"),
+                                Some(Err(err)) => {
+                                    ui.colored_label(colors.danger, i18n.mods_changelog_failed(err));
+                                }
+                                None => {
+                                    if !self.mod_changelog_loading.contains(&installed.id) {
+                                        self.start_mod_changelog_fetch(
+                                            installed.id.clone(),
+                                            installed.curseforge_id,
+                                            installed.file_id,
+                                        );
+                                    }
+                                    ui.add(egui::Spinner::new());
+                                }
+                            });
+                    }
                 });
             });
         }
         ui.add_space(6.0);
+
+        if let Some((mod_id, mod_name)) = self.pending_remove_mod.clone() {
+            egui::Window::new(i18n.remove_mod_confirm_title())
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, Vec2::ZERO)
+                .show(ui.ctx(), |ui| {
+                    ui.label(i18n.remove_mod_confirm_body(&mod_name));
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        if ui
+                            .add(
+                                egui::Button::new(i18n.remove_mod_confirm_yes())
+                                    .fill(tint(colors.danger, 60))
+                                    .stroke(Stroke::new(1.0, colors.danger)),
+                            )
+                            .clicked()
+                        {
+                            self.pending_remove_mod = None;
+                            self.start_remove_installed_mod(mod_id);
+                        }
+                        if ui
+                            .add(
+                                egui::Button::new(i18n.uninstall_confirm_no())
+                                    .fill(colors.surface_elev)
+                                    .stroke(Stroke::new(1.0, colors.border_strong)),
+                            )
+                            .clicked()
+                        {
+                            self.pending_remove_mod = None;
+                        }
+                    });
+                });
+        }
+
+        if self.show_remove_all_mods_confirm {
+            egui::Window::new(i18n.remove_all_mods_confirm_title())
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, Vec2::ZERO)
+                .show(ui.ctx(), |ui| {
+                    ui.label(i18n.remove_all_mods_confirm_body());
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        if ui
+                            .add(
+                                egui::Button::new(i18n.remove_mod_confirm_yes())
+                                    .fill(tint(colors.danger, 60))
+                                    .stroke(Stroke::new(1.0, colors.danger)),
+                            )
+                            .clicked()
+                        {
+                            self.show_remove_all_mods_confirm = false;
+                            self.start_remove_all_mods();
+                        }
+                        if ui
+                            .add(
+                                egui::Button::new(i18n.uninstall_confirm_no())
+                                    .fill(colors.surface_elev)
+                                    .stroke(Stroke::new(1.0, colors.border_strong)),
+                            )
+                            .clicked()
+                        {
+                            self.show_remove_all_mods_confirm = false;
+                        }
+                    });
+                });
+        }
     }
 
     fn render_control_inputs(&mut self, ui: &mut egui::Ui, colors: &ThemePalette, i18n: I18n) {
@@ -2072,17 +4747,137 @@ impl LauncherApp {
         let auth_offline_label = i18n.auth_mode_value(AuthMode::Offline);
         let auth_online_label = i18n.auth_mode_value(AuthMode::Online);
 
+        let previous_auth_mode = self.auth_mode;
         ui.horizontal(|ui| {
             ui.label(RichText::new(i18n.auth_mode_label()).color(colors.text_muted));
             egui::ComboBox::from_id_salt("auth_mode_combo")
                 .selected_text(auth_label)
                 .show_ui(ui, |ui| {
-                    ui.selectable_value(&mut self.auth_mode, AuthMode::Offline, auth_offline_label);
-                    ui.selectable_value(&mut self.auth_mode, AuthMode::Online, auth_online_label);
-                });
+                    ui.selectable_value(&mut self.auth_mode, AuthMode::Offline, auth_offline_label)
+                        .on_hover_text(i18n.auth_mode_hint(AuthMode::Offline));
+                    ui.selectable_value(&mut self.auth_mode, AuthMode::Online, auth_online_label)
+                        .on_hover_text(i18n.auth_mode_hint(AuthMode::Online));
+                })
+                .response
+                .on_hover_text(i18n.auth_mode_hint(self.auth_mode));
         });
+        if self.auth_mode != previous_auth_mode {
+            self.save_current_profile();
+        }
         ui.add_space(10.0);
 
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(i18n.extra_launch_args_label()).color(colors.text_muted));
+            let placeholder = i18n.extra_launch_args_placeholder();
+            let resp = ui.add_sized(
+                Vec2::new(260.0, 36.0),
+                egui::TextEdit::singleline(&mut self.extra_launch_args)
+                    .hint_text(placeholder)
+                    .vertical_align(Align::Center),
+            );
+            if resp.changed() {
+                self.commit_extra_launch_args();
+            }
+            let preview = match tokenize_launch_args(&self.extra_launch_args) {
+                Ok(tokens) => i18n.extra_launch_args_preview(&tokens),
+                Err(err) => i18n.extra_launch_args_error(&err),
+            };
+            resp.on_hover_text(preview);
+        });
+        if let Some(err) = &self.extra_launch_args_error {
+            ui.colored_label(colors.danger, i18n.extra_launch_args_error(err));
+        }
+        ui.add_space(6.0);
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(i18n.memory_settings_label()).color(colors.text_muted));
+            let min_placeholder = i18n.min_memory_placeholder();
+            let min_resp = ui.add_sized(
+                Vec2::new(70.0, 36.0),
+                egui::TextEdit::singleline(&mut self.min_memory_input)
+                    .hint_text(min_placeholder)
+                    .vertical_align(Align::Center),
+            );
+            let max_placeholder = i18n.max_memory_placeholder();
+            let max_resp = ui.add_sized(
+                Vec2::new(70.0, 36.0),
+                egui::TextEdit::singleline(&mut self.max_memory_input)
+                    .hint_text(max_placeholder)
+                    .vertical_align(Align::Center),
+            );
+            if min_resp.changed() || max_resp.changed() {
+                self.memory_settings_error = None;
+            }
+            let apply_clicked = ui
+                .add(
+                    egui::Button::new(i18n.memory_settings_apply_button())
+                        .fill(colors.accent_soft)
+                        .stroke(Stroke::new(1.0, colors.accent))
+                        .min_size(Vec2::new(72.0, 28.0)),
+                )
+                .clicked();
+            let enter_pressed = (min_resp.has_focus() || max_resp.has_focus())
+                && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if apply_clicked || enter_pressed {
+                self.apply_memory_settings();
+            }
+        });
+        if let Some(err) = &self.memory_settings_error {
+            ui.colored_label(colors.danger, err);
+        }
+        ui.add_space(6.0);
+
+        let previous_gc = self.gc;
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(i18n.gc_label()).color(colors.text_muted));
+            egui::ComboBox::from_id_salt("gc_combo")
+                .selected_text(i18n.gc_value(self.gc))
+                .show_ui(ui, |ui| {
+                    for gc in [
+                        GarbageCollector::Default,
+                        GarbageCollector::G1,
+                        GarbageCollector::Zgc,
+                        GarbageCollector::Shenandoah,
+                    ] {
+                        ui.selectable_value(&mut self.gc, gc, i18n.gc_value(gc));
+                    }
+                })
+                .response
+                .on_hover_text(i18n.gc_newer_java_note());
+            if matches!(self.gc, GarbageCollector::Zgc | GarbageCollector::Shenandoah) {
+                ui.colored_label(colors.warning, i18n.gc_newer_java_note());
+            }
+        });
+        if self.gc != previous_gc
+            && let Err(err) = save_gc_to_file(self.gc)
+        {
+            warn!("failed to persist garbage collector choice: {err}");
+        }
+        ui.add_space(6.0);
+
+        ui.horizontal_wrapped(|ui| match self.installed_version {
+            Some(installed) => {
+                let pending_change =
+                    self.selected_version.is_some_and(|selected| selected != installed);
+                let color = if pending_change { colors.warning } else { colors.text_muted };
+                ui.label(RichText::new(i18n.installed_version_label(installed)).color(color));
+                if pending_change {
+                    ui.label(
+                        RichText::new(i18n.installed_version_pending_change())
+                            .color(colors.warning)
+                            .small(),
+                    );
+                }
+            }
+            None => {
+                ui.label(
+                    RichText::new(i18n.installed_version_none())
+                        .color(colors.text_muted)
+                        .small(),
+                );
+            }
+        });
+
         ui.horizontal_wrapped(|ui| {
             ui.label(RichText::new(i18n.version_label()).color(colors.text_muted));
             let latest_label = i18n.version_latest(self.available_versions.first().copied());
@@ -2096,16 +4891,39 @@ impl LauncherApp {
                 .map(|version| i18n.version_value(version))
                 .unwrap_or_else(|| latest_label.clone());
             let previous = self.selected_version;
+            let offline = self.version_fetch_error.is_some();
+            let cached_version = self.current_ready_version();
             egui::ComboBox::from_id_salt("version_combo")
                 .selected_text(selected_text)
                 .show_ui(ui, |ui| {
-                    ui.selectable_value(&mut self.selected_version, None, latest_label.clone());
+                    let latest_enabled = !offline || cached_version.is_none();
+                    let latest_resp = ui
+                        .add_enabled_ui(latest_enabled, |ui| {
+                            ui.selectable_value(
+                                &mut self.selected_version,
+                                None,
+                                latest_label.clone(),
+                            )
+                        })
+                        .inner;
+                    if !latest_enabled {
+                        latest_resp
+                            .on_disabled_hover_text(i18n.version_unavailable_offline_hint());
+                    }
                     for (version, label) in &version_labels {
-                        ui.selectable_value(
-                            &mut self.selected_version,
-                            Some(*version),
-                            label.clone(),
-                        );
+                        let enabled = !offline || cached_version == Some(*version);
+                        let resp = ui
+                            .add_enabled_ui(enabled, |ui| {
+                                ui.selectable_value(
+                                    &mut self.selected_version,
+                                    Some(*version),
+                                    label.clone(),
+                                )
+                            })
+                            .inner;
+                        if !enabled {
+                            resp.on_disabled_hover_text(i18n.version_unavailable_offline_hint());
+                        }
                     }
                 });
             self.sync_version_selection(previous);
@@ -2153,23 +4971,102 @@ impl LauncherApp {
         if let Some(err) = &self.version_fetch_error {
             ui.colored_label(colors.danger, i18n.version_fetch_error(err));
         }
+        if self.is_unsupported_platform() {
+            let (os, arch) = crate::pwr::platform_keys();
+            ui.colored_label(colors.danger, i18n.unsupported_platform_message(os, arch));
+            if ui
+                .add(
+                    egui::Button::new(i18n.file_issue_button())
+                        .fill(colors.surface_elev)
+                        .stroke(Stroke::new(1.0, colors.border_strong))
+                        .min_size(Vec2::new(CONTROL_BUTTON_WIDTH, CTA_HEIGHT)),
+                )
+                .clicked()
+            {
+                ui.ctx().open_url(egui::OpenUrl {
+                    url: "https://github.com/RustedBytes/hrs-launcher/issues/new".into(),
+                    new_tab: true,
+                });
+            }
+        } else if self.no_versions_available() {
+            ui.colored_label(colors.danger, i18n.no_versions_found_message());
+            if ui
+                .add(
+                    egui::Button::new(i18n.run_diagnostics_button())
+                        .fill(colors.surface_elev)
+                        .stroke(Stroke::new(1.0, colors.border_strong))
+                        .min_size(Vec2::new(CONTROL_BUTTON_WIDTH, CTA_HEIGHT)),
+                )
+                .clicked()
+            {
+                self.trigger_action(UserAction::RunDiagnostics);
+            }
+        }
         if let Some(err) = &self.version_input_error {
             ui.colored_label(colors.danger, err);
         }
+        ui.add_space(6.0);
+
+        let previous_tray_enabled = self.tray_enabled;
+        ui.checkbox(&mut self.tray_enabled, i18n.tray_enabled_toggle())
+            .on_hover_text(i18n.tray_enabled_hint());
+        if self.tray_enabled != previous_tray_enabled {
+            self.sync_tray();
+            if let Err(err) = save_tray_enabled_to_file(self.tray_enabled) {
+                warn!("failed to persist tray icon setting: {err}");
+            }
+        }
+
+        let previous_minimize_to_tray = self.minimize_to_tray;
+        ui.checkbox(&mut self.minimize_to_tray, i18n.minimize_to_tray_toggle())
+            .on_hover_text(i18n.minimize_to_tray_hint());
+        if self.minimize_to_tray != previous_minimize_to_tray
+            && let Err(err) = save_minimize_to_tray_to_file(self.minimize_to_tray)
+        {
+            warn!("failed to persist minimize-to-tray setting: {err}");
+        }
+
+        ui.add_space(6.0);
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(i18n.ui_scale_label()).color(colors.text_muted));
+            let previous_scale = self.ui_scale;
+            if ui.button("-").clicked() {
+                self.ui_scale = (self.ui_scale - 0.1).clamp(UI_SCALE_MIN, UI_SCALE_MAX);
+            }
+            ui.add(
+                egui::Slider::new(&mut self.ui_scale, UI_SCALE_MIN..=UI_SCALE_MAX)
+                    .fixed_decimals(2)
+                    .show_value(true),
+            )
+            .on_hover_text(i18n.ui_scale_hint());
+            if ui.button("+").clicked() {
+                self.ui_scale = (self.ui_scale + 0.1).clamp(UI_SCALE_MIN, UI_SCALE_MAX);
+            }
+            if self.ui_scale != previous_scale
+                && let Err(err) = save_ui_scale_to_file(self.ui_scale)
+            {
+                warn!("failed to persist ui scale setting: {err}");
+            }
+        });
     }
 
     fn render_control_actions(&mut self, ui: &mut egui::Ui, colors: &ThemePalette, i18n: I18n) {
         let is_fetching = matches!(
             self.state,
-            AppState::Downloading { .. } | AppState::CheckingForUpdates
+            AppState::Downloading { .. }
+                | AppState::PreparingRuntime { .. }
+                | AppState::CheckingForUpdates
         );
         ui.horizontal_wrapped(|ui| {
             let download_btn = egui::Button::new(i18n.download_button())
                 .fill(colors.accent_soft)
                 .stroke(Stroke::new(1.0, colors.accent))
                 .min_size(Vec2::new(CONTROL_BUTTON_WIDTH, CTA_HEIGHT));
-            if ui.add_enabled(!is_fetching, download_btn).clicked() {
-                self.trigger_action(UserAction::DownloadGame {
+            if ui
+                .add_enabled(!is_fetching && !self.no_versions_available(), download_btn)
+                .clicked()
+            {
+                self.trigger_version_change(UserAction::DownloadGame {
                     target_version: self.selected_version,
                 });
             }
@@ -2179,12 +5076,15 @@ impl LauncherApp {
                 .stroke(Stroke::new(1.0, colors.border_strong))
                 .min_size(Vec2::new(CONTROL_BUTTON_WIDTH, CTA_HEIGHT));
             if ui.add_enabled(!is_fetching, check_btn).clicked() {
-                self.trigger_action(UserAction::CheckForUpdates {
+                self.trigger_version_change(UserAction::CheckForUpdates {
                     target_version: self.selected_version,
                 });
             }
 
-            if matches!(self.state, AppState::Downloading { .. }) {
+            if matches!(
+                self.state,
+                AppState::Downloading { .. } | AppState::PreparingRuntime { .. }
+            ) {
                 let cancel_btn = egui::Button::new(i18n.cancel_button())
                     .fill(tint(colors.danger, 40))
                     .stroke(Stroke::new(1.0, colors.danger))
@@ -2210,6 +5110,34 @@ impl LauncherApp {
                 self.trigger_action(UserAction::RunDiagnostics);
             }
 
+            let testing_java = matches!(self.state, AppState::TestingJava);
+            if ui
+                .add_enabled(
+                    !testing_java,
+                    egui::Button::new(i18n.test_java_button())
+                        .fill(colors.surface_elev)
+                        .stroke(Stroke::new(1.0, colors.border_strong))
+                        .min_size(Vec2::new(CONTROL_BUTTON_WIDTH, CTA_HEIGHT)),
+                )
+                .clicked()
+            {
+                self.trigger_action(UserAction::TestJava);
+            }
+
+            let creating_crash_report = matches!(self.state, AppState::CreatingCrashReport);
+            if ui
+                .add_enabled(
+                    !creating_crash_report,
+                    egui::Button::new(i18n.create_crash_report_button())
+                        .fill(colors.surface_elev)
+                        .stroke(Stroke::new(1.0, colors.border_strong))
+                        .min_size(Vec2::new(CONTROL_BUTTON_WIDTH, CTA_HEIGHT)),
+                )
+                .clicked()
+            {
+                self.trigger_action(UserAction::CreateCrashReport);
+            }
+
             let open_enabled = env::game_latest_dir().exists();
             if ui
                 .add_enabled(
@@ -2230,10 +5158,13 @@ impl LauncherApp {
         let is_busy = matches!(
             self.state,
             AppState::Downloading { .. }
+                | AppState::PreparingRuntime { .. }
                 | AppState::CheckingForUpdates
                 | AppState::DiagnosticsRunning
+                | AppState::TestingJava
+                | AppState::CreatingCrashReport
                 | AppState::Playing
-                | AppState::Uninstalling
+                | AppState::Uninstalling { .. }
                 | AppState::Initialising
         );
         ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
@@ -2247,14 +5178,30 @@ impl LauncherApp {
                 )
                 .clicked();
             if uninstall_clicked {
+                self.uninstall_keep_jre = false;
+                self.uninstall_keep_user_data = false;
                 self.show_uninstall_confirm = true;
             }
+
+            let reinstall_clicked = ui
+                .add_enabled(
+                    !is_busy && self.game_installed(),
+                    egui::Button::new(i18n.reinstall_button())
+                        .fill(colors.surface_elev)
+                        .stroke(Stroke::new(1.0, colors.border_strong))
+                        .min_size(Vec2::new(CONTROL_BUTTON_WIDTH, CTA_HEIGHT)),
+                )
+                .clicked();
+            if reinstall_clicked {
+                self.reinstall_clear_cache = false;
+                self.show_reinstall_confirm = true;
+            }
         });
     }
 
     fn render_controls(&mut self, ui: &mut egui::Ui, colors: &ThemePalette, i18n: I18n) {
         section_frame(colors).show(ui, |ui| {
-            ui.horizontal(|ui| {
+            ui.with_layout(row_layout(i18n), |ui| {
                 ui.heading(i18n.controls_heading());
                 ui.label(
                     RichText::new(i18n.controls_subheading())
@@ -2262,6 +5209,14 @@ impl LauncherApp {
                         .small(),
                 );
             });
+            let last_played_text = match self.last_played {
+                Some(timestamp) => i18n.last_played(&format_relative_time(timestamp, chrono::Utc::now())),
+                None => i18n.last_played_empty().to_owned(),
+            };
+            ui.label(RichText::new(last_played_text).color(colors.text_muted).small());
+            let play_time_text =
+                i18n.total_play_time(&format_play_time(crate::engine::read_total_play_time_seconds()));
+            ui.label(RichText::new(play_time_text).color(colors.text_muted).small());
             ui.add_space(10.0);
 
             let available = ui.available_width();
@@ -2305,6 +5260,9 @@ impl LauncherApp {
                 .show(ui.ctx(), |ui| {
                     ui.label(i18n.uninstall_confirm_body());
                     ui.add_space(10.0);
+                    ui.checkbox(&mut self.uninstall_keep_user_data, i18n.uninstall_keep_user_data_checkbox());
+                    ui.checkbox(&mut self.uninstall_keep_jre, i18n.uninstall_keep_jre_checkbox());
+                    ui.add_space(10.0);
                     ui.horizontal(|ui| {
                         if ui
                             .add(
@@ -2315,7 +5273,10 @@ impl LauncherApp {
                             .clicked()
                         {
                             self.show_uninstall_confirm = false;
-                            self.trigger_action(UserAction::UninstallGame);
+                            self.trigger_action(UserAction::UninstallGame {
+                                keep_jre: self.uninstall_keep_jre,
+                                keep_user_data: self.uninstall_keep_user_data,
+                            });
                         }
                         if ui
                             .add(
@@ -2330,6 +5291,79 @@ impl LauncherApp {
                     });
                 });
         }
+
+        if self.show_reinstall_confirm {
+            egui::Window::new(i18n.reinstall_confirm_title())
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, Vec2::ZERO)
+                .show(ui.ctx(), |ui| {
+                    ui.label(i18n.reinstall_confirm_body());
+                    ui.add_space(10.0);
+                    ui.checkbox(&mut self.reinstall_clear_cache, i18n.reinstall_clear_cache_checkbox());
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        if ui
+                            .add(
+                                egui::Button::new(i18n.reinstall_confirm_yes())
+                                    .fill(tint(colors.danger, 60))
+                                    .stroke(Stroke::new(1.0, colors.danger)),
+                            )
+                            .clicked()
+                        {
+                            self.show_reinstall_confirm = false;
+                            self.trigger_action(UserAction::Reinstall {
+                                target_version: None,
+                                clear_cache: self.reinstall_clear_cache,
+                            });
+                        }
+                        if ui
+                            .add(
+                                egui::Button::new(i18n.uninstall_confirm_no())
+                                    .fill(colors.surface_elev)
+                                    .stroke(Stroke::new(1.0, colors.border_strong)),
+                            )
+                            .clicked()
+                        {
+                            self.show_reinstall_confirm = false;
+                        }
+                    });
+                });
+        }
+
+        if let Some((action, installed, target)) = self.pending_downgrade.clone() {
+            egui::Window::new(i18n.downgrade_confirm_title())
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, Vec2::ZERO)
+                .show(ui.ctx(), |ui| {
+                    ui.label(i18n.downgrade_confirm_body(installed, target));
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        if ui
+                            .add(
+                                egui::Button::new(i18n.downgrade_confirm_yes())
+                                    .fill(tint(colors.danger, 60))
+                                    .stroke(Stroke::new(1.0, colors.danger)),
+                            )
+                            .clicked()
+                        {
+                            self.pending_downgrade = None;
+                            self.trigger_action(action);
+                        }
+                        if ui
+                            .add(
+                                egui::Button::new(i18n.uninstall_confirm_no())
+                                    .fill(colors.surface_elev)
+                                    .stroke(Stroke::new(1.0, colors.border_strong)),
+                            )
+                            .clicked()
+                        {
+                            self.pending_downgrade = None;
+                        }
+                    });
+                });
+        }
     }
 
     fn render_diagnostics(&mut self, ui: &mut egui::Ui, colors: &ThemePalette, i18n: I18n) {
@@ -2350,9 +5384,174 @@ impl LauncherApp {
             } else {
                 ui.label(RichText::new(i18n.diagnostics_empty()).color(colors.text_muted));
             }
+            ui.add_space(6.0);
+            if let Some(output) = &self.java_test_output {
+                ui.label(RichText::new(i18n.java_test_result(output)).color(colors.text_muted));
+            } else {
+                ui.label(RichText::new(i18n.java_test_empty()).color(colors.text_muted));
+            }
+            ui.add_space(6.0);
+            if let Some(path) = &self.crash_report_path {
+                ui.label(RichText::new(i18n.crash_report_ready(path)).color(colors.text_muted));
+            }
+            ui.add_space(6.0);
+            if ui
+                .add(
+                    egui::Button::new(i18n.open_crashes_folder_button())
+                        .fill(colors.surface_elev)
+                        .stroke(Stroke::new(1.0, colors.border_strong))
+                        .min_size(Vec2::new(150.0, 28.0)),
+                )
+                .clicked()
+            {
+                self.trigger_action(UserAction::OpenCrashesFolder);
+            }
+            if debug_mode_enabled() {
+                ui.add_space(6.0);
+                if ui
+                    .add(
+                        egui::Button::new(i18n.open_cache_folder_button())
+                            .fill(colors.surface_elev)
+                            .stroke(Stroke::new(1.0, colors.border_strong))
+                            .min_size(Vec2::new(150.0, 28.0)),
+                    )
+                    .clicked()
+                {
+                    self.trigger_action(UserAction::OpenCacheFolder);
+                }
+            }
+            ui.add_space(6.0);
+            let previous_force_continuous_repaint = self.force_continuous_repaint;
+            ui.checkbox(
+                &mut self.force_continuous_repaint,
+                i18n.force_continuous_repaint_toggle(),
+            )
+            .on_hover_text(i18n.force_continuous_repaint_hint());
+            if self.force_continuous_repaint != previous_force_continuous_repaint
+                && let Err(err) =
+                    save_force_continuous_repaint_to_file(self.force_continuous_repaint)
+            {
+                warn!("failed to persist force-continuous-repaint setting: {err}");
+            }
+            if let Some(detected) = &self.detected_system_java {
+                ui.add_space(6.0);
+                let previous_use_system_java = self.use_system_java;
+                ui.checkbox(
+                    &mut self.use_system_java,
+                    i18n.use_system_java_toggle(),
+                )
+                .on_hover_text(i18n.use_system_java_hint(&detected.display().to_string()));
+                if self.use_system_java != previous_use_system_java {
+                    if let Err(err) = save_use_system_java_to_file(self.use_system_java) {
+                        warn!("failed to persist use-system-java setting: {err}");
+                    }
+                    self.trigger_action(UserAction::SetAllowSystemJava(self.use_system_java));
+                }
+            }
         });
     }
 
+    fn render_toasts(&mut self, ctx: &egui::Context, colors: &ThemePalette) {
+        self.toasts
+            .retain(|toast| toast.shown_at.elapsed() < TOAST_DURATION);
+        if self.toasts.is_empty() {
+            return;
+        }
+        if let Some(oldest) = self.toasts.iter().map(|t| t.shown_at).min() {
+            ctx.request_repaint_after(TOAST_DURATION.saturating_sub(oldest.elapsed()));
+        }
+
+        egui::Area::new(egui::Id::new("toast_stack"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, Vec2::new(-16.0, -16.0))
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    for toast in &self.toasts {
+                        Frame::new()
+                            .fill(colors.surface_elev)
+                            .stroke(Stroke::new(1.0, toast.severity.color(colors)))
+                            .corner_radius(CornerRadius::same(10))
+                            .inner_margin(Margin::symmetric(12, 8))
+                            .shadow(Shadow {
+                                offset: [0, 2],
+                                blur: 10,
+                                spread: 0,
+                                color: Color32::from_black_alpha(70),
+                            })
+                            .show(ui, |ui| {
+                                ui.set_max_width(320.0);
+                                ui.colored_label(toast.severity.color(colors), &toast.message);
+                            });
+                        ui.add_space(6.0);
+                    }
+                });
+            });
+    }
+
+    fn render_onboarding_modal(&mut self, ctx: &egui::Context, colors: &ThemePalette, i18n: I18n) {
+        if !self.show_onboarding {
+            return;
+        }
+
+        let mut dismissed = false;
+        let mut run_diagnostics = false;
+        egui::Window::new(i18n.onboarding_heading())
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, Vec2::ZERO)
+            .default_width(420.0)
+            .show(ctx, |ui| {
+                ui.label(RichText::new(i18n.onboarding_intro()).color(colors.text_muted));
+                ui.add_space(8.0);
+                for step in [
+                    i18n.onboarding_step_player_name(),
+                    i18n.onboarding_step_version(),
+                    i18n.onboarding_step_download(),
+                    i18n.onboarding_step_play(),
+                ] {
+                    ui.horizontal(|ui| {
+                        ui.colored_label(colors.accent, "•");
+                        ui.label(RichText::new(step).color(colors.text_primary));
+                    });
+                }
+                ui.add_space(12.0);
+                self.render_discord_button(ui, colors, i18n);
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add(
+                            egui::Button::new(i18n.run_diagnostics_button())
+                                .fill(colors.surface_elev)
+                                .stroke(Stroke::new(1.0, colors.border_strong)),
+                        )
+                        .clicked()
+                    {
+                        run_diagnostics = true;
+                    }
+                    if ui
+                        .add(
+                            egui::Button::new(i18n.onboarding_dismiss_button())
+                                .fill(colors.accent_soft)
+                                .stroke(Stroke::new(1.0, colors.accent)),
+                        )
+                        .clicked()
+                    {
+                        dismissed = true;
+                    }
+                });
+            });
+
+        if run_diagnostics {
+            self.trigger_action(UserAction::RunDiagnostics);
+        }
+        if dismissed || run_diagnostics {
+            self.show_onboarding = false;
+            if let Err(err) = save_onboarded_to_file() {
+                warn!("failed to persist onboarding flag: {err}");
+            }
+        }
+    }
+
     fn render_diagnostics_modal(&mut self, ctx: &egui::Context, colors: &ThemePalette, i18n: I18n) {
         if !self.show_diagnostics_modal {
             return;
@@ -2398,19 +5597,137 @@ impl LauncherApp {
             });
         self.show_diagnostics_modal = open && !close_requested;
     }
+
+    fn render_logs(&mut self, ui: &mut egui::Ui, colors: &ThemePalette, i18n: I18n) {
+        section_frame(colors).show(ui, |ui| {
+            ui.heading(i18n.logs_heading());
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                let view_btn = egui::Button::new(i18n.view_logs_button())
+                    .fill(colors.accent_soft)
+                    .stroke(Stroke::new(1.0, colors.accent))
+                    .min_size(Vec2::new(120.0, 28.0));
+                if ui.add(view_btn).clicked() {
+                    self.refresh_log_lines();
+                    self.show_logs_modal = true;
+                }
+                let open_folder_btn = egui::Button::new(i18n.open_logs_folder_button())
+                    .fill(colors.surface_elev)
+                    .stroke(Stroke::new(1.0, colors.border_strong))
+                    .min_size(Vec2::new(150.0, 28.0));
+                if ui.add(open_folder_btn).clicked() {
+                    self.trigger_action(UserAction::OpenLogsFolder);
+                }
+            });
+        });
+    }
+
+    fn refresh_log_lines(&mut self) {
+        let path = env::logs_dir().join("launcher.log");
+        let Ok(contents) = fs::read_to_string(&path) else {
+            self.log_lines = Vec::new();
+            self.last_log_refresh = Some(Instant::now());
+            return;
+        };
+        let mut lines: Vec<String> = contents.lines().map(str::to_owned).collect();
+        if lines.len() > LOG_VIEWER_MAX_LINES {
+            lines = lines.split_off(lines.len() - LOG_VIEWER_MAX_LINES);
+        }
+        self.log_lines = lines;
+        self.last_log_refresh = Some(Instant::now());
+    }
+
+    fn render_logs_modal(&mut self, ctx: &egui::Context, colors: &ThemePalette, i18n: I18n) {
+        if !self.show_logs_modal {
+            return;
+        }
+
+        let needs_refresh = self
+            .last_log_refresh
+            .is_none_or(|last| last.elapsed() >= LOG_VIEWER_REFRESH_INTERVAL);
+        if needs_refresh {
+            self.refresh_log_lines();
+        }
+        ctx.request_repaint_after(LOG_VIEWER_REFRESH_INTERVAL);
+
+        let mut open = self.show_logs_modal;
+        let mut close_requested = false;
+        egui::Window::new(i18n.logs_heading())
+            .collapsible(false)
+            .resizable(true)
+            .anchor(egui::Align2::CENTER_CENTER, Vec2::ZERO)
+            .default_width(820.0)
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.set_min_height(360.0);
+                ui.vertical(|ui| {
+                    ui.horizontal(|ui| {
+                        if ui
+                            .add(
+                                egui::Button::new(i18n.open_logs_folder_button())
+                                    .fill(colors.surface_elev)
+                                    .stroke(Stroke::new(1.0, colors.border_strong)),
+                            )
+                            .clicked()
+                        {
+                            self.trigger_action(UserAction::OpenLogsFolder);
+                        }
+                        if ui
+                            .add(
+                                egui::Button::new(i18n.close_button())
+                                    .fill(colors.surface_elev)
+                                    .stroke(Stroke::new(1.0, colors.border_strong)),
+                            )
+                            .clicked()
+                        {
+                            close_requested = true;
+                        }
+                    });
+                    ui.add_space(8.0);
+                    egui::ScrollArea::vertical()
+                        .max_height(DIAGNOSTICS_REPORT_HEIGHT)
+                        .stick_to_bottom(true)
+                        .show(ui, |ui| {
+                            if self.log_lines.is_empty() {
+                                ui.label(
+                                    RichText::new(i18n.logs_empty()).color(colors.text_muted),
+                                );
+                            }
+                            for line in &self.log_lines {
+                                let color = if line.contains(" ERROR") {
+                                    colors.danger
+                                } else if line.contains(" WARN") {
+                                    colors.warning
+                                } else {
+                                    colors.text_primary
+                                };
+                                ui.label(RichText::new(line).color(color).monospace());
+                            }
+                        });
+                });
+            });
+        self.show_logs_modal = open && !close_requested;
+    }
 }
 
 impl eframe::App for LauncherApp {
     fn update(&mut self, ctx: &eframe::egui::Context, _frame: &mut eframe::Frame) {
-        self.sync_state();
-        self.sync_mod_updates();
-        self.sync_version_updates();
-        self.sync_news_updates();
-        self.sync_updater_updates();
+        ctx.set_pixels_per_point(self.ui_scale);
+        self.handle_close_request(ctx);
+        self.sync_state(ctx);
+        self.sync_mod_updates(ctx);
+        self.sync_mod_download_outcomes(ctx);
+        self.sync_version_updates(ctx);
+        self.sync_news_updates(ctx);
+        self.sync_updater_updates(ctx);
+        self.poll_periodic_updater_check(ctx);
+        self.poll_live_mod_search(ctx);
+        self.poll_tray_events(ctx);
         refresh_fonts_if_needed(self, ctx);
         let colors = self.colors();
         apply_theme(ctx, &colors);
         let top_bar_i18n = self.i18n();
+        self.handle_dropped_mod_files(ctx, &colors, top_bar_i18n);
 
         egui::TopBottomPanel::top("top_bar")
             .frame(
@@ -2430,6 +5747,32 @@ impl eframe::App for LauncherApp {
                         Layout::right_to_left(Align::Center),
                         |ui| {
                             let control_height = 34.0;
+                            ui.scope(|ui| {
+                                ui.set_height(control_height);
+                                egui::ComboBox::from_id_salt("profile_combo")
+                                    .selected_text(self.current_profile.clone())
+                                    .show_ui(ui, |ui| {
+                                        for name in self.available_profiles.clone() {
+                                            if ui
+                                                .selectable_label(
+                                                    name == self.current_profile,
+                                                    name.clone(),
+                                                )
+                                                .clicked()
+                                            {
+                                                self.switch_profile(name);
+                                            }
+                                        }
+                                    });
+                                if ui
+                                    .button(top_bar_i18n.new_profile_button())
+                                    .on_hover_text(top_bar_i18n.profile_label())
+                                    .clicked()
+                                {
+                                    self.create_new_profile();
+                                }
+                            });
+                            ui.add_space(10.0);
                             ui.scope(|ui| {
                                 ui.set_height(control_height);
                                 egui::ComboBox::from_id_salt("theme_combo")
@@ -2503,6 +5846,34 @@ impl eframe::App for LauncherApp {
                                             Language::Turkish,
                                             Language::Turkish.display_name(),
                                         );
+                                        ui.selectable_value(
+                                            &mut self.language,
+                                            Language::Italian,
+                                            Language::Italian.display_name(),
+                                        );
+                                        ui.selectable_value(
+                                            &mut self.language,
+                                            Language::Japanese,
+                                            Language::Japanese.display_name(),
+                                        );
+                                        // Korean isn't offered here yet: no bundled font
+                                        // covers hangul, so its translations would render
+                                        // as missing-glyph boxes. See setup_custom_fonts.
+                                        ui.selectable_value(
+                                            &mut self.language,
+                                            Language::Arabic,
+                                            Language::Arabic.display_name(),
+                                        );
+                                        ui.selectable_value(
+                                            &mut self.language,
+                                            Language::Polish,
+                                            Language::Polish.display_name(),
+                                        );
+                                        ui.selectable_value(
+                                            &mut self.language,
+                                            Language::Vietnamese,
+                                            Language::Vietnamese.display_name(),
+                                        );
                                     });
                             });
                         },
@@ -2574,6 +5945,8 @@ impl eframe::App for LauncherApp {
                         ui.add_space(12.0);
                         self.render_diagnostics(ui, &colors, i18n);
                         ui.add_space(12.0);
+                        self.render_logs(ui, &colors, i18n);
+                        ui.add_space(12.0);
                         self.render_news(ui, &colors, i18n);
                         ui.add_space(12.0);
                         self.render_mods(ui, &colors, i18n);
@@ -2593,6 +5966,8 @@ impl eframe::App for LauncherApp {
                                 self.render_controls(ui, &colors, i18n);
                                 ui.add_space(12.0);
                                 self.render_diagnostics(ui, &colors, i18n);
+                                ui.add_space(12.0);
+                                self.render_logs(ui, &colors, i18n);
                             },
                         );
                         ui.add_space(gutter);
@@ -2622,5 +5997,11 @@ impl eframe::App for LauncherApp {
                 });
             });
         self.render_diagnostics_modal(ctx, &colors, i18n);
+        self.render_logs_modal(ctx, &colors, i18n);
+        self.render_close_confirm_modal(ctx, &colors, i18n);
+        self.render_news_article_modal(ctx, &colors, i18n);
+        self.render_onboarding_modal(ctx, &colors, i18n);
+        self.render_toasts(ctx, &colors);
+        self.apply_repaint_policy(ctx);
     }
 }
@@ -1,7 +1,8 @@
+use std::time::Duration;
+
 use serde::Deserialize;
 
-const GITHUB_API_URL: &str =
-    "https://api.github.com/repos/RustedBytes/hrs-launcher/releases/latest";
+const UPDATE_CHECK_INTERVAL_HOURS_VAR: &str = "HRS_LAUNCHER_UPDATE_CHECK_INTERVAL_HOURS";
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct ReleaseInfo {
@@ -9,6 +10,20 @@ pub struct ReleaseInfo {
     pub html_url: String,
 }
 
+/// Returns how often the launcher should re-check for updates while running,
+/// read from `HRS_LAUNCHER_UPDATE_CHECK_INTERVAL_HOURS`. Periodic re-checks
+/// are off by default; set the variable to a positive number of hours to
+/// enable them.
+#[must_use]
+pub fn periodic_check_interval() -> Option<Duration> {
+    let hours = std::env::var(UPDATE_CHECK_INTERVAL_HOURS_VAR)
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    (hours > 0).then(|| Duration::from_secs(hours * 3600))
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UpdateStatus {
     UpToDate,
@@ -23,12 +38,13 @@ pub enum UpdateStatus {
 pub async fn check_for_updates(current_version: &str) -> Result<UpdateStatus, String> {
     let client = reqwest::Client::new();
 
-    let response = client
-        .get(GITHUB_API_URL)
-        .header("User-Agent", "hrs-launcher")
-        .send()
-        .await
-        .map_err(|err| format!("Failed to check for updates: {err}"))?;
+    let response = crate::util::send_with_retry(|| {
+        client
+            .get(crate::endpoints::github_api_url())
+            .header("User-Agent", "hrs-launcher")
+    })
+    .await
+    .map_err(|err| format!("Failed to check for updates: {err}"))?;
 
     if !response.status().is_success() {
         return Err(format!("GitHub API returned status: {}", response.status()));
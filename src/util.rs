@@ -1,5 +1,9 @@
+use std::collections::VecDeque;
+use std::io;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
 /// Check whether an optional cancellation flag has been raised.
 #[must_use]
@@ -10,6 +14,50 @@ pub fn cancel_requested(cancel: &Option<Arc<AtomicBool>>) -> bool {
         .unwrap_or(false)
 }
 
+/// Smooths per-tick download speed into a steadier rolling average, so the
+/// displayed speed/ETA doesn't jump around with every chunk. Keeps a small
+/// ring buffer of `(time, bytes downloaded so far)` samples covering the
+/// last [`Self::WINDOW`] and reports the average rate across it.
+pub struct SpeedTracker {
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl SpeedTracker {
+    const WINDOW: Duration = Duration::from_secs(3);
+
+    #[must_use]
+    pub fn new() -> Self {
+        Self { samples: VecDeque::new() }
+    }
+
+    /// Records a `(now, downloaded)` sample and returns the smoothed
+    /// bytes/sec average across the tracked window, or `None` until there's
+    /// more than one sample to compare against.
+    pub fn record(&mut self, downloaded: u64) -> Option<f32> {
+        let now = Instant::now();
+        self.samples.push_back((now, downloaded));
+        while self.samples.len() > 1
+            && let Some(&(oldest_at, _)) = self.samples.front()
+            && now.duration_since(oldest_at) > Self::WINDOW
+        {
+            self.samples.pop_front();
+        }
+
+        let &(oldest_at, oldest_bytes) = self.samples.front()?;
+        let elapsed = now.duration_since(oldest_at).as_secs_f32();
+        if elapsed <= 0.0 {
+            return None;
+        }
+        Some((downloaded - oldest_bytes) as f32 / elapsed)
+    }
+}
+
+impl Default for SpeedTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Render a human-friendly transfer speed string.
 #[must_use]
 pub fn format_speed(bytes_per_sec: f32) -> String {
@@ -25,6 +73,50 @@ pub fn format_speed(bytes_per_sec: f32) -> String {
     }
 }
 
+/// Render a human-friendly byte size, e.g. for on-disk file sizes.
+#[must_use]
+pub fn format_size(bytes: u64) -> String {
+    const KIB: f64 = 1024.0;
+    const MIB: f64 = KIB * 1024.0;
+    const GIB: f64 = MIB * 1024.0;
+
+    let bytes = bytes as f64;
+    if bytes < KIB {
+        format!("{bytes:.0} B")
+    } else if bytes < MIB {
+        format!("{:.1} KB", bytes / KIB)
+    } else if bytes < GIB {
+        format!("{:.1} MB", bytes / MIB)
+    } else {
+        format!("{:.1} GB", bytes / GIB)
+    }
+}
+
+/// Render a human-friendly "time remaining" estimate from the remaining
+/// bytes and current speed, e.g. "~3m 20s remaining". Falls back to
+/// "calculating..." when the total size is unknown or the speed is too
+/// low to give a meaningful estimate, to avoid dividing by (near) zero.
+#[must_use]
+pub fn format_eta(downloaded: u64, total: Option<u64>, bytes_per_sec: f32) -> String {
+    const MIN_SPEED: f32 = 1.0;
+
+    let Some(total) = total.filter(|&total| total > downloaded) else {
+        return "calculating...".into();
+    };
+    if bytes_per_sec < MIN_SPEED {
+        return "calculating...".into();
+    }
+
+    let remaining_secs = ((total - downloaded) as f32 / bytes_per_sec).round() as u64;
+    let minutes = remaining_secs / 60;
+    let seconds = remaining_secs % 60;
+    if minutes > 0 {
+        format!("~{minutes}m {seconds}s remaining")
+    } else {
+        format!("~{seconds}s remaining")
+    }
+}
+
 /// Compute download progress as a percentage.
 #[must_use]
 pub fn progress_percent(downloaded: u64, total: Option<u64>) -> f32 {
@@ -34,10 +126,222 @@ pub fn progress_percent(downloaded: u64, total: Option<u64>) -> f32 {
     }
 }
 
+/// Builds the path of the temporary file [`write_atomic`]/[`write_atomic_async`]
+/// write to before renaming it over `path`, so a crash mid-write never
+/// leaves `path` itself truncated or half-written.
+fn atomic_tmp_path(path: &Path) -> PathBuf {
+    let file_name = path.file_name().and_then(|name| name.to_str()).unwrap_or("tmp");
+    path.with_file_name(format!(".{file_name}.tmp-{}", std::process::id()))
+}
+
+/// Renames `tmp_path` over `path`. Unlike POSIX, Windows' rename fails if
+/// `path` already exists, so on failure there we remove it first and retry.
+fn rename_atomic(tmp_path: &Path, path: &Path) -> io::Result<()> {
+    match std::fs::rename(tmp_path, path) {
+        Ok(()) => Ok(()),
+        Err(_) if cfg!(windows) => {
+            let _ = std::fs::remove_file(path);
+            std::fs::rename(tmp_path, path)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Writes `contents` to `path` by first writing a sibling temp file and then
+/// renaming it into place, so a crash or power loss mid-write leaves the
+/// previous file intact instead of a truncated or corrupt one.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let tmp_path = atomic_tmp_path(path);
+    std::fs::write(&tmp_path, contents)?;
+    rename_atomic(&tmp_path, path)
+}
+
+/// Async counterpart of [`write_atomic`], for callers already running on a
+/// tokio runtime.
+pub async fn write_atomic_async(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let tmp_path = atomic_tmp_path(path);
+    tokio::fs::write(&tmp_path, contents).await?;
+    match tokio::fs::rename(&tmp_path, path).await {
+        Ok(()) => Ok(()),
+        Err(_) if cfg!(windows) => {
+            let _ = tokio::fs::remove_file(path).await;
+            tokio::fs::rename(&tmp_path, path).await
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Clears the macOS quarantine attribute itch.io/Adoptium downloads land
+/// with, which otherwise makes Gatekeeper silently refuse to run a freshly
+/// extracted binary. A no-op everywhere except macOS; best-effort, since a
+/// missing attribute (already cleared, or never set) is not an error.
+pub fn clear_quarantine(path: &Path) {
+    if !cfg!(target_os = "macos") {
+        return;
+    }
+    match std::process::Command::new("xattr")
+        .arg("-dr")
+        .arg("com.apple.quarantine")
+        .arg(path)
+        .output()
+    {
+        Ok(output) if !output.status.success() => {
+            log::debug!(
+                "clear_quarantine: xattr exited with {} for {}: {}",
+                output.status,
+                path.display(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(_) => {}
+        Err(err) => {
+            log::warn!(
+                "clear_quarantine: failed to run xattr for {}: {err}",
+                path.display()
+            );
+        }
+    }
+}
+
+/// Opens `file`'s parent directory in the OS file manager and, where
+/// supported, pre-selects the file itself (`explorer /select,` on Windows,
+/// `open -R` on macOS). Linux file managers have no common equivalent, so
+/// there we just open the containing folder. Returns an error if `file`
+/// doesn't exist, so callers don't silently open the wrong thing.
+pub fn reveal_in_file_manager(file: &Path) -> Result<(), String> {
+    if !file.exists() {
+        return Err(format!("File not found: {}", file.display()));
+    }
+
+    if cfg!(target_os = "windows") {
+        std::process::Command::new("explorer")
+            .arg("/select,")
+            .arg(file)
+            .spawn()
+            .map_err(|e| format!("failed to launch explorer: {e}"))?;
+        return Ok(());
+    }
+
+    if cfg!(target_os = "macos") {
+        std::process::Command::new("open")
+            .arg("-R")
+            .arg(file)
+            .spawn()
+            .map_err(|e| format!("failed to launch open -R: {e}"))?;
+        return Ok(());
+    }
+
+    let dir = file
+        .parent()
+        .ok_or_else(|| format!("no parent directory for {}", file.display()))?;
+    open::that(dir).map_err(|e| format!("failed to open {}: {e}", dir.display()))
+}
+
+/// Sends the request built by `build` (called fresh on every attempt), retrying
+/// a couple of times with a short backoff on transport errors, `429`, or a
+/// `5xx` status. A request that reaches the server and gets back another 4xx
+/// is returned as-is on the first try, since a retry wouldn't change that.
+/// Intended for read-only GET/HEAD calls (news, update checks, version
+/// probes) where a transient blip shouldn't surface as a user-facing error.
+pub async fn send_with_retry<F>(mut build: F) -> Result<reqwest::Response, String>
+where
+    F: FnMut() -> reqwest::RequestBuilder,
+{
+    const ATTEMPTS: u32 = 3;
+    const BASE_BACKOFF: Duration = Duration::from_millis(250);
+
+    let mut last_err = String::new();
+    for attempt in 0..ATTEMPTS {
+        match build().send().await {
+            Ok(resp) => {
+                let status = resp.status();
+                if status.as_u16() != 429 && !status.is_server_error() {
+                    return Ok(resp);
+                }
+                last_err = format!("server returned {status}");
+            }
+            Err(err) => last_err = err.to_string(),
+        }
+        if attempt + 1 < ATTEMPTS {
+            tokio::time::sleep(BASE_BACKOFF * 2u32.pow(attempt)).await;
+        }
+    }
+    Err(last_err)
+}
+
+/// Splits a raw "extra launch arguments" string into individual arguments,
+/// respecting single and double quotes. These end up as literal entries in
+/// `std::process::Command::arg`, never passed through a shell, so characters
+/// like `;`, `|`, or `` ` `` are inert - quoting here only controls how
+/// whitespace is grouped into a single argument.
+pub fn tokenize_launch_args(raw: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_token = false;
+    let mut quote: Option<char> = None;
+
+    for c in raw.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '\'' || c == '"' => {
+                quote = Some(c);
+                in_token = true;
+            }
+            None if c.is_whitespace() => {
+                if in_token {
+                    tokens.push(std::mem::take(&mut current));
+                    in_token = false;
+                }
+            }
+            None => {
+                current.push(c);
+                in_token = true;
+            }
+        }
+    }
+
+    if quote.is_some() {
+        return Err("unterminated quote in launch arguments".to_owned());
+    }
+    if in_token {
+        tokens.push(current);
+    }
+    if tokens.iter().any(|token| token.contains('\0')) {
+        return Err("launch arguments cannot contain NUL bytes".to_owned());
+    }
+
+    Ok(tokens)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering as AtomicOrdering};
+
+    static TEMP_DIR_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    /// A fresh, empty temp dir for one test. Cleaned up best-effort on drop.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            let id = TEMP_DIR_COUNTER.fetch_add(1, AtomicOrdering::SeqCst);
+            let dir = std::env::temp_dir().join(format!(
+                "hrs-launcher-util-test-{}-{id}",
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).expect("create temp dir");
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
 
     #[test]
     fn formats_speed_human_readable() {
@@ -46,6 +350,25 @@ mod tests {
         assert_eq!(format_speed(5_242_880.0), "5.0 MB/s");
     }
 
+    #[test]
+    fn formats_eta_from_remaining_bytes_and_speed() {
+        assert_eq!(format_eta(0, Some(1_000), 100.0), "~10s remaining");
+        assert_eq!(format_eta(0, Some(20_000), 100.0), "~3m 20s remaining");
+        assert_eq!(format_eta(500, None, 100.0), "calculating...");
+        assert_eq!(format_eta(0, Some(1_000), 0.0), "calculating...");
+        assert_eq!(format_eta(1_000, Some(1_000), 100.0), "calculating...");
+    }
+
+    #[test]
+    fn reveal_in_file_manager_errors_on_missing_file() {
+        let dir = TempDir::new();
+        let missing = dir.0.join("does-not-exist.jar");
+
+        let result = reveal_in_file_manager(&missing);
+
+        assert!(result.is_err());
+    }
+
     #[test]
     fn calculates_progress_percent() {
         assert_eq!(progress_percent(0, Some(10)), 0.0);
@@ -62,4 +385,112 @@ mod tests {
         assert!(cancel_requested(&Some(flag)));
         assert!(!cancel_requested(&None));
     }
+
+    #[test]
+    fn write_atomic_replaces_file_contents() {
+        let temp = TempDir::new();
+        let path = temp.0.join("state.txt");
+        write_atomic(&path, b"first").unwrap();
+        write_atomic(&path, b"second").unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"second");
+    }
+
+    #[test]
+    fn interrupted_write_leaves_previous_file_intact() {
+        let temp = TempDir::new();
+        let path = temp.0.join("manifest.json");
+        write_atomic(&path, b"original").unwrap();
+
+        // Simulate a crash between writing the temp file and the rename that
+        // would publish it: leave the temp file behind without renaming.
+        let tmp_path = atomic_tmp_path(&path);
+        std::fs::write(&tmp_path, b"truncated").unwrap();
+
+        assert_eq!(std::fs::read(&path).unwrap(), b"original");
+    }
+
+    #[test]
+    fn tokenizes_launch_args_respecting_quotes() {
+        let tokens = tokenize_launch_args(r#"--flag "quoted value" 'single quoted'"#).unwrap();
+        assert_eq!(tokens, vec!["--flag", "quoted value", "single quoted"]);
+    }
+
+    #[test]
+    fn rejects_unterminated_quote_in_launch_args() {
+        assert!(tokenize_launch_args("--flag \"unterminated").is_err());
+    }
+
+    /// A minimal mock HTTP server that replies to each successive connection
+    /// with the next status code from `statuses`, repeating the last one once
+    /// exhausted. Lets tests exercise retry counts without a real network.
+    struct MockServer {
+        addr: std::net::SocketAddr,
+    }
+
+    impl MockServer {
+        fn start(statuses: Vec<u16>) -> Self {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+            let addr = listener.local_addr().expect("mock server local addr");
+            let statuses = std::sync::Arc::new(statuses);
+            let calls = std::sync::Arc::new(AtomicU32::new(0));
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let Ok(stream) = stream else { continue };
+                    let statuses = statuses.clone();
+                    let calls = calls.clone();
+                    std::thread::spawn(move || Self::serve_one(stream, &statuses, &calls));
+                }
+            });
+            Self { addr }
+        }
+
+        fn serve_one(mut stream: std::net::TcpStream, statuses: &[u16], calls: &AtomicU32) {
+            use std::io::{Read, Write};
+            let mut buf = [0u8; 8192];
+            let _ = stream.read(&mut buf);
+            let index = calls.fetch_add(1, AtomicOrdering::SeqCst) as usize;
+            let status = statuses.get(index).or_else(|| statuses.last()).copied().unwrap_or(500);
+            let head = format!("HTTP/1.1 {status} status\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+            let _ = stream.write_all(head.as_bytes());
+        }
+
+        fn url(&self) -> String {
+            format!("http://{}/", self.addr)
+        }
+    }
+
+    #[tokio::test]
+    async fn send_with_retry_recovers_after_transient_server_errors() {
+        let server = MockServer::start(vec![500, 500, 200]);
+        let client = reqwest::Client::new();
+        let url = server.url();
+
+        let resp = send_with_retry(|| client.get(&url))
+            .await
+            .expect("should succeed on the third attempt");
+
+        assert_eq!(resp.status(), reqwest::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn send_with_retry_gives_up_after_persistent_server_errors() {
+        let server = MockServer::start(vec![500, 500, 500]);
+        let client = reqwest::Client::new();
+        let url = server.url();
+
+        assert!(send_with_retry(|| client.get(&url)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn send_with_retry_does_not_retry_plain_client_errors() {
+        let server = MockServer::start(vec![404]);
+        let client = reqwest::Client::new();
+        let url = server.url();
+
+        let resp = send_with_retry(|| client.get(&url))
+            .await
+            .expect("a plain 4xx is returned, not retried");
+
+        assert_eq!(resp.status(), reqwest::StatusCode::NOT_FOUND);
+    }
 }